@@ -5,6 +5,12 @@ use core::{cmp::Ordering, fmt, ops::Deref, str::FromStr};
 use sqlparser::ast::Ident;
 
 /// Top-level unique identifier.
+///
+/// Identifiers follow the postgres rules for case sensitivity:
+/// unquoted identifiers (e.g. `Order_Id`) are case-insensitive and are stored in lowercase,
+/// so `a` and `A` are the same identifier.
+/// Quoted identifiers (e.g. `"Order Id"`) are stored exactly as written,
+/// preserving both their case and any special characters.
 #[derive(Debug, PartialEq, Eq, Clone, Hash, Ord, PartialOrd, Copy)]
 pub struct Identifier {
     name: ArrayString<64>,
@@ -28,6 +34,20 @@ impl Identifier {
         }
     }
 
+    /// Constructor for quoted [Identifier]s, which keep the exact string they are given.
+    ///
+    /// Note: like [`Identifier::new`], this constructor should be private within the `proof_of_sql_parser` crate.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if:
+    /// - The provided string is too long to fit into the internal `ArrayString`.
+    pub(crate) fn new_quoted<S: AsRef<str>>(string: S) -> Self {
+        Self {
+            name: ArrayString::from(string.as_ref()).expect("Identifier too long"),
+        }
+    }
+
     /// An alias for [`Identifier::from_str`], provided for convenience.
     ///
     /// # Errors
@@ -38,6 +58,43 @@ impl Identifier {
         Self::from_str(string.as_ref())
     }
 
+    /// Constructor for a quoted [Identifier] from its unquoted contents,
+    /// so that `Identifier::try_new_quoted("Order Id")` is the same as `Identifier::try_new("\"Order Id\"")`.
+    ///
+    /// # Errors
+    /// Returns a `ParseResult::Err` if the string is empty, contains a `"`, or is longer than 64 bytes.
+    pub fn try_new_quoted<S: AsRef<str>>(string: S) -> ParseResult<Self> {
+        let string = string.as_ref();
+        if string.is_empty() || string.contains('"') || string.len() > 64 {
+            return Err(ParseError::IdentifierParseError {
+                error: format!("invalid quoted identifier: {string:?}"),
+            });
+        }
+        Ok(Identifier::new_quoted(string))
+    }
+
+    /// Constructor for an [Identifier] from a name that comes from outside of SQL, such as an Arrow field name.
+    ///
+    /// Names that are valid unquoted identifiers are case-folded exactly as [`Identifier::try_new`] does,
+    /// so `"a"` and `"A"` result in the same identifier. This is intentional: it is how SQL resolves
+    /// unquoted names, so `SELECT A` finds a field named `"A"`. Only a name that is not a valid unquoted
+    /// identifier, such as `"Order Id"`, is treated as a quoted identifier and kept verbatim.
+    ///
+    /// # Errors
+    /// Returns a `ParseResult::Err` if the name is neither a valid unquoted nor a valid quoted identifier.
+    pub fn try_from_name<S: AsRef<str>>(string: S) -> ParseResult<Self> {
+        Self::try_new(string.as_ref()).or_else(|_| Self::try_new_quoted(string))
+    }
+
+    /// Whether this [Identifier] has to be quoted to be written in SQL,
+    /// i.e. whether its name is not already a valid lowercase unquoted identifier.
+    #[must_use]
+    pub fn requires_quotes(&self) -> bool {
+        IdentifierParser::new()
+            .parse(&self.name)
+            .map_or(true, |parsed| parsed.name != self.name)
+    }
+
     /// The name of this [Identifier]
     /// It already implements [Deref] to [str], so this method is not necessary for most use cases.
     #[must_use]
@@ -56,19 +113,23 @@ impl FromStr for Identifier {
     type Err = ParseError;
 
     fn from_str(string: &str) -> ParseResult<Self> {
-        let name = IdentifierParser::new()
+        IdentifierParser::new()
             .parse(string)
             .map_err(|e| ParseError::IdentifierParseError{ error:
-                format!("failed to parse identifier, (you may have used a reserved keyword as an ID, i.e. 'timestamp') {e:?}")})?;
-
-        Ok(Identifier::new(name))
+                format!("failed to parse identifier, (you may have used a reserved keyword as an ID, i.e. 'timestamp') {e:?}")})
     }
 }
 crate::impl_serde_from_str!(Identifier);
 
+/// Identifiers that cannot be written unquoted are displayed with surrounding double quotes,
+/// so that the output always parses back into the same [Identifier].
 impl fmt::Display for Identifier {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-        self.name.fmt(f)
+        if self.requires_quotes() {
+            write!(f, "\"{}\"", self.name)
+        } else {
+            self.name.fmt(f)
+        }
     }
 }
 
@@ -77,8 +138,11 @@ impl TryFrom<Ident> for Identifier {
     type Error = ParseError;
 
     fn try_from(ident: Ident) -> ParseResult<Self> {
-        // Convert Ident's value to Identifier
-        Identifier::try_new(ident.value)
+        // Convert Ident's value to Identifier, respecting quoted identifiers
+        match ident.quote_style {
+            Some('"') => Identifier::try_new_quoted(ident.value),
+            _ => Identifier::try_new(ident.value),
+        }
     }
 }
 
@@ -290,6 +354,80 @@ mod tests {
         Identifier::new("茶".repeat(21));
     }
 
+    #[test]
+    fn we_can_parse_quoted_identifiers_preserving_case_and_special_characters() {
+        let identifier = Identifier::from_str("\"Order Id\"").unwrap();
+        assert_eq!(identifier.name(), "Order Id");
+        assert_eq!(identifier, Identifier::try_new_quoted("Order Id").unwrap());
+        assert_ne!(
+            Identifier::from_str("\"ABC\"").unwrap(),
+            Identifier::from_str("ABC").unwrap()
+        );
+        assert_eq!(
+            Identifier::from_str("\"abc\"").unwrap(),
+            Identifier::from_str("ABC").unwrap()
+        );
+        assert_eq!(Identifier::from_str("\"select\"").unwrap().name(), "select");
+        assert_eq!(Identifier::from_str("\"茶$!\"").unwrap().name(), "茶$!");
+    }
+
+    #[test]
+    fn we_cannot_parse_invalid_quoted_identifiers() {
+        assert!(Identifier::from_str("\"\"").is_err());
+        assert!(Identifier::from_str("\"a\"b\"").is_err());
+        assert!(Identifier::from_str("\"unterminated").is_err());
+        assert!(Identifier::from_str(&format!("\"{}\"", "t".repeat(65))).is_err());
+        assert!(Identifier::from_str(&format!("\"{}\"", "t".repeat(64))).is_ok());
+        assert!(Identifier::try_new_quoted("").is_err());
+        assert!(Identifier::try_new_quoted("a\"b").is_err());
+        assert!(Identifier::try_new_quoted("t".repeat(65)).is_err());
+    }
+
+    #[test]
+    fn quoted_identifiers_are_displayed_with_quotes_only_when_required() {
+        assert_eq!(
+            Identifier::from_str("\"Order Id\"").unwrap().to_string(),
+            "\"Order Id\""
+        );
+        assert_eq!(
+            Identifier::from_str("\"ABC\"").unwrap().to_string(),
+            "\"ABC\""
+        );
+        assert_eq!(
+            Identifier::from_str("\"select\"").unwrap().to_string(),
+            "\"select\""
+        );
+        assert_eq!(Identifier::from_str("\"abc\"").unwrap().to_string(), "abc");
+        assert!(!Identifier::from_str("ABC").unwrap().requires_quotes());
+    }
+
+    #[test]
+    fn quoted_identifiers_round_trip_through_display_and_serde() {
+        for name in ["\"Order Id\"", "\"ABC\"", "\"select\"", "abc"] {
+            let identifier = Identifier::from_str(name).unwrap();
+            let displayed = identifier.to_string();
+            assert_eq!(Identifier::from_str(&displayed).unwrap(), identifier);
+            let serialized = serde_json::to_string(&identifier).unwrap();
+            let deserialized: Identifier = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(deserialized, identifier);
+        }
+    }
+
+    #[test]
+    fn we_can_create_identifiers_from_names() {
+        assert_eq!(Identifier::try_from_name("ABC").unwrap().name(), "abc");
+        assert_eq!(
+            Identifier::try_from_name("Order Id").unwrap().name(),
+            "Order Id"
+        );
+        assert_eq!(
+            Identifier::try_from_name("select").unwrap().name(),
+            "select"
+        );
+        assert!(Identifier::try_from_name("").is_err());
+        assert!(Identifier::try_from_name("a\"b").is_err());
+    }
+
     #[test]
     fn try_from_ident() {
         let ident = Ident::new("ValidIdentifier");
@@ -298,5 +436,9 @@ mod tests {
 
         let invalid_ident = Ident::new("INVALID$IDENTIFIER");
         assert!(Identifier::try_from(invalid_ident).is_err());
+
+        let quoted_ident = Ident::with_quote('"', "Order Id");
+        let identifier = Identifier::try_from(quoted_ident).unwrap();
+        assert_eq!(identifier.name(), "Order Id");
     }
 }
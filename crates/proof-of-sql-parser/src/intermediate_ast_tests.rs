@@ -678,6 +678,27 @@ fn we_can_parse_a_query_with_one_schema_followed_by_a_table_name() {
     assert_eq!(ast, expected_ast);
 }
 
+#[test]
+fn we_can_parse_a_query_with_quoted_identifiers() {
+    let ast = r#"select "Order Id" as "Id", a from "My Schema"."Orders" where "Order Id" <= 4;"#
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query(
+            vec![
+                col_res(col(r#""Order Id""#), r#""Id""#),
+                col_res(col("a"), "a"),
+            ],
+            tab(Some(r#""My Schema""#), r#""Orders""#),
+            le(col(r#""Order Id""#), lit(4)),
+            vec![],
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+}
+
 #[test]
 fn we_can_parse_a_query_without_a_filter() {
     let ast = "select a from tab".parse::<SelectStatement>().unwrap();
@@ -103,10 +103,9 @@ impl FromStr for ResourceId {
             }
         })?;
 
-        // use unsafe `Identifier::new` to prevent double parsing the ids
         Ok(ResourceId {
-            schema: Identifier::new(schema),
-            object_name: Identifier::new(object_name),
+            schema,
+            object_name,
         })
     }
 }
@@ -151,6 +150,18 @@ mod tests {
         assert_eq!(resource_id.schema().name(), "g00d_identifier");
     }
 
+    #[test]
+    fn resource_id_from_str_preserves_quoted_identifiers() {
+        let resource_id = ResourceId::from_str("\"My Schema\".\"Orders\"").unwrap();
+        assert_eq!(resource_id.schema().name(), "My Schema");
+        assert_eq!(resource_id.object_name().name(), "Orders");
+        assert_eq!(resource_id.to_string(), "\"My Schema\".\"Orders\"");
+        assert_eq!(
+            ResourceId::from_str(&resource_id.to_string()).unwrap(),
+            resource_id
+        );
+    }
+
     #[test]
     fn try_new_resource_id_with_additional_characters_fails() {
         assert!(ResourceId::try_new("GOOD_IDENTIFIER", "GOOD_IDENTIFIER.").is_err());
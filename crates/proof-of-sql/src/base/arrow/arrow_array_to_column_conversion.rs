@@ -1,10 +1,15 @@
 use super::scalar_and_i256_conversions::convert_i256_to_scalar;
-use crate::base::{database::Column, math::decimal::Precision, scalar::Scalar};
+use crate::base::{
+    database::Column,
+    math::decimal::Precision,
+    scalar::{Scalar, ScalarExt},
+};
 use arrow::{
     array::{
-        Array, ArrayRef, BooleanArray, Decimal128Array, Decimal256Array, Int16Array, Int32Array,
-        Int64Array, Int8Array, StringArray, TimestampMicrosecondArray, TimestampMillisecondArray,
-        TimestampNanosecondArray, TimestampSecondArray,
+        Array, ArrayRef, BinaryArray, BooleanArray, Decimal128Array, Decimal256Array, Int16Array,
+        Int32Array, Int64Array, Int8Array, LargeBinaryArray, StringArray,
+        TimestampMicrosecondArray, TimestampMillisecondArray, TimestampNanosecondArray,
+        TimestampSecondArray,
     },
     datatypes::{i256, DataType, TimeUnit as ArrowTimeUnit},
 };
@@ -91,6 +96,7 @@ pub trait ArrayRefExt {
 impl ArrayRefExt for ArrayRef {
     #[cfg(any(test, feature = "test"))]
     #[cfg(feature = "blitzar")]
+    #[allow(clippy::too_many_lines)]
     fn to_curve25519_scalars(
         &self,
     ) -> Result<Vec<crate::base::scalar::Curve25519Scalar>, ArrowArrayToColumnConversionError> {
@@ -120,7 +126,7 @@ impl ArrayRefExt for ArrayRef {
                 .as_any()
                 .downcast_ref::<Int64Array>()
                 .map(|array| array.values().iter().map(|v| Ok((*v).into())).collect()),
-            DataType::Decimal128(38, 0) => self
+            DataType::Decimal128(_, 0) => self
                 .as_any()
                 .downcast_ref::<Decimal128Array>()
                 .map(|array| array.values().iter().map(|v| Ok((*v).into())).collect()),
@@ -150,6 +156,30 @@ impl ArrayRefExt for ArrayRef {
                     })
                     .collect()
             }),
+            DataType::Binary => self.as_any().downcast_ref::<BinaryArray>().map(|array| {
+                array
+                    .iter()
+                    .map(|v| {
+                        v.ok_or(ArrowArrayToColumnConversionError::ArrayContainsNulls)
+                            .map(crate::base::scalar::Curve25519Scalar::from_byte_slice_via_hash)
+                    })
+                    .collect()
+            }),
+            DataType::LargeBinary => {
+                self.as_any()
+                    .downcast_ref::<LargeBinaryArray>()
+                    .map(|array| {
+                        array
+                            .iter()
+                            .map(|v| {
+                                v.ok_or(ArrowArrayToColumnConversionError::ArrayContainsNulls)
+                                .map(
+                                    crate::base::scalar::Curve25519Scalar::from_byte_slice_via_hash,
+                                )
+                            })
+                            .collect()
+                    })
+            }
             DataType::Timestamp(time_unit, _) => match time_unit {
                 ArrowTimeUnit::Second => self
                     .as_any()
@@ -185,14 +215,16 @@ impl ArrayRefExt for ArrayRef {
     /// - `alloc`: Reference to a `Bump` allocator used for memory allocation during the conversion.
     /// - `range`: Reference to a `Range<usize>` specifying the slice of the array to convert.
     /// - `precomputed_scals`: Optional reference to a slice of `Curve25519Scalar` values.
-    ///    `VarChar` columns store hashes to their values as scalars, which can be provided here.
+    ///    `VarChar` and `VarBinary` columns store hashes to their values as scalars, which can be provided here.
     ///
     /// # Supported types
-    /// - For `DataType::Int64` and `DataType::Decimal128(38, 0)`, it slices the array
+    /// - For `DataType::Int64` and `DataType::Decimal128(_, 0)`, it slices the array
     ///   based on the provided range and returns the corresponding `BigInt` or `Int128` column.
     /// - Decimal256, converts arrow i256 columns into Decimal75(precision, scale) columns.
     /// - For `DataType::Utf8`, it extracts string values and scalar values (if `precomputed_scals`
     ///   is provided) for the specified range and returns a `VarChar` column.
+    /// - For `DataType::Binary` and `DataType::LargeBinary`, it does the same with byte values and
+    ///   returns a `VarBinary` column.
     ///
     /// # Panics
     /// - When any range is OOB, i.e. indexing 3..6 or 5..5 on array of size 2.
@@ -269,7 +301,7 @@ impl ArrayRefExt for ArrayRef {
                     })
                 }
             }
-            DataType::Decimal128(38, 0) => {
+            DataType::Decimal128(_, 0) => {
                 if let Some(array) = self.as_any().downcast_ref::<Decimal128Array>() {
                     Ok(Column::Int128(&array.values()[range.start..range.end]))
                 } else {
@@ -375,6 +407,46 @@ impl ArrayRefExt for ArrayRef {
                     })
                 }
             }
+            DataType::Binary => {
+                if let Some(array) = self.as_any().downcast_ref::<BinaryArray>() {
+                    let vals = alloc
+                        .alloc_slice_fill_with(range.end - range.start, |i| -> &'a [u8] {
+                            array.value(range.start + i)
+                        });
+                    let scals = if let Some(scals) = precomputed_scals {
+                        &scals[range.start..range.end]
+                    } else {
+                        alloc.alloc_slice_fill_with(vals.len(), |i| {
+                            S::from_byte_slice_via_hash(vals[i])
+                        })
+                    };
+                    Ok(Column::VarBinary((vals, scals)))
+                } else {
+                    Err(ArrowArrayToColumnConversionError::UnsupportedType {
+                        datatype: self.data_type().clone(),
+                    })
+                }
+            }
+            DataType::LargeBinary => {
+                if let Some(array) = self.as_any().downcast_ref::<LargeBinaryArray>() {
+                    let vals = alloc
+                        .alloc_slice_fill_with(range.end - range.start, |i| -> &'a [u8] {
+                            array.value(range.start + i)
+                        });
+                    let scals = if let Some(scals) = precomputed_scals {
+                        &scals[range.start..range.end]
+                    } else {
+                        alloc.alloc_slice_fill_with(vals.len(), |i| {
+                            S::from_byte_slice_via_hash(vals[i])
+                        })
+                    };
+                    Ok(Column::VarBinary((vals, scals)))
+                } else {
+                    Err(ArrowArrayToColumnConversionError::UnsupportedType {
+                        datatype: self.data_type().clone(),
+                    })
+                }
+            }
             data_type => Err(ArrowArrayToColumnConversionError::UnsupportedType {
                 datatype: data_type.clone(),
             }),
@@ -500,6 +572,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn we_can_convert_binary_array_normal_range() {
+        let alloc = Bump::new();
+        let data: [&[u8]; 4] = [b"", &[0xff, 0x00], b"abc", &[0xc3, 0x28]];
+        let array: ArrayRef = Arc::new(BinaryArray::from_iter_values(data));
+        let result = array.to_column::<Curve25519Scalar>(&alloc, &(1..4), None);
+        let expected_scals: Vec<Curve25519Scalar> = data[1..]
+            .iter()
+            .map(|v| Curve25519Scalar::from_byte_slice_via_hash(v))
+            .collect();
+        assert_eq!(
+            result.unwrap(),
+            Column::VarBinary((&data[1..], expected_scals.as_slice()))
+        );
+
+        let array: ArrayRef = Arc::new(LargeBinaryArray::from_iter_values(data));
+        let result = array.to_column::<Curve25519Scalar>(&alloc, &(1..4), None);
+        assert_eq!(
+            result.unwrap(),
+            Column::VarBinary((&data[1..], expected_scals.as_slice()))
+        );
+    }
+
     #[test]
     fn we_can_convert_utf8_array_empty_range() {
         let alloc = Bump::new();
@@ -0,0 +1,133 @@
+use crate::base::{
+    database::{ColumnType, SchemaAccessor, TableRef},
+    map::IndexMap,
+};
+use alloc::vec::Vec;
+use arrow::datatypes::{Field, SchemaRef};
+use proof_of_sql_parser::Identifier;
+
+/// A [`SchemaAccessor`] backed by arrow schemas, for plugging arrow-native catalogs into the proof system.
+///
+/// Field names are converted with [`Identifier::try_from_name`] and data types with
+/// [`ColumnType::try_from_arrow`]. Fields that fail either conversion are treated as if they did not exist.
+pub struct ArrowSchemaAccessor {
+    schemas: IndexMap<TableRef, SchemaRef>,
+}
+
+impl ArrowSchemaAccessor {
+    /// Create a new `ArrowSchemaAccessor` with the given schemas.
+    #[must_use]
+    pub fn new(schemas: IndexMap<TableRef, SchemaRef>) -> Self {
+        Self { schemas }
+    }
+}
+
+/// Convert an arrow field to a column name and type, logging the reason if this is not possible.
+fn field_to_column(table_ref: TableRef, field: &Field) -> Option<(Identifier, ColumnType)> {
+    let column_id = Identifier::try_from_name(field.name())
+        .map_err(|err| {
+            tracing::warn!(
+                "ignoring column {:?} of table {table_ref}: {err}",
+                field.name()
+            );
+        })
+        .ok()?;
+    let column_type = ColumnType::try_from_arrow(field.data_type())
+        .map_err(|err| {
+            tracing::warn!("ignoring column {column_id} of table {table_ref}: {err}");
+        })
+        .ok()?;
+    Some((column_id, column_type))
+}
+
+impl SchemaAccessor for ArrowSchemaAccessor {
+    fn lookup_column(&self, table_ref: TableRef, column_id: Identifier) -> Option<ColumnType> {
+        self.schemas
+            .get(&table_ref)?
+            .fields()
+            .iter()
+            .filter_map(|field| field_to_column(table_ref, field))
+            .find_map(|(id, column_type)| (id == column_id).then_some(column_type))
+    }
+
+    fn lookup_schema(&self, table_ref: TableRef) -> Vec<(Identifier, ColumnType)> {
+        self.schemas
+            .get(&table_ref)
+            .map(|schema| {
+                schema
+                    .fields()
+                    .iter()
+                    .filter_map(|field| field_to_column(table_ref, field))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::{map::indexmap, math::decimal::Precision};
+    use alloc::sync::Arc;
+    use arrow::datatypes::{DataType, Schema};
+
+    fn sample_arrow_schema_accessor() -> ArrowSchemaAccessor {
+        let table: TableRef = "schema.table".parse().unwrap();
+        ArrowSchemaAccessor::new(indexmap! {
+            table => Arc::new(Schema::new(vec![
+                Field::new("a", DataType::Int64, false),
+                Field::new("B", DataType::Utf8, false),
+                Field::new("c", DataType::Float32, false),
+                Field::new("Order Id", DataType::Decimal256(10, 2), false),
+            ])),
+        })
+    }
+
+    #[test]
+    fn we_can_lookup_columns_of_an_arrow_schema() {
+        let accessor = sample_arrow_schema_accessor();
+        let table: TableRef = "schema.table".parse().unwrap();
+        assert_eq!(
+            accessor.lookup_column(table, "a".parse().unwrap()),
+            Some(ColumnType::BigInt)
+        );
+        assert_eq!(
+            accessor.lookup_column(table, "b".parse().unwrap()),
+            Some(ColumnType::VarChar)
+        );
+        assert_eq!(
+            accessor.lookup_column(table, Identifier::try_new_quoted("Order Id").unwrap()),
+            Some(ColumnType::Decimal75(Precision::new(10).unwrap(), 2))
+        );
+        assert_eq!(
+            accessor.lookup_column(table, "not_a_col".parse().unwrap()),
+            None
+        );
+        assert_eq!(
+            accessor.lookup_column("schema.not_a_table".parse().unwrap(), "a".parse().unwrap()),
+            None
+        );
+    }
+
+    #[test]
+    fn we_treat_columns_with_unsupported_arrow_types_as_absent() {
+        let accessor = sample_arrow_schema_accessor();
+        let table: TableRef = "schema.table".parse().unwrap();
+        assert_eq!(accessor.lookup_column(table, "c".parse().unwrap()), None);
+        assert_eq!(
+            accessor.lookup_schema(table),
+            vec![
+                ("a".parse().unwrap(), ColumnType::BigInt),
+                ("b".parse().unwrap(), ColumnType::VarChar),
+                (
+                    Identifier::try_new_quoted("Order Id").unwrap(),
+                    ColumnType::Decimal75(Precision::new(10).unwrap(), 2)
+                ),
+            ]
+        );
+        assert_eq!(
+            accessor.lookup_schema("schema.not_a_table".parse().unwrap()),
+            vec![]
+        );
+    }
+}
@@ -20,6 +20,7 @@ impl From<&ColumnType> for DataType {
                 DataType::Decimal256(precision.value(), *scale)
             }
             ColumnType::VarChar => DataType::Utf8,
+            ColumnType::VarBinary => DataType::Binary,
             ColumnType::Scalar => unimplemented!("Cannot convert Scalar type to arrow type"),
             ColumnType::TimestampTZ(timeunit, timezone) => {
                 let arrow_timezone = Some(Arc::from(timezone.to_string()));
@@ -46,7 +47,7 @@ impl TryFrom<DataType> for ColumnType {
             DataType::Int16 => Ok(ColumnType::SmallInt),
             DataType::Int32 => Ok(ColumnType::Int),
             DataType::Int64 => Ok(ColumnType::BigInt),
-            DataType::Decimal128(38, 0) => Ok(ColumnType::Int128),
+            DataType::Decimal128(_, 0) => Ok(ColumnType::Int128),
             DataType::Decimal256(precision, scale) if precision <= 75 => {
                 Ok(ColumnType::Decimal75(Precision::new(precision)?, scale))
             }
@@ -63,10 +64,24 @@ impl TryFrom<DataType> for ColumnType {
                 ))
             }
             DataType::Utf8 => Ok(ColumnType::VarChar),
+            DataType::Binary | DataType::LargeBinary => Ok(ColumnType::VarBinary),
             _ => Err(format!("Unsupported arrow data type {data_type:?}")),
         }
     }
 }
+
+impl ColumnType {
+    /// Convert an arrow [`DataType`] to the corresponding [`ColumnType`], if there is one.
+    ///
+    /// This is the same conversion as `ColumnType::try_from(DataType)`, but borrows the data type.
+    ///
+    /// # Errors
+    /// Returns a description of the problem if the data type is not supported.
+    pub fn try_from_arrow(data_type: &DataType) -> Result<Self, String> {
+        Self::try_from(data_type.clone())
+    }
+}
+
 /// Convert [`ColumnField`] values to arrow Field
 impl From<&ColumnField> for Field {
     fn from(column_field: &ColumnField) -> Self {
@@ -1,5 +1,9 @@
 //! This module provides conversions and utilities for working with Arrow data structures.
 
+/// Module for a schema accessor backed by Arrow schemas.
+mod arrow_schema_accessor;
+pub use arrow_schema_accessor::ArrowSchemaAccessor;
+
 /// Module for handling conversion from Arrow arrays to columns.
 pub mod arrow_array_to_column_conversion;
 
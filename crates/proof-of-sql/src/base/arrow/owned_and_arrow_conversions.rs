@@ -2,15 +2,18 @@
 //! The mapping is as follows:
 //! `OwnedType` <-> `Array/ArrayRef`
 //! `OwnedTable` <-> `RecordBatch`
+//! `OwnedTable` <- Arrow IPC stream
 //! `Boolean` <-> `Boolean`
 //! `BigInt` <-> `Int64`
 //! `VarChar` <-> `Utf8/String`
-//! `Int128` <-> `Decimal128(38,0)`
+//! `VarBinary` <-> `Binary/LargeBinary`
+//! `Int128` <-> `Decimal128(p,0)`
 //! `Decimal75` <-> `S`
 //!
 //! Note: this converts `Int128` values to `Decimal128(38,0)`, which are backed by `i128`.
 //! This is because there is no `Int128` type in Arrow.
-//! This does not check that the values are less than 39 digits.
+//! The precision can be changed with [`ArrowConversionOptions`], and any `Decimal128` with scale 0 is read back as `Int128`.
+//! This does not check that the values fit in the precision.
 //! However, the actual arrow backing `i128` is the correct value.
 use super::scalar_and_i256_conversions::{convert_i256_to_scalar, convert_scalar_to_i256};
 use crate::base::{
@@ -19,15 +22,18 @@ use crate::base::{
     math::decimal::Precision,
     scalar::Scalar,
 };
-use alloc::sync::Arc;
+use alloc::{sync::Arc, vec::Vec};
 use arrow::{
     array::{
-        ArrayRef, BooleanArray, Decimal128Array, Decimal256Array, Int16Array, Int32Array,
-        Int64Array, Int8Array, StringArray, TimestampMicrosecondArray, TimestampMillisecondArray,
-        TimestampNanosecondArray, TimestampSecondArray,
+        ArrayRef, BinaryArray, BooleanArray, Decimal128Array, Decimal256Array, Int16Array,
+        Int32Array, Int64Array, Int8Array, LargeBinaryArray, StringArray,
+        TimestampMicrosecondArray, TimestampMillisecondArray, TimestampNanosecondArray,
+        TimestampSecondArray,
     },
+    compute::concat_batches,
     datatypes::{i256, DataType, Schema, SchemaRef, TimeUnit as ArrowTimeUnit},
     error::ArrowError,
+    ipc::reader::StreamReader,
     record_batch::RecordBatch,
 };
 use proof_of_sql_parser::{
@@ -49,6 +55,10 @@ pub enum OwnedArrowConversionError {
         datatype: DataType,
     },
     /// This error occurs when trying to convert from a record batch with duplicate identifiers (e.g. `"a"` and `"A"`).
+    ///
+    /// Field names that are valid unquoted identifiers are case-insensitive, as they are in SQL,
+    /// so `"a"` and `"A"` are duplicates. Any other field name (e.g. `"Order Id"`) is treated as a quoted identifier
+    /// and must match exactly. See [`Identifier::try_from_name`].
     #[snafu(display("conversion resulted in duplicate identifiers"))]
     DuplicateIdentifiers,
     /// This error occurs when convering from a record batch name to an identifier fails. (Which may my impossible.)
@@ -72,6 +82,34 @@ pub enum OwnedArrowConversionError {
         /// The underlying source error
         source: PoSQLTimestampError,
     },
+    /// This error occurs when reading or combining Arrow data fails, e.g. because an IPC stream is malformed.
+    #[snafu(transparent)]
+    ArrowError {
+        /// The underlying source error
+        source: ArrowError,
+    },
+}
+
+/// Options for converting owned types into Arrow types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArrowConversionOptions {
+    /// The precision of the `Decimal128` that `Int128` columns are exported as, which defaults to 38.
+    ///
+    /// The scale is always 0.
+    pub int128_precision: u8,
+    /// Whether tables are exported with their columns sorted by name, e.g. for stable diffs across producers.
+    ///
+    /// Defaults to `false`, which keeps the columns in insertion order.
+    pub sort_columns_by_name: bool,
+}
+
+impl Default for ArrowConversionOptions {
+    fn default() -> Self {
+        Self {
+            int128_precision: 38,
+            sort_columns_by_name: false,
+        }
+    }
 }
 
 /// # Panics
@@ -89,7 +127,7 @@ impl<S: Scalar> From<OwnedColumn<S>> for ArrayRef {
             OwnedColumn::BigInt(col) => Arc::new(Int64Array::from(col)),
             OwnedColumn::Int128(col) => Arc::new(
                 Decimal128Array::from(col)
-                    .with_precision_and_scale(38, 0)
+                    .with_precision_and_scale(ArrowConversionOptions::default().int128_precision, 0)
                     .unwrap(),
             ),
             OwnedColumn::Decimal75(precision, scale, col) => {
@@ -103,6 +141,7 @@ impl<S: Scalar> From<OwnedColumn<S>> for ArrayRef {
             }
             OwnedColumn::Scalar(_) => unimplemented!("Cannot convert Scalar type to arrow type"),
             OwnedColumn::VarChar(col) => Arc::new(StringArray::from(col)),
+            OwnedColumn::VarBinary(col) => Arc::new(BinaryArray::from_iter_values(col)),
             OwnedColumn::TimestampTZ(time_unit, _, col) => match time_unit {
                 PoSQLTimeUnit::Second => Arc::new(TimestampSecondArray::from(col)),
                 PoSQLTimeUnit::Millisecond => Arc::new(TimestampMillisecondArray::from(col)),
@@ -113,22 +152,67 @@ impl<S: Scalar> From<OwnedColumn<S>> for ArrayRef {
     }
 }
 
-impl<S: Scalar> TryFrom<OwnedTable<S>> for RecordBatch {
-    type Error = ArrowError;
-    fn try_from(value: OwnedTable<S>) -> Result<Self, Self::Error> {
-        if value.is_empty() {
+impl<S: Scalar> OwnedColumn<S> {
+    /// Converts the column into an Arrow array, using `options` to choose the Arrow types.
+    ///
+    /// # Errors
+    /// Returns an error if `options.int128_precision` is not a valid `Decimal128` precision, i.e. not in `1..=38`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic in the same cases as the conversion into [`ArrayRef`] using [`From`].
+    pub fn try_into_array_ref_with_options(
+        self,
+        options: &ArrowConversionOptions,
+    ) -> Result<ArrayRef, ArrowError> {
+        match self {
+            OwnedColumn::Int128(col) => Ok(Arc::new(
+                Decimal128Array::from(col).with_precision_and_scale(options.int128_precision, 0)?,
+            )),
+            column => Ok(ArrayRef::from(column)),
+        }
+    }
+}
+
+impl<S: Scalar> OwnedTable<S> {
+    /// Converts the table into a [`RecordBatch`], using `options` to choose the Arrow types.
+    ///
+    /// # Errors
+    /// Returns an error if a column can not be converted with `options`,
+    /// or if the record batch can not be created.
+    pub fn try_into_record_batch_with_options(
+        self,
+        options: &ArrowConversionOptions,
+    ) -> Result<RecordBatch, ArrowError> {
+        if self.is_empty() {
             Ok(RecordBatch::new_empty(SchemaRef::new(Schema::empty())))
         } else {
+            let mut table = self.into_inner();
+            if options.sort_columns_by_name {
+                table.sort_keys();
+            }
             RecordBatch::try_from_iter(
-                value
-                    .into_inner()
+                table
                     .into_iter()
-                    .map(|(identifier, owned_column)| (identifier, ArrayRef::from(owned_column))),
+                    .map(|(identifier, owned_column)| {
+                        Ok((
+                            identifier,
+                            owned_column.try_into_array_ref_with_options(options)?,
+                        ))
+                    })
+                    .collect::<Result<Vec<_>, ArrowError>>()?,
             )
         }
     }
 }
 
+impl<S: Scalar> TryFrom<OwnedTable<S>> for RecordBatch {
+    type Error = ArrowError;
+    fn try_from(value: OwnedTable<S>) -> Result<Self, Self::Error> {
+        value.try_into_record_batch_with_options(&ArrowConversionOptions::default())
+    }
+}
+
 impl<S: Scalar> TryFrom<ArrayRef> for OwnedColumn<S> {
     type Error = OwnedArrowConversionError;
     fn try_from(value: ArrayRef) -> Result<Self, Self::Error> {
@@ -146,9 +230,11 @@ impl<S: Scalar> TryFrom<&ArrayRef> for OwnedColumn<S> {
     /// - `Int16Array` when converting from `DataType::Int16`.
     /// - `Int32Array` when converting from `DataType::Int32`.
     /// - `Int64Array` when converting from `DataType::Int64`.
-    /// - `Decimal128Array` when converting from `DataType::Decimal128(38, 0)`.
+    /// - `Decimal128Array` when converting from `DataType::Decimal128(_, 0)`.
     /// - `Decimal256Array` when converting from `DataType::Decimal256` if precision is less than or equal to 75.
     /// - `StringArray` when converting from `DataType::Utf8`.
+    /// - `BinaryArray` when converting from `DataType::Binary`.
+    /// - `LargeBinaryArray` when converting from `DataType::LargeBinary`.
     fn try_from(value: &ArrayRef) -> Result<Self, Self::Error> {
         match &value.data_type() {
             // Arrow uses a bit-packed representation for booleans.
@@ -194,7 +280,7 @@ impl<S: Scalar> TryFrom<&ArrayRef> for OwnedColumn<S> {
                     .values()
                     .to_vec(),
             )),
-            DataType::Decimal128(38, 0) => Ok(Self::Int128(
+            DataType::Decimal128(_, 0) => Ok(Self::Int128(
                 value
                     .as_any()
                     .downcast_ref::<Decimal128Array>()
@@ -224,6 +310,26 @@ impl<S: Scalar> TryFrom<&ArrayRef> for OwnedColumn<S> {
                     .map(|s| s.unwrap().to_string())
                     .collect(),
             )),
+            DataType::Binary => Ok(Self::VarBinary(
+                value
+                    .as_any()
+                    .downcast_ref::<BinaryArray>()
+                    .unwrap()
+                    .iter()
+                    .map(|b| b.map(<[u8]>::to_vec))
+                    .collect::<Option<Vec<_>>>()
+                    .ok_or(OwnedArrowConversionError::NullNotSupportedYet)?,
+            )),
+            DataType::LargeBinary => Ok(Self::VarBinary(
+                value
+                    .as_any()
+                    .downcast_ref::<LargeBinaryArray>()
+                    .unwrap()
+                    .iter()
+                    .map(|b| b.map(<[u8]>::to_vec))
+                    .collect::<Option<Vec<_>>>()
+                    .ok_or(OwnedArrowConversionError::NullNotSupportedYet)?,
+            )),
             DataType::Timestamp(time_unit, timezone) => match time_unit {
                 ArrowTimeUnit::Second => {
                     let array = value
@@ -300,7 +406,7 @@ impl<S: Scalar> TryFrom<RecordBatch> for OwnedTable<S> {
             .zip(value.columns())
             .map(|(field, array_ref)| {
                 let owned_column = OwnedColumn::try_from(array_ref)?;
-                let identifier = Identifier::try_new(field.name())?; //This may always succeed.
+                let identifier = Identifier::try_from_name(field.name())?;
                 Ok((identifier, owned_column))
             })
             .collect();
@@ -312,3 +418,22 @@ impl<S: Scalar> TryFrom<RecordBatch> for OwnedTable<S> {
         }
     }
 }
+
+impl<S: Scalar> OwnedTable<S> {
+    /// Reads an Arrow IPC stream into an `OwnedTable`.
+    ///
+    /// All record batches of the stream are concatenated into a single table,
+    /// which is then converted the same way as a single [`RecordBatch`].
+    ///
+    /// # Errors
+    /// Returns an error if the stream can not be read,
+    /// or if its data can not be converted, e.g. because a column has an unsupported type.
+    pub fn try_from_ipc_reader(
+        reader: impl std::io::Read,
+    ) -> Result<Self, OwnedArrowConversionError> {
+        let stream = StreamReader::try_new(reader, None)?;
+        let schema = stream.schema();
+        let batches = stream.collect::<Result<Vec<_>, _>>()?;
+        Self::try_from(concat_batches(&schema, &batches)?)
+    }
+}
@@ -1,18 +1,24 @@
-use super::owned_and_arrow_conversions::OwnedArrowConversionError;
+use super::owned_and_arrow_conversions::{ArrowConversionOptions, OwnedArrowConversionError};
 use crate::{
     base::{
         database::{owned_table_utility::*, OwnedColumn, OwnedTable},
         map::IndexMap,
+        math::decimal::Precision,
         scalar::Curve25519Scalar,
     },
     record_batch,
 };
 use alloc::sync::Arc;
 use arrow::{
-    array::{ArrayRef, BooleanArray, Decimal128Array, Float32Array, Int64Array, StringArray},
-    datatypes::Schema,
+    array::{
+        ArrayRef, BinaryArray, BooleanArray, Decimal128Array, Decimal256Array, Float32Array,
+        Int64Array, LargeBinaryArray, StringArray,
+    },
+    datatypes::{DataType, Schema},
+    ipc::writer::StreamWriter,
     record_batch::RecordBatch,
 };
+use proof_of_sql_parser::posql_time::{PoSQLTimeUnit, PoSQLTimeZone};
 
 fn we_can_convert_between_owned_column_and_array_ref_impl(
     owned_column: &OwnedColumn<Curve25519Scalar>,
@@ -52,12 +58,19 @@ fn we_can_convert_between_varchar_owned_column_and_array_ref_impl(data: Vec<Stri
         Arc::new(StringArray::from(data)),
     );
 }
+fn we_can_convert_between_varbinary_owned_column_and_array_ref_impl(data: Vec<Vec<u8>>) {
+    we_can_convert_between_owned_column_and_array_ref_impl(
+        &OwnedColumn::<Curve25519Scalar>::VarBinary(data.clone()),
+        Arc::new(BinaryArray::from_iter_values(data)),
+    );
+}
 #[test]
 fn we_can_convert_between_owned_column_and_array_ref() {
     we_can_convert_between_boolean_owned_column_and_array_ref_impl(vec![]);
     we_can_convert_between_bigint_owned_column_and_array_ref_impl(vec![]);
     we_can_convert_between_int128_owned_column_and_array_ref_impl(vec![]);
     we_can_convert_between_varchar_owned_column_and_array_ref_impl(vec![]);
+    we_can_convert_between_varbinary_owned_column_and_array_ref_impl(vec![]);
     let data = vec![true, false, true, false, true, false, true, false, true];
     we_can_convert_between_boolean_owned_column_and_array_ref_impl(data);
     let data = vec![0, 1, 2, 3, 4, 5, 6, i64::MIN, i64::MAX];
@@ -68,6 +81,169 @@ fn we_can_convert_between_owned_column_and_array_ref() {
     we_can_convert_between_varchar_owned_column_and_array_ref_impl(
         data.into_iter().map(String::from).collect(),
     );
+    let data = vec![
+        vec![],
+        vec![0xff, 0xfe, 0x00],
+        b"abc".to_vec(),
+        vec![0xc3, 0x28],
+    ];
+    we_can_convert_between_varbinary_owned_column_and_array_ref_impl(data);
+}
+
+#[test]
+fn we_can_convert_a_large_binary_array_ref_to_an_owned_column() {
+    let data = vec![
+        vec![],
+        vec![0xff, 0xfe, 0x00],
+        b"abc".to_vec(),
+        vec![0xc3, 0x28],
+    ];
+    let array_ref: ArrayRef = Arc::new(LargeBinaryArray::from_iter_values(data.clone()));
+    assert_eq!(
+        OwnedColumn::<Curve25519Scalar>::try_from(array_ref).unwrap(),
+        OwnedColumn::VarBinary(data)
+    );
+}
+
+#[test]
+fn we_get_a_null_error_when_converting_a_binary_array_ref_with_nulls_to_an_owned_column() {
+    let array_ref: ArrayRef = Arc::new(BinaryArray::from_opt_vec(vec![Some(b"a"), None]));
+    assert!(matches!(
+        OwnedColumn::<Curve25519Scalar>::try_from(array_ref),
+        Err(OwnedArrowConversionError::NullNotSupportedYet)
+    ));
+}
+
+#[test]
+fn we_can_round_trip_a_negative_scale_decimal_through_arrow() {
+    let owned_column = OwnedColumn::<Curve25519Scalar>::Decimal75(
+        Precision::new(5).unwrap(),
+        -2,
+        [7, -3, 0, 99999].map(Curve25519Scalar::from).to_vec(),
+    );
+    let array_ref = ArrayRef::from(owned_column.clone());
+    assert_eq!(array_ref.data_type(), &DataType::Decimal256(5, -2));
+    assert_eq!(
+        array_ref
+            .as_any()
+            .downcast_ref::<Decimal256Array>()
+            .unwrap()
+            .value_as_string(0),
+        "700"
+    );
+    assert_eq!(OwnedColumn::try_from(array_ref).unwrap(), owned_column);
+
+    let table: OwnedTable<Curve25519Scalar> = owned_table([decimal75("a", 5, -2, [7, -3])]);
+    let batch = RecordBatch::try_from(table.clone()).unwrap();
+    assert_eq!(OwnedTable::try_from(batch).unwrap(), table);
+}
+
+#[test]
+fn we_can_export_int128_columns_with_a_custom_precision() {
+    let options = ArrowConversionOptions {
+        int128_precision: 30,
+        ..Default::default()
+    };
+    let array_ref = OwnedColumn::<Curve25519Scalar>::Int128(vec![0, -1, 10_i128.pow(29)])
+        .try_into_array_ref_with_options(&options)
+        .unwrap();
+    assert_eq!(array_ref.data_type(), &DataType::Decimal128(30, 0));
+
+    let record_batch = owned_table::<Curve25519Scalar>([int128("a", [1, 2]), bigint("b", [3, 4])])
+        .try_into_record_batch_with_options(&options)
+        .unwrap();
+    assert_eq!(
+        record_batch.schema().field(0).data_type(),
+        &DataType::Decimal128(30, 0)
+    );
+    assert_eq!(record_batch.schema().field(1).data_type(), &DataType::Int64);
+
+    // The default options match the plain conversion
+    assert_eq!(
+        OwnedColumn::<Curve25519Scalar>::Int128(vec![1])
+            .try_into_array_ref_with_options(&ArrowConversionOptions::default())
+            .unwrap()
+            .data_type(),
+        &DataType::Decimal128(38, 0)
+    );
+}
+
+#[test]
+fn we_can_export_a_table_with_its_columns_sorted_by_name() {
+    let unsorted = owned_table::<Curve25519Scalar>([
+        varchar("c", ["x", "y"]),
+        bigint("a", [1, 2]),
+        int128("d", [3, 4]),
+        boolean("b", [true, false]),
+    ]);
+    let sorted = owned_table::<Curve25519Scalar>([
+        bigint("a", [1, 2]),
+        boolean("b", [true, false]),
+        varchar("c", ["x", "y"]),
+        int128("d", [3, 4]),
+    ]);
+    let options = ArrowConversionOptions {
+        sort_columns_by_name: true,
+        ..Default::default()
+    };
+    let record_batch = unsorted
+        .clone()
+        .try_into_record_batch_with_options(&options)
+        .unwrap();
+    assert_eq!(record_batch, RecordBatch::try_from(sorted.clone()).unwrap());
+    assert_eq!(
+        record_batch,
+        sorted.try_into_record_batch_with_options(&options).unwrap()
+    );
+
+    let record_batch = RecordBatch::try_from(unsorted).unwrap();
+    let names: Vec<_> = record_batch
+        .schema()
+        .fields()
+        .iter()
+        .map(|field| field.name().clone())
+        .collect();
+    assert_eq!(names, ["c", "a", "d", "b"]);
+}
+
+#[test]
+fn we_cannot_export_int128_columns_with_an_invalid_precision() {
+    let options = ArrowConversionOptions {
+        int128_precision: 39,
+        ..Default::default()
+    };
+    assert!(OwnedColumn::<Curve25519Scalar>::Int128(vec![1])
+        .try_into_array_ref_with_options(&options)
+        .is_err());
+    assert!(owned_table::<Curve25519Scalar>([int128("a", [1])])
+        .try_into_record_batch_with_options(&options)
+        .is_err());
+}
+
+#[test]
+fn we_can_import_a_decimal128_with_scale_zero_of_any_precision_as_int128() {
+    let array_ref: ArrayRef = Arc::new(
+        Decimal128Array::from(vec![0, -7, 10_i128.pow(29)])
+            .with_precision_and_scale(30, 0)
+            .unwrap(),
+    );
+    assert_eq!(
+        OwnedColumn::<Curve25519Scalar>::try_from(array_ref).unwrap(),
+        OwnedColumn::Int128(vec![0, -7, 10_i128.pow(29)])
+    );
+}
+
+#[test]
+fn we_cannot_import_a_decimal128_with_a_nonzero_scale_as_int128() {
+    let array_ref: ArrayRef = Arc::new(
+        Decimal128Array::from(vec![1, 2])
+            .with_precision_and_scale(38, 2)
+            .unwrap(),
+    );
+    assert!(matches!(
+        OwnedColumn::<Curve25519Scalar>::try_from(array_ref),
+        Err(OwnedArrowConversionError::UnsupportedType { .. })
+    ));
 }
 
 #[test]
@@ -129,6 +305,69 @@ fn we_can_convert_between_owned_table_and_record_batch() {
     );
 }
 
+#[test]
+fn we_can_round_trip_a_table_with_every_arrow_compatible_column_type() {
+    let table: OwnedTable<Curve25519Scalar> = owned_table([
+        boolean("boolean", [true, false, true]),
+        tinyint("tinyint", [i8::MIN, 0, i8::MAX]),
+        smallint("smallint", [i16::MIN, 0, i16::MAX]),
+        int("int", [i32::MIN, 0, i32::MAX]),
+        bigint("bigint", [i64::MIN, 0, i64::MAX]),
+        int128("int128", [i128::MIN, 0, i128::MAX]),
+        decimal75("decimal", 12, 4, [-1, 0, 123_456]),
+        varchar("varchar", ["", "a", "héllo"]),
+        varbinary("varbinary", [&[][..], &[0], &[1, 2, 255]]),
+        timestamptz(
+            "event_time",
+            PoSQLTimeUnit::Millisecond,
+            PoSQLTimeZone::Utc,
+            [-1, 0, 1_625_072_400_000],
+        ),
+    ]);
+    let batch = RecordBatch::try_from(table.clone()).unwrap();
+    assert_eq!(batch.num_columns(), table.num_columns());
+    assert_eq!(batch.num_rows(), 3);
+    assert_eq!(OwnedTable::try_from(batch).unwrap(), table);
+}
+
+#[test]
+fn we_can_convert_between_owned_table_and_record_batch_with_quoted_column_names() {
+    we_can_convert_between_owned_table_and_record_batch_impl(
+        &owned_table([
+            bigint(r#""Order Id""#, [1, 2, 3]),
+            varchar(r#""Total $""#, ["a", "b", "c"]),
+            boolean("flag", [true, false, true]),
+        ]),
+        &record_batch!(
+            "Order Id" => [1_i64, 2, 3],
+            "Total $" => ["a", "b", "c"],
+            "flag" => [true, false, true],
+        ),
+    );
+}
+
+#[test]
+fn we_can_convert_a_record_batch_with_unquoted_column_names_to_lowercase() {
+    let record_batch = record_batch!(
+        "A" => [0_i64; 0],
+    );
+    let table = OwnedTable::<Curve25519Scalar>::try_from(record_batch).unwrap();
+    assert_eq!(table, owned_table([bigint("a", [0; 0])]));
+}
+
+#[test]
+fn we_can_convert_a_record_batch_with_quoted_column_names_differing_in_case() {
+    let record_batch = record_batch!(
+        "a b" => [0_i64; 0],
+        "A B" => [0_i128; 0],
+    );
+    let table = OwnedTable::<Curve25519Scalar>::try_from(record_batch).unwrap();
+    assert_eq!(
+        table,
+        owned_table([bigint(r#""a b""#, [0; 0]), int128(r#""A B""#, [0; 0])])
+    );
+}
+
 #[test]
 fn we_cannot_convert_a_record_batch_if_it_has_repeated_column_names() {
     let record_batch = record_batch!(
@@ -147,3 +386,52 @@ fn we_panic_when_converting_an_owned_table_with_a_scalar_column() {
     let owned_table = owned_table::<Curve25519Scalar>([scalar("a", [0; 0])]);
     let _ = RecordBatch::try_from(owned_table);
 }
+
+fn ipc_stream(batches: &[RecordBatch]) -> Vec<u8> {
+    let mut writer = StreamWriter::try_new(Vec::new(), &batches[0].schema()).unwrap();
+    for batch in batches {
+        writer.write(batch).unwrap();
+    }
+    writer.into_inner().unwrap()
+}
+
+#[test]
+fn we_can_read_an_owned_table_from_an_ipc_stream_with_multiple_batches() {
+    let stream = ipc_stream(&[
+        record_batch!(
+            "a" => [1_i64, 2],
+            "b" => ["x", "y"],
+        ),
+        record_batch!(
+            "a" => [3_i64],
+            "b" => ["z"],
+        ),
+    ]);
+    let table = OwnedTable::<Curve25519Scalar>::try_from_ipc_reader(&stream[..]).unwrap();
+    assert_eq!(
+        table,
+        owned_table([bigint("a", [1, 2, 3]), varchar("b", ["x", "y", "z"])])
+    );
+}
+
+#[test]
+fn we_cannot_read_an_owned_table_from_an_ipc_stream_with_an_unsupported_type() {
+    let record_batch = RecordBatch::try_from_iter([(
+        "a",
+        Arc::new(Float32Array::from(vec![1.0_f32, 2.0])) as ArrayRef,
+    )])
+    .unwrap();
+    let stream = ipc_stream(&[record_batch]);
+    assert!(matches!(
+        OwnedTable::<Curve25519Scalar>::try_from_ipc_reader(&stream[..]),
+        Err(OwnedArrowConversionError::UnsupportedType { .. })
+    ));
+}
+
+#[test]
+fn we_cannot_read_an_owned_table_from_a_malformed_ipc_stream() {
+    assert!(matches!(
+        OwnedTable::<Curve25519Scalar>::try_from_ipc_reader(&b"not an ipc stream"[..]),
+        Err(OwnedArrowConversionError::ArrowError { .. })
+    ));
+}
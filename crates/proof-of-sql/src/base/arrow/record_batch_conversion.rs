@@ -27,7 +27,7 @@ pub fn batch_to_columns<'a, S: Scalar + 'a>(
         .into_iter()
         .zip(batch.columns())
         .map(|(field, array)| {
-            let identifier: Identifier = field.name().parse()?;
+            let identifier = Identifier::try_from_name(field.name())?;
             let column: Column<S> = array.to_column(alloc, &(0..array.len()), None)?;
             Ok((identifier, column))
         })
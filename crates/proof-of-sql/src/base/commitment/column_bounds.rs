@@ -236,6 +236,7 @@ impl ColumnBounds {
             | CommittableColumn::Decimal75(_, _, _)
             | CommittableColumn::Scalar(_)
             | CommittableColumn::VarChar(_)
+            | CommittableColumn::VarBinary(_)
             | CommittableColumn::RangeCheckWord(_) => ColumnBounds::NoOrder,
         }
     }
@@ -1,4 +1,6 @@
 use super::CommitmentEvaluationProof;
+#[cfg(feature = "curve25519-cpu")]
+use super::NaiveCurve25519EvaluationProof;
 use crate::base::{commitment::vec_commitment_ext::VecCommitmentExt, database::Column};
 use ark_std::UniformRand;
 #[cfg(feature = "blitzar")]
@@ -278,3 +280,28 @@ fn test_random_ipa_with_length_2() {
     test_random_commitment_evaluation_proof::<InnerProductProof>(2, 2, &(), &());
     test_random_commitment_evaluation_proof::<InnerProductProof>(2, 200, &(), &());
 }
+
+#[test]
+#[cfg(feature = "curve25519-cpu")]
+fn test_simple_naive_curve25519_evaluation_proof() {
+    test_simple_commitment_evaluation_proof::<NaiveCurve25519EvaluationProof>(&(), &());
+}
+
+#[test]
+#[cfg(feature = "curve25519-cpu")]
+fn test_random_naive_curve25519_evaluation_proof_with_length_1() {
+    test_commitment_evaluation_proof_with_length_1::<NaiveCurve25519EvaluationProof>(&(), &());
+}
+
+#[test]
+#[cfg(feature = "curve25519-cpu")]
+fn test_random_naive_curve25519_evaluation_proof() {
+    for (table_length, offset) in [(2, 0), (5, 1), (16, 10), (50, 32), (128, 200)] {
+        test_random_commitment_evaluation_proof::<NaiveCurve25519EvaluationProof>(
+            table_length,
+            offset,
+            &(),
+            &(),
+        );
+    }
+}
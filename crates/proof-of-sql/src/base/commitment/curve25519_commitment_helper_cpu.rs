@@ -0,0 +1,82 @@
+use super::CommittableColumn;
+use crate::base::scalar::Curve25519Scalar;
+use alloc::vec::Vec;
+use curve25519_dalek::{ristretto::RistrettoPoint, scalar::Scalar, traits::VartimeMultiscalarMul};
+
+/// The domain separation context used to derive the generators from their index.
+const GENERATOR_CONTEXT: &str = "proof-of-sql curve25519 generators v1";
+
+/// Derive the `index`-th generator used by the pure-Rust curve25519 commitment backend.
+///
+/// The generator is obtained by hashing the index with a domain-separated blake3 XOF
+/// and mapping the 64 uniform bytes to a Ristretto point, so nobody knows the discrete log relation
+/// between any two generators.
+#[must_use]
+pub fn get_curve25519_generator(index: u64) -> RistrettoPoint {
+    let mut hasher = blake3::Hasher::new_derive_key(GENERATOR_CONTEXT);
+    hasher.update(&index.to_le_bytes());
+    let mut uniform_bytes = [0u8; 64];
+    hasher.finalize_xof().fill(&mut uniform_bytes);
+    RistrettoPoint::from_uniform_bytes(&uniform_bytes)
+}
+
+/// Fill `generators` with the generators used by the pure-Rust curve25519 commitment backend,
+/// starting from the `offset`-th one.
+///
+/// This mirrors `blitzar::compute::get_curve25519_generators`, although the generators themselves differ from blitzar's.
+pub fn get_curve25519_generators(generators: &mut [RistrettoPoint], offset: u64) {
+    for (generator, index) in generators.iter_mut().zip(offset..) {
+        *generator = get_curve25519_generator(index);
+    }
+}
+
+fn compute_curve25519_commitment_impl<'a, T>(column: &'a [T], offset: usize) -> RistrettoPoint
+where
+    &'a T: Into<Curve25519Scalar>,
+{
+    let scalars = column.iter().map(|s| Scalar::from(s.into()));
+    let generators = (offset as u64..)
+        .take(column.len())
+        .map(get_curve25519_generator);
+    RistrettoPoint::vartime_multiscalar_mul(scalars, generators)
+}
+
+fn compute_curve25519_commitment(
+    committable_column: &CommittableColumn,
+    offset: usize,
+) -> RistrettoPoint {
+    match committable_column {
+        CommittableColumn::Scalar(column)
+        | CommittableColumn::Decimal75(_, _, column)
+        | CommittableColumn::VarChar(column)
+        | CommittableColumn::VarBinary(column) => {
+            compute_curve25519_commitment_impl(column, offset)
+        }
+        CommittableColumn::TinyInt(column) => compute_curve25519_commitment_impl(column, offset),
+        CommittableColumn::SmallInt(column) => compute_curve25519_commitment_impl(column, offset),
+        CommittableColumn::Int(column) => compute_curve25519_commitment_impl(column, offset),
+        CommittableColumn::BigInt(column) | CommittableColumn::TimestampTZ(_, _, column) => {
+            compute_curve25519_commitment_impl(column, offset)
+        }
+        CommittableColumn::Int128(column) => compute_curve25519_commitment_impl(column, offset),
+        CommittableColumn::Boolean(column) => compute_curve25519_commitment_impl(column, offset),
+        CommittableColumn::RangeCheckWord(column) => {
+            compute_curve25519_commitment_impl(column, offset)
+        }
+    }
+}
+
+/// Compute Pedersen commitments to the given columns on the CPU, without blitzar.
+///
+/// `offset` is the index of the generator used for the first row of each column.
+/// For the same data and generators, this matches `blitzar::compute::compute_curve25519_commitments_with_generators`.
+#[must_use]
+pub fn compute_curve25519_commitments(
+    committable_columns: &[CommittableColumn],
+    offset: usize,
+) -> Vec<RistrettoPoint> {
+    committable_columns
+        .iter()
+        .map(|column| compute_curve25519_commitment(column, offset))
+        .collect()
+}
@@ -0,0 +1,59 @@
+use super::{curve25519_commitment_helper_cpu, Commitment, CommittableColumn};
+use crate::base::scalar::Curve25519Scalar;
+use alloc::vec::Vec;
+use core::ops::Mul;
+use curve25519_dalek::ristretto::RistrettoPoint;
+use derive_more::{AddAssign, Neg, Sub, SubAssign};
+use serde::{Deserialize, Serialize};
+
+/// A Pedersen commitment over curve25519 computed by the pure-Rust backend.
+///
+/// The generators of this backend differ from blitzar's, so these commitments are a distinct type
+/// from the [`RistrettoPoint`] commitments computed by blitzar and can not be mixed up with them.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    AddAssign,
+    SubAssign,
+    Neg,
+    Sub,
+    Serialize,
+    Deserialize,
+)]
+pub struct Curve25519CpuCommitment(pub RistrettoPoint);
+
+// Traits required for `Curve25519CpuCommitment` to impl `Commitment`.
+impl Mul<Curve25519CpuCommitment> for Curve25519Scalar {
+    type Output = Curve25519CpuCommitment;
+    fn mul(self, rhs: Curve25519CpuCommitment) -> Self::Output {
+        Curve25519CpuCommitment(self * rhs.0)
+    }
+}
+impl<'a> Mul<&'a Curve25519CpuCommitment> for Curve25519Scalar {
+    type Output = Curve25519CpuCommitment;
+    fn mul(self, rhs: &'a Curve25519CpuCommitment) -> Self::Output {
+        Curve25519CpuCommitment(self * rhs.0)
+    }
+}
+impl Commitment for Curve25519CpuCommitment {
+    type Scalar = Curve25519Scalar;
+    type PublicSetup<'a> = ();
+
+    fn compute_commitments(
+        committable_columns: &[CommittableColumn],
+        offset: usize,
+        _setup: &Self::PublicSetup<'_>,
+    ) -> Vec<Self> {
+        curve25519_commitment_helper_cpu::compute_curve25519_commitments(
+            committable_columns,
+            offset,
+        )
+        .into_iter()
+        .map(Self)
+        .collect()
+    }
+}
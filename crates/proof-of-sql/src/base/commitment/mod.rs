@@ -129,6 +129,27 @@ impl Commitment for RistrettoPoint {
     }
 }
 
+/// A pure-Rust curve25519 commitment backend that does not depend on blitzar.
+///
+/// Note that the generators of this backend differ from blitzar's,
+/// so its commitments are the distinct [`Curve25519CpuCommitment`] type rather than [`RistrettoPoint`].
+#[cfg(feature = "curve25519-cpu")]
+pub mod curve25519_commitment_helper_cpu;
+
+#[cfg(feature = "curve25519-cpu")]
+mod curve25519_cpu_commitment;
+#[cfg(feature = "curve25519-cpu")]
+pub use curve25519_cpu_commitment::Curve25519CpuCommitment;
+
+#[cfg(all(any(test, feature = "test"), feature = "curve25519-cpu"))]
+mod naive_curve25519_evaluation_proof;
+#[cfg(all(any(test, feature = "test"), feature = "curve25519-cpu"))]
+pub use naive_curve25519_evaluation_proof::{
+    NaiveCurve25519EvaluationProof, NaiveCurve25519EvaluationProofError,
+};
+#[cfg(all(test, feature = "curve25519-cpu"))]
+mod naive_curve25519_evaluation_proof_test;
+
 mod commitment_evaluation_proof;
 pub use commitment_evaluation_proof::CommitmentEvaluationProof;
 #[cfg(test)]
@@ -143,7 +143,8 @@ impl Commitment for NaiveCommitment {
                     CommittableColumn::Scalar(scalar_vec) => {
                         scalar_vec.iter().map(core::convert::Into::into).collect()
                     }
-                    CommittableColumn::VarChar(varchar_vec) => {
+                    CommittableColumn::VarChar(varchar_vec)
+                    | CommittableColumn::VarBinary(varchar_vec) => {
                         varchar_vec.iter().map(core::convert::Into::into).collect()
                     }
                     CommittableColumn::TimestampTZ(_, _, i64_vec) => {
@@ -0,0 +1,130 @@
+use super::{Commitment, CommitmentEvaluationProof, CommittableColumn, Curve25519CpuCommitment};
+use crate::base::{
+    polynomial::compute_evaluation_vector,
+    proof::Transcript,
+    scalar::{Curve25519Scalar, Scalar},
+};
+use alloc::{vec, vec::Vec};
+use serde::{Deserialize, Serialize};
+use snafu::Snafu;
+
+/// A pure-Rust evaluation proof for [`Curve25519CpuCommitment`]s that does not depend on blitzar.
+///
+/// The proof simply reveals the committed vector, so it is neither succinct nor zero-knowledge.
+/// The verifier recomputes the commitment and the inner product itself.
+/// This makes it useful for testing the full prove/verify cycle without blitzar,
+/// but it should not be used to produce proofs for untrusted parties, and it is only available
+/// in tests and with the `test` feature.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NaiveCurve25519EvaluationProof {
+    a: Vec<Curve25519Scalar>,
+    challenge: Curve25519Scalar,
+}
+
+/// Errors that can occur when verifying a [`NaiveCurve25519EvaluationProof`].
+#[derive(Snafu, Debug)]
+pub enum NaiveCurve25519EvaluationProofError {
+    /// The proof was not created with the same transcript.
+    #[snafu(display("transcript mismatch"))]
+    TranscriptMismatch,
+    /// The revealed vector is longer than the table.
+    #[snafu(display("revealed vector is longer than the table"))]
+    LengthMismatch,
+    /// The revealed vector does not match the batched commitment.
+    #[snafu(display("commitment mismatch"))]
+    CommitmentMismatch,
+    /// The revealed vector does not evaluate to the claimed product.
+    #[snafu(display("product mismatch"))]
+    ProductMismatch,
+}
+
+/// Append the revealed vector and the evaluation point to the transcript, and draw a challenge
+/// that binds the proof to the transcript state.
+fn challenge(
+    transcript: &mut impl Transcript,
+    a: &[Curve25519Scalar],
+    b_point: &[Curve25519Scalar],
+    generators_offset: u64,
+) -> Curve25519Scalar {
+    transcript.extend_as_be([generators_offset]);
+    transcript.extend_scalars_as_be(a);
+    transcript.extend_scalars_as_be(b_point);
+    transcript.scalar_challenge_as_be()
+}
+
+/// The expanded vector form of `b_point` of the given length.
+fn evaluation_vector(b_point: &[Curve25519Scalar], length: usize) -> Vec<Curve25519Scalar> {
+    let mut b = vec![Curve25519Scalar::default(); length];
+    if b_point.is_empty() {
+        if let Some(first) = b.first_mut() {
+            *first = Curve25519Scalar::ONE;
+        }
+    } else {
+        compute_evaluation_vector(&mut b, b_point);
+    }
+    b
+}
+
+impl CommitmentEvaluationProof for NaiveCurve25519EvaluationProof {
+    type Scalar = Curve25519Scalar;
+    type Commitment = Curve25519CpuCommitment;
+    type Error = NaiveCurve25519EvaluationProofError;
+    type ProverPublicSetup<'a> = ();
+    type VerifierPublicSetup<'a> = ();
+
+    fn new(
+        transcript: &mut impl Transcript,
+        a: &[Self::Scalar],
+        b_point: &[Self::Scalar],
+        generators_offset: u64,
+        _setup: &Self::ProverPublicSetup<'_>,
+    ) -> Self {
+        Self {
+            a: a.to_vec(),
+            challenge: challenge(transcript, a, b_point, generators_offset),
+        }
+    }
+
+    fn verify_batched_proof(
+        &self,
+        transcript: &mut impl Transcript,
+        commit_batch: &[Self::Commitment],
+        batching_factors: &[Self::Scalar],
+        product: &Self::Scalar,
+        b_point: &[Self::Scalar],
+        generators_offset: u64,
+        table_length: usize,
+        _setup: &Self::VerifierPublicSetup<'_>,
+    ) -> Result<(), Self::Error> {
+        if challenge(transcript, &self.a, b_point, generators_offset) != self.challenge {
+            return Err(NaiveCurve25519EvaluationProofError::TranscriptMismatch);
+        }
+        if self.a.len() > table_length {
+            return Err(NaiveCurve25519EvaluationProofError::LengthMismatch);
+        }
+        let batched_commitment: Curve25519CpuCommitment =
+            commit_batch.iter().zip(batching_factors).fold(
+                Curve25519CpuCommitment::default(),
+                |mut acc, (commitment, factor)| {
+                    acc += *factor * commitment;
+                    acc
+                },
+            );
+        let committable_a = CommittableColumn::from(&self.a[..]);
+        let expected_commitment = Curve25519CpuCommitment::compute_commitments(
+            &[committable_a],
+            usize::try_from(generators_offset)
+                .map_err(|_| NaiveCurve25519EvaluationProofError::CommitmentMismatch)?,
+            &(),
+        );
+        if expected_commitment[..] != [batched_commitment] {
+            return Err(NaiveCurve25519EvaluationProofError::CommitmentMismatch);
+        }
+        let b = evaluation_vector(b_point, table_length);
+        let evaluation: Curve25519Scalar = self.a.iter().zip(&b).map(|(a, b)| *a * *b).sum();
+        if evaluation != *product {
+            return Err(NaiveCurve25519EvaluationProofError::ProductMismatch);
+        }
+        Ok(())
+    }
+}
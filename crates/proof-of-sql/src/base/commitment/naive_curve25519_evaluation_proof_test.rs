@@ -0,0 +1,110 @@
+use super::{
+    curve25519_commitment_helper_cpu::get_curve25519_generators, Curve25519CpuCommitment,
+    NaiveCurve25519EvaluationProof,
+};
+use crate::{
+    base::{
+        commitment::CommittableColumn,
+        database::{owned_table_utility::*, OwnedTableTestAccessor, TestAccessor},
+    },
+    sql::{
+        proof::VerifiableQueryResult,
+        proof_exprs::test_utility::*,
+        proof_plans::{test_utility::*, DynProofPlan},
+    },
+};
+use curve25519_dalek::RistrettoPoint;
+
+#[test]
+fn we_can_prove_and_verify_a_projection_with_the_naive_curve25519_evaluation_proof() {
+    let data = owned_table([
+        bigint("a", [1_i64, 4, 5, 2, 5]),
+        varchar("b", ["1", "2", "3", "4", "5"]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor =
+        OwnedTableTestAccessor::<NaiveCurve25519EvaluationProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 3);
+    let ast: DynProofPlan<Curve25519CpuCommitment> = projection(
+        vec![
+            aliased_plan(add(column(t, "a", &accessor), const_bigint(1)), "c"),
+            col_expr_plan(t, "b", &accessor),
+        ],
+        tab(t),
+    );
+    let verifiable_res =
+        VerifiableQueryResult::<NaiveCurve25519EvaluationProof>::new(&ast, &accessor, &());
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected = owned_table([
+        bigint("c", [2_i64, 5, 6, 3, 6]),
+        varchar("b", ["1", "2", "3", "4", "5"]),
+    ]);
+    assert_eq!(res, expected);
+
+    // The proof must not verify against a different offset
+    accessor.update_offset(t, 2);
+    assert!(verifiable_res.verify(&ast, &accessor, &()).is_err());
+}
+
+#[test]
+fn we_get_distinct_generators_that_are_consistent_across_offsets() {
+    let mut generators = [RistrettoPoint::default(); 4];
+    get_curve25519_generators(&mut generators, 0);
+    let mut offset_generators = [RistrettoPoint::default(); 2];
+    get_curve25519_generators(&mut offset_generators, 2);
+    assert_eq!(offset_generators, generators[2..]);
+    for (i, generator) in generators.iter().enumerate() {
+        assert_ne!(*generator, RistrettoPoint::default());
+        assert!(!generators[..i].contains(generator));
+    }
+}
+
+#[cfg(feature = "blitzar")]
+#[test]
+fn we_get_the_same_commitments_as_blitzar_for_the_same_data_and_generators() {
+    use super::curve25519_commitment_helper_cpu::compute_curve25519_commitments;
+    use crate::base::scalar::Curve25519Scalar;
+    use curve25519_dalek::ristretto::CompressedRistretto;
+
+    let offset = 3;
+    let scalars = [Curve25519Scalar::from(7), Curve25519Scalar::from(-3)];
+    let committable_columns = [
+        CommittableColumn::BigInt(&[1, -2, 3]),
+        CommittableColumn::Boolean(&[true, false, true]),
+        CommittableColumn::Int128(&[i128::MAX, 0, i128::MIN]),
+        CommittableColumn::from(&scalars[..]),
+    ];
+    let mut generators = [RistrettoPoint::default(); 3];
+    get_curve25519_generators(&mut generators, offset as u64);
+    let mut blitzar_commitments = [CompressedRistretto::default(); 4];
+    let sequences: Vec<_> = committable_columns.iter().map(Into::into).collect();
+    blitzar::compute::compute_curve25519_commitments_with_generators(
+        &mut blitzar_commitments,
+        &sequences,
+        &generators,
+    );
+    let commitments = compute_curve25519_commitments(&committable_columns, offset);
+    assert_eq!(
+        commitments
+            .iter()
+            .map(RistrettoPoint::compress)
+            .collect::<Vec<_>>(),
+        blitzar_commitments
+    );
+}
+
+#[test]
+fn we_compute_cpu_commitments_with_the_cpu_generators() {
+    use super::{curve25519_commitment_helper_cpu::get_curve25519_generator, Commitment};
+    use crate::base::scalar::Curve25519Scalar;
+    use curve25519_dalek::scalar::Scalar;
+
+    let commitments = Curve25519CpuCommitment::compute_commitments(
+        &[CommittableColumn::BigInt(&[2, -1])],
+        1,
+        &(),
+    );
+    let expected = Scalar::from(Curve25519Scalar::from(2)) * get_curve25519_generator(1)
+        - get_curve25519_generator(2);
+    assert_eq!(commitments, [Curve25519CpuCommitment(expected)]);
+}
@@ -20,6 +20,10 @@ pub trait MetadataAccessor {
     ///
     /// If the data span has its first row starting at the ith table row,
     /// this `get_offset` should then return `i`.
+    ///
+    /// This is the offset the table's column commitments were computed with,
+    /// e.g. the `offset` passed to [`TestAccessor::add_table`](super::TestAccessor::add_table),
+    /// so verifiers can use it to align commitments with the generators.
     fn get_offset(&self, table_ref: TableRef) -> usize;
 }
 
@@ -84,7 +88,18 @@ pub trait CommitmentAccessor<C: Commitment>: MetadataAccessor {
 /// will only be accessing information about columns that exist in the database.
 pub trait DataAccessor<S: Scalar>: MetadataAccessor {
     /// Return the data span in the table (not the full-table data)
+    ///
+    /// The returned column borrows from the accessor for as long as the accessor is borrowed,
+    /// and the prover only ever reads from it. Accessors backed by in-memory data should
+    /// return a view into their storage rather than a copy, so proving does not clone large columns.
     fn get_column(&self, column: ColumnRef) -> Column<S>;
+
+    /// Hint that the given columns are about to be read with [`Self::get_column`]
+    ///
+    /// The prover calls this once per plan, with every column the plan references, before evaluating the plan.
+    /// Accessors backed by a remote store can use it to load the columns in one batch.
+    /// The default implementation does nothing.
+    fn prefetch(&self, _column_refs: &[ColumnRef]) {}
 }
 
 /// Access tables and their schemas in a database.
@@ -0,0 +1,83 @@
+use super::{
+    Column, ColumnRef, ColumnType, CommitmentAccessor, DataAccessor, MetadataAccessor,
+    SchemaAccessor, TableRef,
+};
+use crate::base::{commitment::Commitment, map::IndexMap, scalar::Scalar};
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use proof_of_sql_parser::Identifier;
+
+/// An accessor that wraps another accessor and caches the column commitments it returns.
+///
+/// When many queries are proven and verified over the same static tables,
+/// wrapping the accessor in a single [`CachingCommitmentAccessor`] means each column commitment
+/// is computed only once. Everything else, including the column data used by the prover,
+/// is passed through unchanged, so proofs are identical to those made with the inner accessor.
+///
+/// The cache is never invalidated, so the wrapped tables must not change while it is in use.
+pub struct CachingCommitmentAccessor<'a, C: Commitment, A> {
+    accessor: &'a A,
+    commitments: RefCell<IndexMap<ColumnRef, C>>,
+}
+
+impl<'a, C: Commitment, A> CachingCommitmentAccessor<'a, C, A> {
+    /// Creates a new caching accessor around `accessor` with an empty cache.
+    pub fn new(accessor: &'a A) -> Self {
+        Self {
+            accessor,
+            commitments: RefCell::new(IndexMap::default()),
+        }
+    }
+
+    /// Returns the number of column commitments currently cached.
+    pub fn num_cached_commitments(&self) -> usize {
+        self.commitments.borrow().len()
+    }
+}
+
+impl<C: Commitment, A: MetadataAccessor> MetadataAccessor for CachingCommitmentAccessor<'_, C, A> {
+    fn get_length(&self, table_ref: TableRef) -> usize {
+        self.accessor.get_length(table_ref)
+    }
+
+    fn get_offset(&self, table_ref: TableRef) -> usize {
+        self.accessor.get_offset(table_ref)
+    }
+}
+
+impl<C: Commitment, A: CommitmentAccessor<C>> CommitmentAccessor<C>
+    for CachingCommitmentAccessor<'_, C, A>
+{
+    fn get_commitment(&self, column: ColumnRef) -> C {
+        if let Some(commitment) = self.commitments.borrow().get(&column) {
+            return commitment.clone();
+        }
+        let commitment = self.accessor.get_commitment(column);
+        self.commitments
+            .borrow_mut()
+            .insert(column, commitment.clone());
+        commitment
+    }
+}
+
+impl<S: Scalar, C: Commitment, A: DataAccessor<S>> DataAccessor<S>
+    for CachingCommitmentAccessor<'_, C, A>
+{
+    fn get_column(&self, column: ColumnRef) -> Column<S> {
+        self.accessor.get_column(column)
+    }
+
+    fn prefetch(&self, column_refs: &[ColumnRef]) {
+        self.accessor.prefetch(column_refs);
+    }
+}
+
+impl<C: Commitment, A: SchemaAccessor> SchemaAccessor for CachingCommitmentAccessor<'_, C, A> {
+    fn lookup_column(&self, table_ref: TableRef, column_id: Identifier) -> Option<ColumnType> {
+        self.accessor.lookup_column(table_ref, column_id)
+    }
+
+    fn lookup_schema(&self, table_ref: TableRef) -> Vec<(Identifier, ColumnType)> {
+        self.accessor.lookup_schema(table_ref)
+    }
+}
@@ -0,0 +1,103 @@
+use super::{
+    CachingCommitmentAccessor, Column, ColumnRef, ColumnType, CommitmentAccessor, DataAccessor,
+    MetadataAccessor, OwnedTableTestAccessor, SchemaAccessor, TableRef, TestAccessor,
+};
+use crate::{
+    base::{database::owned_table_utility::*, scalar::Curve25519Scalar},
+    sql::{
+        proof::VerifiableQueryResult, proof_exprs::test_utility::*, proof_plans::test_utility::*,
+    },
+};
+use blitzar::proof::InnerProductProof;
+use core::cell::Cell;
+use curve25519_dalek::RistrettoPoint;
+use proof_of_sql_parser::Identifier;
+
+/// Counts how many commitments are computed by the wrapped accessor.
+struct CountingCommitmentAccessor<'a> {
+    accessor: OwnedTableTestAccessor<'a, InnerProductProof>,
+    num_commitments_computed: Cell<usize>,
+}
+
+impl MetadataAccessor for CountingCommitmentAccessor<'_> {
+    fn get_length(&self, table_ref: TableRef) -> usize {
+        self.accessor.get_length(table_ref)
+    }
+    fn get_offset(&self, table_ref: TableRef) -> usize {
+        self.accessor.get_offset(table_ref)
+    }
+}
+
+impl CommitmentAccessor<RistrettoPoint> for CountingCommitmentAccessor<'_> {
+    fn get_commitment(&self, column: ColumnRef) -> RistrettoPoint {
+        self.num_commitments_computed
+            .set(self.num_commitments_computed.get() + 1);
+        self.accessor.get_commitment(column)
+    }
+}
+
+impl DataAccessor<Curve25519Scalar> for CountingCommitmentAccessor<'_> {
+    fn get_column(&self, column: ColumnRef) -> Column<Curve25519Scalar> {
+        self.accessor.get_column(column)
+    }
+}
+
+impl SchemaAccessor for CountingCommitmentAccessor<'_> {
+    fn lookup_column(&self, table_ref: TableRef, column_id: Identifier) -> Option<ColumnType> {
+        self.accessor.lookup_column(table_ref, column_id)
+    }
+    fn lookup_schema(&self, table_ref: TableRef) -> Vec<(Identifier, ColumnType)> {
+        self.accessor.lookup_schema(table_ref)
+    }
+}
+
+#[test]
+fn we_can_prove_and_verify_repeated_queries_with_cached_commitments() {
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(
+        t,
+        owned_table([bigint("a", [1, 2, 3]), varchar("b", ["x", "y", "z"])]),
+        0,
+    );
+    let expr = projection(cols_expr_plan(t, &["a", "b"], &accessor), tab(t));
+    let fresh_res = VerifiableQueryResult::<InnerProductProof>::new(&expr, &accessor, &());
+
+    let counting_accessor = CountingCommitmentAccessor {
+        accessor,
+        num_commitments_computed: Cell::new(0),
+    };
+    let caching_accessor = CachingCommitmentAccessor::new(&counting_accessor);
+    let first_res = VerifiableQueryResult::new(&expr, &caching_accessor, &());
+    let second_res = VerifiableQueryResult::new(&expr, &caching_accessor, &());
+    assert!(first_res == fresh_res);
+    assert!(second_res == fresh_res);
+
+    let first_table = first_res
+        .verify(&expr, &caching_accessor, &())
+        .unwrap()
+        .table;
+    let second_table = second_res
+        .verify(&expr, &caching_accessor, &())
+        .unwrap()
+        .table;
+    let expected = owned_table([bigint("a", [1, 2, 3]), varchar("b", ["x", "y", "z"])]);
+    assert_eq!(first_table, expected);
+    assert_eq!(second_table, expected);
+    assert_eq!(counting_accessor.num_commitments_computed.get(), 2);
+    assert_eq!(caching_accessor.num_cached_commitments(), 2);
+}
+
+#[test]
+fn cached_commitments_are_the_same_as_computed_commitments() {
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, owned_table([bigint("a", [1, 2, 3])]), 2);
+    let caching_accessor = CachingCommitmentAccessor::new(&accessor);
+    let column = ColumnRef::new(t, "a".parse().unwrap(), ColumnType::BigInt);
+    let expected = accessor.get_commitment(column);
+    assert_eq!(caching_accessor.get_commitment(column), expected);
+    assert_eq!(caching_accessor.get_commitment(column), expected);
+    assert_eq!(caching_accessor.get_length(t), 3);
+    assert_eq!(caching_accessor.get_offset(t), 2);
+}
@@ -1,4 +1,4 @@
-use super::{LiteralValue, OwnedColumn, TableRef};
+use super::{try_add_subtract_column_types, LiteralValue, OwnedColumn, TableRef};
 use crate::base::{
     math::decimal::Precision,
     scalar::{Scalar, ScalarExt},
@@ -12,6 +12,7 @@ use core::{
     mem::size_of,
 };
 use proof_of_sql_parser::{
+    intermediate_ast::BinaryOperator,
     posql_time::{PoSQLTimeUnit, PoSQLTimeZone},
     Identifier,
 };
@@ -47,6 +48,10 @@ pub enum Column<'a, S: Scalar> {
     ///  - the first element maps to the str values.
     ///  - the second element maps to the str hashes (see [`crate::base::scalar::Scalar`]).
     VarChar((&'a [&'a str], &'a [S])),
+    /// Binary columns
+    ///  - the first element maps to the byte values.
+    ///  - the second element maps to the byte hashes (see [`crate::base::scalar::ScalarExt::from_byte_slice_via_hash`]).
+    VarBinary((&'a [&'a [u8]], &'a [S])),
     /// Timestamp columns with timezone
     /// - the first element maps to the stored `TimeUnit`
     /// - the second element maps to a timezone
@@ -65,6 +70,7 @@ impl<'a, S: Scalar> Column<'a, S> {
             Self::Int(_) => ColumnType::Int,
             Self::BigInt(_) => ColumnType::BigInt,
             Self::VarChar(_) => ColumnType::VarChar,
+            Self::VarBinary(_) => ColumnType::VarBinary,
             Self::Int128(_) => ColumnType::Int128,
             Self::Scalar(_) => ColumnType::Scalar,
             Self::Decimal75(precision, scale, _) => ColumnType::Decimal75(*precision, *scale),
@@ -88,6 +94,10 @@ impl<'a, S: Scalar> Column<'a, S> {
                 assert_eq!(col.len(), scals.len());
                 col.len()
             }
+            Self::VarBinary((col, scals)) => {
+                assert_eq!(col.len(), scals.len());
+                col.len()
+            }
             Self::Int128(col) => col.len(),
             Self::Scalar(col) | Self::Decimal75(_, _, col) => col.len(),
         }
@@ -136,6 +146,10 @@ impl<'a, S: Scalar> Column<'a, S> {
                 alloc.alloc_slice_fill_with(length, |_| alloc.alloc_str(string) as &str),
                 alloc.alloc_slice_fill_copy(length, *scalar),
             )),
+            LiteralValue::VarBinary((bytes, scalar)) => Column::VarBinary((
+                alloc.alloc_slice_fill_with(length, |_| alloc.alloc_slice_copy(bytes) as &[u8]),
+                alloc.alloc_slice_fill_copy(length, *scalar),
+            )),
         }
     }
 
@@ -163,6 +177,17 @@ impl<'a, S: Scalar> Column<'a, S> {
                     alloc.alloc_slice_copy(scalars.as_slice()),
                 ))
             }
+            OwnedColumn::VarBinary(col) => {
+                let scalars = col
+                    .iter()
+                    .map(|b| S::from_byte_slice_via_hash(b))
+                    .collect::<Vec<_>>();
+                let bytes = col.iter().map(Vec::as_slice).collect::<Vec<_>>();
+                Column::VarBinary((
+                    alloc.alloc_slice_clone(bytes.as_slice()),
+                    alloc.alloc_slice_copy(scalars.as_slice()),
+                ))
+            }
             OwnedColumn::TimestampTZ(tu, tz, col) => Column::TimestampTZ(*tu, *tz, col.as_slice()),
         }
     }
@@ -185,7 +210,7 @@ impl<'a, S: Scalar> Column<'a, S> {
             Self::BigInt(col) => alloc.alloc_slice_fill_with(col.len(), |i| S::from(col[i])),
             Self::Int128(col) => alloc.alloc_slice_fill_with(col.len(), |i| S::from(col[i])),
             Self::Scalar(col) | Self::Decimal75(_, _, col) => col,
-            Self::VarChar((_, scals)) => scals,
+            Self::VarChar((_, scals)) | Self::VarBinary((_, scals)) => scals,
             Self::TimestampTZ(_, _, col) => {
                 alloc.alloc_slice_fill_with(col.len(), |i| S::from(col[i]))
             }
@@ -204,18 +229,20 @@ impl<'a, S: Scalar> Column<'a, S> {
             Self::BigInt(col) | Self::TimestampTZ(_, _, col) => S::from(col[index]),
             Self::Int128(col) => S::from(col[index]),
             Self::Scalar(col) | Self::Decimal75(_, _, col) => col[index],
-            Self::VarChar((_, scals)) => scals[index],
+            Self::VarChar((_, scals)) | Self::VarBinary((_, scals)) => scals[index],
         })
     }
 
     /// Convert a column to a vector of Scalar values with scaling
     #[allow(clippy::missing_panics_doc)]
     pub(crate) fn to_scalar_with_scaling(self, scale: i8) -> Vec<S> {
-        let scale_factor = S::pow10(u8::try_from(scale).expect("Upscale factor is nonnegative"));
+        let scale_factor = S::pow10(u32::try_from(scale).expect("Upscale factor is nonnegative"));
         match self {
             Self::Boolean(col) => slice_cast_with(col, |b| S::from(b) * scale_factor),
             Self::Decimal75(_, _, col) => slice_cast_with(col, |s| *s * scale_factor),
-            Self::VarChar((_, values)) => slice_cast_with(values, |s| *s * scale_factor),
+            Self::VarChar((_, values)) | Self::VarBinary((_, values)) => {
+                slice_cast_with(values, |s| *s * scale_factor)
+            }
             Self::TinyInt(col) => slice_cast_with(col, |i| S::from(i) * scale_factor),
             Self::SmallInt(col) => slice_cast_with(col, |i| S::from(i) * scale_factor),
             Self::Int(col) => slice_cast_with(col, |i| S::from(i) * scale_factor),
@@ -255,6 +282,9 @@ pub enum ColumnType {
     /// Mapped to String
     #[serde(alias = "VARCHAR", alias = "varchar")]
     VarChar,
+    /// Mapped to Vec<u8>
+    #[serde(alias = "VARBINARY", alias = "varbinary")]
+    VarBinary,
     /// Mapped to i256
     #[serde(rename = "Decimal75", alias = "DECIMAL75", alias = "decimal75")]
     Decimal75(Precision, i8),
@@ -337,6 +367,27 @@ impl ColumnType {
         })
     }
 
+    /// Returns the type that values of both types can be unified to, as in a `UNION`, if there is one.
+    ///
+    /// Numeric types are promoted as in [`try_add_subtract_column_types`], i.e. to the type of their sum,
+    /// except that a decimal result does not get the extra carry digit that a sum may need.
+    /// If the sum is not representable, e.g. because it would need a precision above the supported maximum,
+    /// the types can not be unified.
+    /// Non-numeric types can only be unified with themselves.
+    #[must_use]
+    pub fn union_type(&self, other: &Self) -> Option<Self> {
+        if self == other {
+            return Some(*self);
+        }
+        match try_add_subtract_column_types(*self, *other, BinaryOperator::Add).ok()? {
+            ColumnType::Decimal75(precision, scale) => Some(ColumnType::Decimal75(
+                Precision::new(precision.value() - 1).ok()?,
+                scale,
+            )),
+            column_type => Some(column_type),
+        }
+    }
+
     /// Returns the precision of a [`ColumnType`] if it is converted to a decimal wrapped in `Some()`. If it can not be converted to a decimal, return None.
     #[must_use]
     pub fn precision_value(&self) -> Option<u8> {
@@ -350,7 +401,7 @@ impl ColumnType {
             // Scalars are not in database & are only used for typeless comparisons for testing so we return 0
             // so that they do not cause errors when used in comparisons.
             Self::Scalar => Some(0_u8),
-            Self::Boolean | Self::VarChar => None,
+            Self::Boolean | Self::VarChar | Self::VarBinary => None,
         }
     }
     /// Returns scale of a [`ColumnType`] if it is convertible to a decimal wrapped in `Some()`. Otherwise return None.
@@ -364,7 +415,7 @@ impl ColumnType {
             | Self::BigInt
             | Self::Int128
             | Self::Scalar => Some(0),
-            Self::Boolean | Self::VarChar => None,
+            Self::Boolean | Self::VarChar | Self::VarBinary => None,
             Self::TimestampTZ(tu, _) => match tu {
                 PoSQLTimeUnit::Second => Some(0),
                 PoSQLTimeUnit::Millisecond => Some(3),
@@ -384,7 +435,9 @@ impl ColumnType {
             Self::Int => size_of::<i32>(),
             Self::BigInt | Self::TimestampTZ(_, _) => size_of::<i64>(),
             Self::Int128 => size_of::<i128>(),
-            Self::Scalar | Self::Decimal75(_, _) | Self::VarChar => size_of::<[u64; 4]>(),
+            Self::Scalar | Self::Decimal75(_, _) | Self::VarChar | Self::VarBinary => {
+                size_of::<[u64; 4]>()
+            }
         }
     }
 
@@ -405,7 +458,11 @@ impl ColumnType {
             | Self::BigInt
             | Self::Int128
             | Self::TimestampTZ(_, _) => true,
-            Self::Decimal75(_, _) | Self::Scalar | Self::VarChar | Self::Boolean => false,
+            Self::Decimal75(_, _)
+            | Self::Scalar
+            | Self::VarChar
+            | Self::VarBinary
+            | Self::Boolean => false,
         }
     }
 }
@@ -428,6 +485,7 @@ impl Display for ColumnType {
                 )
             }
             ColumnType::VarChar => write!(f, "VARCHAR"),
+            ColumnType::VarBinary => write!(f, "VARBINARY"),
             ColumnType::Scalar => write!(f, "SCALAR"),
             ColumnType::TimestampTZ(timeunit, timezone) => {
                 write!(f, "TIMESTAMP(TIMEUNIT: {timeunit}, TIMEZONE: {timezone})")
@@ -442,6 +500,7 @@ pub struct ColumnRef {
     column_id: Identifier,
     table_ref: TableRef,
     column_type: ColumnType,
+    table_alias: Option<Identifier>,
 }
 
 impl ColumnRef {
@@ -452,9 +511,28 @@ impl ColumnRef {
             column_id,
             table_ref,
             column_type,
+            table_alias: None,
         }
     }
 
+    /// Returns this column reference qualified by the alias of its table.
+    ///
+    /// References to the same column through different aliases are distinct,
+    /// e.g. `l.a` and `r.a` in a self-join, while still reading the data of the same table.
+    #[must_use]
+    pub fn with_table_alias(self, table_alias: Identifier) -> Self {
+        Self {
+            table_alias: Some(table_alias),
+            ..self
+        }
+    }
+
+    /// Returns the alias of the table of this column, if any
+    #[must_use]
+    pub fn table_alias(&self) -> Option<Identifier> {
+        self.table_alias
+    }
+
     /// Returns the table reference of this column
     #[must_use]
     pub fn table_ref(&self) -> TableRef {
@@ -502,6 +580,22 @@ impl ColumnField {
     pub fn data_type(&self) -> ColumnType {
         self.data_type
     }
+
+    /// Returns the field resulting from a `UNION` of this field with `other`, if their types can be unified.
+    ///
+    /// The result keeps the name of this field and has the type given by [`ColumnType::union_type`].
+    #[must_use]
+    pub fn union_field(&self, other: &ColumnField) -> Option<ColumnField> {
+        self.data_type
+            .union_type(&other.data_type)
+            .map(|data_type| ColumnField::new(self.name, data_type))
+    }
+
+    /// Returns whether the types of this field and `other` can be unified in a `UNION`.
+    #[must_use]
+    pub fn is_union_compatible(&self, other: &ColumnField) -> bool {
+        self.union_field(other).is_some()
+    }
 }
 
 #[cfg(test)]
@@ -1016,4 +1110,90 @@ mod tests {
         assert_eq!(column.column_type().byte_size(), 8);
         assert_eq!(column.column_type().bit_size(), 64);
     }
+
+    #[test]
+    fn we_can_unify_integer_types_by_widening() {
+        assert_eq!(
+            ColumnType::BigInt.union_type(&ColumnType::Int128),
+            Some(ColumnType::Int128)
+        );
+        assert_eq!(
+            ColumnType::SmallInt.union_type(&ColumnType::TinyInt),
+            Some(ColumnType::SmallInt)
+        );
+        assert_eq!(
+            ColumnType::Int.union_type(&ColumnType::Int),
+            Some(ColumnType::Int)
+        );
+        assert_eq!(
+            ColumnType::Int.union_type(&ColumnType::Scalar),
+            Some(ColumnType::Scalar)
+        );
+    }
+
+    #[test]
+    fn we_can_unify_decimal_types_with_differing_scales() {
+        let decimal =
+            |precision, scale| ColumnType::Decimal75(Precision::new(precision).unwrap(), scale);
+        // 3 integer digits and 2 fractional digits, or 1 integer digit and 4 fractional digits
+        assert_eq!(
+            decimal(5, 2).union_type(&decimal(5, 4)),
+            Some(decimal(7, 4))
+        );
+        assert_eq!(
+            decimal(5, 4).union_type(&decimal(5, 2)),
+            Some(decimal(7, 4))
+        );
+        assert_eq!(
+            decimal(10, -2).union_type(&decimal(3, 1)),
+            Some(decimal(13, 1))
+        );
+        assert_eq!(
+            ColumnType::BigInt.union_type(&decimal(5, 2)),
+            Some(decimal(21, 2))
+        );
+        assert_eq!(
+            decimal(74, 0).union_type(&ColumnType::BigInt),
+            Some(decimal(74, 0))
+        );
+        // The sum would need 76 digits, so the types can not be unified
+        assert_eq!(decimal(75, 0).union_type(&ColumnType::BigInt), None);
+        assert_eq!(decimal(75, 0).union_type(&decimal(75, 1)), None);
+        assert_eq!(ColumnType::Int128.union_type(&decimal(75, 40)), None);
+    }
+
+    #[test]
+    fn we_cannot_unify_incompatible_types() {
+        assert_eq!(ColumnType::VarChar.union_type(&ColumnType::BigInt), None);
+        assert_eq!(ColumnType::Boolean.union_type(&ColumnType::TinyInt), None);
+        assert_eq!(ColumnType::VarChar.union_type(&ColumnType::VarBinary), None);
+        assert_eq!(
+            ColumnType::TimestampTZ(PoSQLTimeUnit::Second, PoSQLTimeZone::Utc).union_type(
+                &ColumnType::TimestampTZ(PoSQLTimeUnit::Millisecond, PoSQLTimeZone::Utc)
+            ),
+            None
+        );
+        assert_eq!(
+            ColumnType::VarChar.union_type(&ColumnType::VarChar),
+            Some(ColumnType::VarChar)
+        );
+    }
+
+    #[test]
+    fn we_can_get_the_union_field_of_two_fields() {
+        let a = ColumnField::new("a".parse().unwrap(), ColumnType::BigInt);
+        let b = ColumnField::new("b".parse().unwrap(), ColumnType::Int128);
+        let c = ColumnField::new("c".parse().unwrap(), ColumnType::VarChar);
+        assert!(a.is_union_compatible(&b));
+        assert_eq!(
+            a.union_field(&b),
+            Some(ColumnField::new("a".parse().unwrap(), ColumnType::Int128))
+        );
+        assert_eq!(
+            b.union_field(&a),
+            Some(ColumnField::new("b".parse().unwrap(), ColumnType::Int128))
+        );
+        assert!(!a.is_union_compatible(&c));
+        assert_eq!(c.union_field(&a), None);
+    }
 }
@@ -550,7 +550,7 @@ where
                 .collect::<Vec<_>>()
         } else {
             let upscale_factor =
-                S::pow10(u8::try_from(upscale).expect("Upscale factor is nonnegative"));
+                S::pow10(u32::try_from(upscale).expect("Upscale factor is nonnegative"));
             lhs.iter()
                 .zip(rhs.iter())
                 .map(|(l, r)| -> bool { Into::<S>::into(*l) * upscale_factor == *r })
@@ -571,7 +571,7 @@ where
                 .collect::<Vec<_>>()
         } else {
             let upscale_factor =
-                S::pow10(u8::try_from(upscale).expect("Upscale factor is nonnegative"));
+                S::pow10(u32::try_from(upscale).expect("Upscale factor is nonnegative"));
             lhs.iter()
                 .zip(rhs.iter())
                 .map(|(l, r)| -> bool { Into::<S>::into(*l) == *r * upscale_factor })
@@ -626,7 +626,7 @@ where
                 .collect::<Vec<_>>()
         } else {
             let upscale_factor =
-                S::pow10(u8::try_from(upscale).expect("Upscale factor is nonnegative"));
+                S::pow10(u32::try_from(upscale).expect("Upscale factor is nonnegative"));
             lhs.iter()
                 .zip(rhs.iter())
                 .map(|(l, r)| -> bool {
@@ -654,7 +654,7 @@ where
                 .collect::<Vec<_>>()
         } else {
             let upscale_factor =
-                S::pow10(u8::try_from(upscale).expect("Upscale factor is nonnegative"));
+                S::pow10(u32::try_from(upscale).expect("Upscale factor is nonnegative"));
             lhs.iter()
                 .zip(rhs.iter())
                 .map(|(l, r)| -> bool {
@@ -711,7 +711,7 @@ where
                 .collect::<Vec<_>>()
         } else {
             let upscale_factor =
-                S::pow10(u8::try_from(upscale).expect("Upscale factor is nonnegative"));
+                S::pow10(u32::try_from(upscale).expect("Upscale factor is nonnegative"));
             lhs.iter()
                 .zip(rhs.iter())
                 .map(|(l, r)| -> bool {
@@ -739,7 +739,7 @@ where
                 .collect::<Vec<_>>()
         } else {
             let upscale_factor =
-                S::pow10(u8::try_from(upscale).expect("Upscale factor is nonnegative"));
+                S::pow10(u32::try_from(upscale).expect("Upscale factor is nonnegative"));
             lhs.iter()
                 .zip(rhs.iter())
                 .map(|(l, r)| -> bool {
@@ -789,14 +789,14 @@ where
     // One of left_scale and right_scale is 0 so we can avoid scaling when unnecessary
     let scalars: Vec<S> = if left_upscale > 0 {
         let upscale_factor =
-            S::pow10(u8::try_from(left_upscale).expect("Upscale factor is nonnegative"));
+            S::pow10(u32::try_from(left_upscale).expect("Upscale factor is nonnegative"));
         lhs.iter()
             .zip(rhs)
             .map(|(l, r)| S::from(*l) * upscale_factor + S::from(*r))
             .collect()
     } else if right_upscale > 0 {
         let upscale_factor =
-            S::pow10(u8::try_from(right_upscale).expect("Upscale factor is nonnegative"));
+            S::pow10(u32::try_from(right_upscale).expect("Upscale factor is nonnegative"));
         lhs.iter()
             .zip(rhs)
             .map(|(l, r)| S::from(*l) + upscale_factor * S::from(*r))
@@ -851,14 +851,14 @@ where
     // One of left_scale and right_scale is 0 so we can avoid scaling when unnecessary
     let scalars: Vec<S> = if left_upscale > 0 {
         let upscale_factor =
-            S::pow10(u8::try_from(left_upscale).expect("Upscale factor is nonnegative"));
+            S::pow10(u32::try_from(left_upscale).expect("Upscale factor is nonnegative"));
         lhs.iter()
             .zip(rhs)
             .map(|(l, r)| S::from(*l) * upscale_factor - S::from(*r))
             .collect()
     } else if right_upscale > 0 {
         let upscale_factor =
-            S::pow10(u8::try_from(right_upscale).expect("Upscale factor is nonnegative"));
+            S::pow10(u32::try_from(right_upscale).expect("Upscale factor is nonnegative"));
         lhs.iter()
             .zip(rhs)
             .map(|(l, r)| S::from(*l) - upscale_factor * S::from(*r))
@@ -1,7 +1,10 @@
 use crate::base::{
-    database::{Column, ColumnType, LiteralValue},
+    database::{
+        Column, ColumnType, LiteralValue, OwnedColumn, OwnedColumnError, OwnedColumnResult,
+    },
     scalar::Scalar,
 };
+use alloc::string::ToString;
 use bumpalo::Bump;
 use snafu::Snafu;
 
@@ -37,6 +40,54 @@ impl<'a, S: Scalar> ColumnarValue<'a, S> {
         }
     }
 
+    /// Returns the number of values if this is a [`ColumnarValue::Column`],
+    /// or `None` if this is a [`ColumnarValue::Literal`], whose size is indeterminate
+    #[must_use]
+    pub fn len(&self) -> Option<usize> {
+        match self {
+            Self::Column(column) => Some(column.len()),
+            Self::Literal(_) => None,
+        }
+    }
+
+    /// Returns whether the [`ColumnarValue::Column`] is empty,
+    /// or `None` if this is a [`ColumnarValue::Literal`], whose size is indeterminate
+    #[must_use]
+    pub fn is_empty(&self) -> Option<bool> {
+        self.len().map(|len| len == 0)
+    }
+
+    /// Returns true if this is a single value with indeterminate size, i.e. a [`ColumnarValue::Literal`]
+    #[must_use]
+    pub fn is_scalar(&self) -> bool {
+        matches!(self, Self::Literal(_))
+    }
+
+    /// Returns true if this is a list of values, i.e. a [`ColumnarValue::Column`]
+    #[must_use]
+    pub fn is_array(&self) -> bool {
+        matches!(self, Self::Column(_))
+    }
+
+    /// Wraps an [`OwnedColumn`] as a [`ColumnarValue::Column`]
+    pub fn from_owned_column(owned_column: &'a OwnedColumn<S>, alloc: &'a Bump) -> Self {
+        Self::Column(Column::from_owned_column(owned_column, alloc))
+    }
+
+    /// Converts a [`ColumnarValue::Column`] to an [`OwnedColumn`]
+    ///
+    /// # Errors
+    /// Returns [`OwnedColumnError::Unsupported`] for a [`ColumnarValue::Literal`], whose length is
+    /// indeterminate. Use [`Self::into_column`] to give it a length first.
+    pub fn try_into_owned_column(&self) -> OwnedColumnResult<OwnedColumn<S>> {
+        match self {
+            Self::Column(column) => Ok(OwnedColumn::from(column)),
+            Self::Literal(_) => Err(OwnedColumnError::Unsupported {
+                error: "a literal cannot be converted to a column without a length".to_string(),
+            }),
+        }
+    }
+
     /// Converts the [`ColumnarValue`] to a [`Column`]
     pub fn into_column(
         &self,
@@ -64,8 +115,10 @@ impl<'a, S: Scalar> ColumnarValue<'a, S> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::base::scalar::test_scalar::TestScalar;
+    use crate::base::{math::decimal::Precision, scalar::test_scalar::TestScalar};
+    use alloc::{string::String, vec, vec::Vec};
     use core::convert::Into;
+    use proof_of_sql_parser::posql_time::{PoSQLTimeUnit, PoSQLTimeZone};
 
     #[test]
     fn we_can_get_column_type_of_columnar_values() {
@@ -76,6 +129,25 @@ mod tests {
         assert_eq!(column.column_type(), ColumnType::Boolean);
     }
 
+    #[test]
+    fn we_can_tell_scalar_columnar_values_from_array_columnar_values() {
+        let column = ColumnarValue::Column(Column::<TestScalar>::Int(&[1, 2, 3]));
+        assert!(column.is_array());
+        assert!(!column.is_scalar());
+        assert_eq!(column.len(), Some(3));
+
+        let column = ColumnarValue::Column(Column::<TestScalar>::Boolean(&[]));
+        assert!(column.is_array());
+        assert_eq!(column.len(), Some(0));
+        assert_eq!(column.is_empty(), Some(true));
+
+        let literal = ColumnarValue::Literal(LiteralValue::<TestScalar>::BigInt(7));
+        assert!(literal.is_scalar());
+        assert!(!literal.is_array());
+        assert_eq!(literal.len(), None);
+        assert_eq!(literal.is_empty(), None);
+    }
+
     #[test]
     fn we_can_transform_columnar_values_into_columns() {
         let bump = Bump::new();
@@ -135,4 +207,53 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn we_can_round_trip_owned_columns_through_columnar_values() {
+        let bump = Bump::new();
+        let columns: Vec<OwnedColumn<TestScalar>> = vec![
+            OwnedColumn::Boolean(vec![true, false]),
+            OwnedColumn::TinyInt(vec![1, -2]),
+            OwnedColumn::SmallInt(vec![3, -4]),
+            OwnedColumn::Int(vec![5, -6]),
+            OwnedColumn::BigInt(vec![7, -8]),
+            OwnedColumn::Int128(vec![9, -10]),
+            OwnedColumn::Decimal75(
+                Precision::new(10).unwrap(),
+                2,
+                vec![TestScalar::from(11), TestScalar::from(-12)],
+            ),
+            OwnedColumn::Scalar(vec![TestScalar::from(13), TestScalar::from(-14)]),
+            OwnedColumn::VarChar(vec!["a".to_string(), String::new()]),
+            OwnedColumn::VarBinary(vec![vec![1, 2], vec![]]),
+            OwnedColumn::TimestampTZ(PoSQLTimeUnit::Second, PoSQLTimeZone::Utc, vec![15, -16]),
+            OwnedColumn::BigInt(vec![]),
+        ];
+        for owned_column in &columns {
+            let columnar_value = ColumnarValue::from_owned_column(owned_column, &bump);
+            assert!(columnar_value.is_array());
+            assert_eq!(columnar_value.column_type(), owned_column.column_type());
+            assert_eq!(columnar_value.len(), Some(owned_column.len()));
+            assert_eq!(
+                columnar_value.try_into_owned_column().as_ref(),
+                Ok(owned_column)
+            );
+        }
+    }
+
+    #[test]
+    fn we_cannot_convert_a_literal_columnar_value_to_an_owned_column() {
+        let literal = ColumnarValue::Literal(LiteralValue::<TestScalar>::BigInt(7));
+        assert!(matches!(
+            literal.try_into_owned_column(),
+            Err(OwnedColumnError::Unsupported { .. })
+        ));
+
+        let bump = Bump::new();
+        let column = literal.into_column(2, &bump).unwrap();
+        assert_eq!(
+            ColumnarValue::Column(column).try_into_owned_column(),
+            Ok(OwnedColumn::BigInt(vec![7, 7]))
+        );
+    }
 }
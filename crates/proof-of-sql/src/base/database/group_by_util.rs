@@ -162,7 +162,10 @@ pub(crate) fn sum_aggregate_column_by_index_counts<'a, S: Scalar>(
         }
         Column::Scalar(col) => sum_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
         // The following should never be reached because the `SUM` function can only be applied to numeric types.
-        Column::VarChar(_) | Column::TimestampTZ(_, _, _) | Column::Boolean(_) => {
+        Column::VarChar(_)
+        | Column::VarBinary(_)
+        | Column::TimestampTZ(_, _, _)
+        | Column::Boolean(_) => {
             unreachable!("SUM can not be applied to non-numeric types")
         }
     }
@@ -193,9 +196,9 @@ pub(crate) fn max_aggregate_column_by_index_counts<'a, S: Scalar>(
             max_aggregate_slice_by_index_counts(alloc, col, counts, indexes)
         }
         Column::Scalar(col) => max_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
-        // The following should never be reached because the `MAX` function can't be applied to varchar.
-        Column::VarChar(_) => {
-            unreachable!("MAX can not be applied to varchar")
+        // The following should never be reached because the `MAX` function can't be applied to varchar or varbinary.
+        Column::VarChar(_) | Column::VarBinary(_) => {
+            unreachable!("MAX can not be applied to varchar or varbinary")
         }
     }
 }
@@ -225,9 +228,9 @@ pub(crate) fn min_aggregate_column_by_index_counts<'a, S: Scalar>(
             min_aggregate_slice_by_index_counts(alloc, col, counts, indexes)
         }
         Column::Scalar(col) => min_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
-        // The following should never be reached because the `MIN` function can't be applied to varchar.
-        Column::VarChar(_) => {
-            unreachable!("MIN can not be applied to varchar")
+        // The following should never be reached because the `MIN` function can't be applied to varchar or varbinary.
+        Column::VarChar(_) | Column::VarBinary(_) => {
+            unreachable!("MIN can not be applied to varchar or varbinary")
         }
     }
 }
@@ -375,6 +378,7 @@ pub(crate) fn compare_indexes_by_columns<S: Scalar>(
             Column::Decimal75(_, _, col) => col[i].signed_cmp(&col[j]),
             Column::Scalar(col) => col[i].cmp(&col[j]),
             Column::VarChar((col, _)) => col[i].cmp(col[j]),
+            Column::VarBinary((col, _)) => col[i].cmp(col[j]),
         })
         .find(|&ord| ord != Ordering::Equal)
         .unwrap_or(Ordering::Equal)
@@ -401,6 +405,7 @@ pub(crate) fn compare_indexes_by_owned_columns<S: Scalar>(
             OwnedColumn::Decimal75(_, _, col) => col[i].signed_cmp(&col[j]),
             OwnedColumn::Scalar(col) => col[i].cmp(&col[j]),
             OwnedColumn::VarChar(col) => col[i].cmp(&col[j]),
+            OwnedColumn::VarBinary(col) => col[i].cmp(&col[j]),
         })
         .find(|&ord| ord != Ordering::Equal)
         .unwrap_or(Ordering::Equal)
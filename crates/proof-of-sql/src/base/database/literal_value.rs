@@ -1,5 +1,9 @@
+use super::owned_column::format_decimal;
 use crate::base::{database::ColumnType, math::decimal::Precision, scalar::Scalar};
-use alloc::string::String;
+use alloc::{string::String, vec::Vec};
+use chrono::{DateTime, FixedOffset};
+use core::fmt::{self, Display, Formatter};
+use num_bigint::BigInt;
 use proof_of_sql_parser::posql_time::{PoSQLTimeUnit, PoSQLTimeZone};
 use serde::{Deserialize, Serialize};
 
@@ -26,6 +30,10 @@ pub enum LiteralValue<S: Scalar> {
     ///  - the first element maps to the str value.
     ///  - the second element maps to the str hash (see [`crate::base::scalar::Scalar`]).
     VarChar((String, S)),
+    /// Binary literals
+    ///  - the first element maps to the byte value.
+    ///  - the second element maps to the byte hash (see [`crate::base::scalar::ScalarExt::from_byte_slice_via_hash`]).
+    VarBinary((Vec<u8>, S)),
     /// i128 literals
     Int128(i128),
     /// Decimal literals with a max width of 252 bits
@@ -48,6 +56,7 @@ impl<S: Scalar> LiteralValue<S> {
             Self::Int(_) => ColumnType::Int,
             Self::BigInt(_) => ColumnType::BigInt,
             Self::VarChar(_) => ColumnType::VarChar,
+            Self::VarBinary(_) => ColumnType::VarBinary,
             Self::Int128(_) => ColumnType::Int128,
             Self::Scalar(_) => ColumnType::Scalar,
             Self::Decimal75(precision, scale, _) => ColumnType::Decimal75(*precision, *scale),
@@ -63,10 +72,65 @@ impl<S: Scalar> LiteralValue<S> {
             Self::SmallInt(i) => i.into(),
             Self::Int(i) => i.into(),
             Self::BigInt(i) => i.into(),
-            Self::VarChar((_, s)) | Self::Decimal75(_, _, s) => *s,
+            Self::VarChar((_, s)) | Self::VarBinary((_, s)) | Self::Decimal75(_, _, s) => *s,
             Self::Int128(i) => i.into(),
             Self::Scalar(scalar) => *scalar,
             Self::TimeStampTZ(_, _, time) => time.into(),
         }
     }
 }
+
+/// Display the literal using SQL literal syntax
+///
+/// Strings are single-quoted with embedded quotes doubled, binary values are hex strings (`X'0A1B'`),
+/// decimals are shown with their scale applied and timestamps are shown as `TIMESTAMP '<RFC 3339>'`.
+/// Scalars have no SQL literal syntax and are shown as signed integers.
+impl<S: Scalar> Display for LiteralValue<S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Boolean(true) => write!(f, "TRUE"),
+            Self::Boolean(false) => write!(f, "FALSE"),
+            Self::TinyInt(i) => write!(f, "{i}"),
+            Self::SmallInt(i) => write!(f, "{i}"),
+            Self::Int(i) => write!(f, "{i}"),
+            Self::BigInt(i) => write!(f, "{i}"),
+            Self::Int128(i) => write!(f, "{i}"),
+            Self::VarChar((s, _)) => write!(f, "'{}'", s.replace('\'', "''")),
+            Self::VarBinary((bytes, _)) => {
+                write!(f, "X'")?;
+                for byte in bytes {
+                    write!(f, "{byte:02X}")?;
+                }
+                write!(f, "'")
+            }
+            Self::Decimal75(_, scale, value) => {
+                write!(f, "{}", format_decimal(&(*value).into(), *scale))
+            }
+            Self::Scalar(value) => write!(f, "{}", Into::<BigInt>::into(*value)),
+            Self::TimeStampTZ(unit, timezone, time) => {
+                let utc = match unit {
+                    PoSQLTimeUnit::Second => DateTime::from_timestamp(*time, 0),
+                    PoSQLTimeUnit::Millisecond => DateTime::from_timestamp_millis(*time),
+                    PoSQLTimeUnit::Microsecond => DateTime::from_timestamp_micros(*time),
+                    PoSQLTimeUnit::Nanosecond => Some(DateTime::from_timestamp_nanos(*time)),
+                };
+                let offset = match timezone {
+                    PoSQLTimeZone::Utc => FixedOffset::east_opt(0),
+                    PoSQLTimeZone::FixedOffset(offset) => FixedOffset::east_opt(*offset),
+                };
+                match (utc, offset) {
+                    (Some(utc), Some(offset)) => {
+                        let local = utc.with_timezone(&offset).naive_local();
+                        write!(f, "TIMESTAMP '{}T{}", local.date(), local.time())?;
+                        match timezone {
+                            PoSQLTimeZone::Utc => write!(f, "Z'"),
+                            PoSQLTimeZone::FixedOffset(_) => write!(f, "{offset}'"),
+                        }
+                    }
+                    // Out of the range that can be shown as a date, so fall back to the raw value.
+                    _ => write!(f, "{time}"),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use super::LiteralValue;
+use crate::base::{
+    math::decimal::Precision,
+    scalar::{Curve25519Scalar, ScalarExt},
+};
+use alloc::{string::ToString, vec};
+use proof_of_sql_parser::posql_time::{PoSQLTimeUnit, PoSQLTimeZone};
+
+type Literal = LiteralValue<Curve25519Scalar>;
+
+#[test]
+fn we_can_display_boolean_literals() {
+    assert_eq!(Literal::Boolean(true).to_string(), "TRUE");
+    assert_eq!(Literal::Boolean(false).to_string(), "FALSE");
+}
+
+#[test]
+fn we_can_display_integer_literals() {
+    assert_eq!(Literal::TinyInt(-12).to_string(), "-12");
+    assert_eq!(Literal::SmallInt(i16::MIN).to_string(), "-32768");
+    assert_eq!(Literal::Int(7).to_string(), "7");
+    assert_eq!(Literal::BigInt(-9_000_000_000).to_string(), "-9000000000");
+    assert_eq!(
+        Literal::Int128(i128::MAX).to_string(),
+        "170141183460469231731687303715884105727"
+    );
+}
+
+#[test]
+fn we_can_display_decimal_literals() {
+    let precision = Precision::new(10).unwrap();
+    assert_eq!(
+        Literal::Decimal75(precision, 4, 12345.into()).to_string(),
+        "1.2345"
+    );
+    assert_eq!(
+        Literal::Decimal75(precision, 4, (-12345).into()).to_string(),
+        "-1.2345"
+    );
+    assert_eq!(
+        Literal::Decimal75(precision, 4, 5.into()).to_string(),
+        "0.0005"
+    );
+    assert_eq!(Literal::Decimal75(precision, 0, 5.into()).to_string(), "5");
+    assert_eq!(
+        Literal::Decimal75(precision, -2, 5.into()).to_string(),
+        "500"
+    );
+}
+
+#[test]
+fn we_can_display_string_and_binary_literals() {
+    let varchar = |s: &str| Literal::VarChar((s.to_string(), s.into()));
+    assert_eq!(varchar("abc").to_string(), "'abc'");
+    assert_eq!(varchar("").to_string(), "''");
+    assert_eq!(varchar("it's").to_string(), "'it''s'");
+    let bytes = vec![0x0a, 0xff, 0x00];
+    let scalar = Curve25519Scalar::from_byte_slice_via_hash(&bytes);
+    assert_eq!(Literal::VarBinary((bytes, scalar)).to_string(), "X'0AFF00'");
+}
+
+#[test]
+fn we_can_display_scalar_literals() {
+    assert_eq!(Literal::Scalar((-3).into()).to_string(), "-3");
+    assert_eq!(Literal::Scalar(42.into()).to_string(), "42");
+}
+
+#[test]
+fn we_can_display_timestamp_literals() {
+    assert_eq!(
+        Literal::TimeStampTZ(PoSQLTimeUnit::Second, PoSQLTimeZone::Utc, 1_704_067_200).to_string(),
+        "TIMESTAMP '2024-01-01T00:00:00Z'"
+    );
+    assert_eq!(
+        Literal::TimeStampTZ(
+            PoSQLTimeUnit::Millisecond,
+            PoSQLTimeZone::FixedOffset(3600),
+            1_704_067_200_123
+        )
+        .to_string(),
+        "TIMESTAMP '2024-01-01T01:00:00.123+01:00'"
+    );
+    assert_eq!(
+        Literal::TimeStampTZ(PoSQLTimeUnit::Second, PoSQLTimeZone::Utc, i64::MAX).to_string(),
+        i64::MAX.to_string()
+    );
+}
@@ -20,6 +20,8 @@ pub use columnar_value::ColumnarValue;
 
 mod literal_value;
 pub use literal_value::LiteralValue;
+#[cfg(test)]
+mod literal_value_test;
 
 mod table_ref;
 #[cfg(feature = "arrow")]
@@ -36,7 +38,7 @@ pub mod arrow_schema_utility;
 
 mod owned_column;
 pub(crate) use owned_column::compare_indexes_by_owned_columns_with_direction;
-pub use owned_column::OwnedColumn;
+pub use owned_column::{LengthMode, OwnedColumn};
 
 mod owned_column_error;
 pub use owned_column_error::{OwnedColumnError, OwnedColumnResult};
@@ -73,6 +75,11 @@ pub use owned_table_test_accessor::OwnedTableTestAccessor;
 #[cfg(all(test, feature = "blitzar"))]
 mod owned_table_test_accessor_test;
 
+mod caching_commitment_accessor;
+pub use caching_commitment_accessor::CachingCommitmentAccessor;
+#[cfg(all(test, feature = "blitzar"))]
+mod caching_commitment_accessor_test;
+
 /// TODO: add docs
 pub(crate) mod filter_util;
 #[cfg(test)]
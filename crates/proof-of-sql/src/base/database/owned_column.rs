@@ -2,27 +2,45 @@
 /// This is primarily used as an internal result that is used before
 /// converting to the final result in either Arrow format or JSON.
 /// This is the analog of an arrow Array.
-use super::{Column, ColumnType, OwnedColumnError, OwnedColumnResult};
+use super::{Column, ColumnType, LiteralValue, OwnedColumnError, OwnedColumnResult};
 use crate::base::{
+    map::IndexMap,
     math::{
         decimal::Precision,
         permutation::{Permutation, PermutationError},
     },
-    scalar::Scalar,
+    scalar::{Scalar, ScalarExt},
 };
 use alloc::{
+    format,
     string::{String, ToString},
     vec::Vec,
 };
+use bumpalo::Bump;
 use core::cmp::Ordering;
+use num_bigint::{BigInt, Sign};
 use proof_of_sql_parser::{
     intermediate_ast::OrderByDirection,
     posql_time::{PoSQLTimeUnit, PoSQLTimeZone},
 };
+use serde::{Deserialize, Deserializer, Serialize};
 
-#[derive(Debug, PartialEq, Clone, Eq)]
+/// How [`OwnedColumn::try_length`] measures the length of a string.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum LengthMode {
+    /// The number of bytes in the UTF-8 encoding, as in `OCTET_LENGTH`.
+    Bytes,
+    /// The number of Unicode scalar values, as in `CHAR_LENGTH`. This is the default.
+    #[default]
+    Characters,
+}
+
+#[derive(Debug, PartialEq, Clone, Eq, Serialize)]
 #[non_exhaustive]
 /// Supported types for [`OwnedColumn`]
+///
+/// Deserialization checks that the values of a `Decimal75` column fit its precision, as
+/// [`OwnedColumn::try_new_decimal75`] does.
 pub enum OwnedColumn<S: Scalar> {
     /// Boolean columns
     Boolean(Vec<bool>),
@@ -36,6 +54,8 @@ pub enum OwnedColumn<S: Scalar> {
     BigInt(Vec<i64>),
     /// String columns
     VarChar(Vec<String>),
+    /// Binary columns
+    VarBinary(Vec<Vec<u8>>),
     /// i128 columns
     Int128(Vec<i128>),
     /// Decimal columns
@@ -46,6 +66,49 @@ pub enum OwnedColumn<S: Scalar> {
     TimestampTZ(PoSQLTimeUnit, PoSQLTimeZone, Vec<i64>),
 }
 
+/// The serialized form of an [`OwnedColumn`]
+#[derive(Deserialize)]
+#[serde(rename = "OwnedColumn")]
+enum OwnedColumnParts<S: Scalar> {
+    Boolean(Vec<bool>),
+    TinyInt(Vec<i8>),
+    SmallInt(Vec<i16>),
+    Int(Vec<i32>),
+    BigInt(Vec<i64>),
+    VarChar(Vec<String>),
+    VarBinary(Vec<Vec<u8>>),
+    Int128(Vec<i128>),
+    Decimal75(Precision, i8, Vec<S>),
+    Scalar(Vec<S>),
+    TimestampTZ(PoSQLTimeUnit, PoSQLTimeZone, Vec<i64>),
+}
+
+// Custom deserializer since the values of a decimal column must fit its precision
+impl<'de, S: Scalar + Deserialize<'de>> Deserialize<'de> for OwnedColumn<S> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(match OwnedColumnParts::deserialize(deserializer)? {
+            OwnedColumnParts::Boolean(col) => Self::Boolean(col),
+            OwnedColumnParts::TinyInt(col) => Self::TinyInt(col),
+            OwnedColumnParts::SmallInt(col) => Self::SmallInt(col),
+            OwnedColumnParts::Int(col) => Self::Int(col),
+            OwnedColumnParts::BigInt(col) => Self::BigInt(col),
+            OwnedColumnParts::VarChar(col) => Self::VarChar(col),
+            OwnedColumnParts::VarBinary(col) => Self::VarBinary(col),
+            OwnedColumnParts::Int128(col) => Self::Int128(col),
+            OwnedColumnParts::Decimal75(precision, scale, col) => {
+                Self::try_new_decimal75(precision, scale, col).map_err(serde::de::Error::custom)?
+            }
+            OwnedColumnParts::Scalar(col) => Self::Scalar(col),
+            OwnedColumnParts::TimestampTZ(time_unit, timezone, col) => {
+                Self::TimestampTZ(time_unit, timezone, col)
+            }
+        })
+    }
+}
+
 impl<S: Scalar> OwnedColumn<S> {
     /// Returns the length of the column.
     #[must_use]
@@ -57,6 +120,7 @@ impl<S: Scalar> OwnedColumn<S> {
             OwnedColumn::Int(col) => col.len(),
             OwnedColumn::BigInt(col) | OwnedColumn::TimestampTZ(_, _, col) => col.len(),
             OwnedColumn::VarChar(col) => col.len(),
+            OwnedColumn::VarBinary(col) => col.len(),
             OwnedColumn::Int128(col) => col.len(),
             OwnedColumn::Decimal75(_, _, col) | OwnedColumn::Scalar(col) => col.len(),
         }
@@ -71,6 +135,7 @@ impl<S: Scalar> OwnedColumn<S> {
             OwnedColumn::Int(col) => OwnedColumn::Int(permutation.try_apply(col)?),
             OwnedColumn::BigInt(col) => OwnedColumn::BigInt(permutation.try_apply(col)?),
             OwnedColumn::VarChar(col) => OwnedColumn::VarChar(permutation.try_apply(col)?),
+            OwnedColumn::VarBinary(col) => OwnedColumn::VarBinary(permutation.try_apply(col)?),
             OwnedColumn::Int128(col) => OwnedColumn::Int128(permutation.try_apply(col)?),
             OwnedColumn::Decimal75(precision, scale, col) => {
                 OwnedColumn::Decimal75(*precision, *scale, permutation.try_apply(col)?)
@@ -92,6 +157,7 @@ impl<S: Scalar> OwnedColumn<S> {
             OwnedColumn::Int(col) => OwnedColumn::Int(col[start..end].to_vec()),
             OwnedColumn::BigInt(col) => OwnedColumn::BigInt(col[start..end].to_vec()),
             OwnedColumn::VarChar(col) => OwnedColumn::VarChar(col[start..end].to_vec()),
+            OwnedColumn::VarBinary(col) => OwnedColumn::VarBinary(col[start..end].to_vec()),
             OwnedColumn::Int128(col) => OwnedColumn::Int128(col[start..end].to_vec()),
             OwnedColumn::Decimal75(precision, scale, col) => {
                 OwnedColumn::Decimal75(*precision, *scale, col[start..end].to_vec())
@@ -103,6 +169,186 @@ impl<S: Scalar> OwnedColumn<S> {
         }
     }
 
+    /// Returns the column repeated `n` times, e.g. `[a, b]` becomes `[a, b, a, b]` for `n = 2`.
+    #[must_use]
+    pub fn repeat_whole(&self, n: usize) -> Self {
+        match self {
+            OwnedColumn::Boolean(col) => OwnedColumn::Boolean(repeat_whole_slice(col, n)),
+            OwnedColumn::TinyInt(col) => OwnedColumn::TinyInt(repeat_whole_slice(col, n)),
+            OwnedColumn::SmallInt(col) => OwnedColumn::SmallInt(repeat_whole_slice(col, n)),
+            OwnedColumn::Int(col) => OwnedColumn::Int(repeat_whole_slice(col, n)),
+            OwnedColumn::BigInt(col) => OwnedColumn::BigInt(repeat_whole_slice(col, n)),
+            OwnedColumn::VarChar(col) => OwnedColumn::VarChar(repeat_whole_slice(col, n)),
+            OwnedColumn::VarBinary(col) => OwnedColumn::VarBinary(repeat_whole_slice(col, n)),
+            OwnedColumn::Int128(col) => OwnedColumn::Int128(repeat_whole_slice(col, n)),
+            OwnedColumn::Decimal75(precision, scale, col) => {
+                OwnedColumn::Decimal75(*precision, *scale, repeat_whole_slice(col, n))
+            }
+            OwnedColumn::Scalar(col) => OwnedColumn::Scalar(repeat_whole_slice(col, n)),
+            OwnedColumn::TimestampTZ(tu, tz, col) => {
+                OwnedColumn::TimestampTZ(*tu, *tz, repeat_whole_slice(col, n))
+            }
+        }
+    }
+
+    /// Returns the column with each element repeated `n` times, e.g. `[a, b]` becomes `[a, a, b, b]` for `n = 2`.
+    #[must_use]
+    pub fn repeat_each(&self, n: usize) -> Self {
+        match self {
+            OwnedColumn::Boolean(col) => OwnedColumn::Boolean(repeat_each_slice(col, n)),
+            OwnedColumn::TinyInt(col) => OwnedColumn::TinyInt(repeat_each_slice(col, n)),
+            OwnedColumn::SmallInt(col) => OwnedColumn::SmallInt(repeat_each_slice(col, n)),
+            OwnedColumn::Int(col) => OwnedColumn::Int(repeat_each_slice(col, n)),
+            OwnedColumn::BigInt(col) => OwnedColumn::BigInt(repeat_each_slice(col, n)),
+            OwnedColumn::VarChar(col) => OwnedColumn::VarChar(repeat_each_slice(col, n)),
+            OwnedColumn::VarBinary(col) => OwnedColumn::VarBinary(repeat_each_slice(col, n)),
+            OwnedColumn::Int128(col) => OwnedColumn::Int128(repeat_each_slice(col, n)),
+            OwnedColumn::Decimal75(precision, scale, col) => {
+                OwnedColumn::Decimal75(*precision, *scale, repeat_each_slice(col, n))
+            }
+            OwnedColumn::Scalar(col) => OwnedColumn::Scalar(repeat_each_slice(col, n)),
+            OwnedColumn::TimestampTZ(tu, tz, col) => {
+                OwnedColumn::TimestampTZ(*tu, *tz, repeat_each_slice(col, n))
+            }
+        }
+    }
+
+    /// Creates a `Decimal75` column, checking that the scale and every value fit the precision.
+    ///
+    /// Every value must have a magnitude strictly less than `10^precision`.
+    /// The precision is always positive since [`Precision`] can not be constructed otherwise.
+    /// Negative scales are allowed.
+    ///
+    /// # Errors
+    /// Returns [`OwnedColumnError::DecimalScaleExceedsPrecision`] if `scale` is larger than `precision`,
+    /// and [`OwnedColumnError::DecimalValueExceedsPrecision`] if any value does not fit in `precision` digits.
+    pub fn try_new_decimal75(
+        precision: Precision,
+        scale: i8,
+        scalars: Vec<S>,
+    ) -> OwnedColumnResult<Self> {
+        if i16::from(scale) > i16::from(precision.value()) {
+            return Err(OwnedColumnError::DecimalScaleExceedsPrecision {
+                precision: precision.value(),
+                scale,
+            });
+        }
+        let bound = S::pow10(precision.value().into());
+        if let Some(index) = scalars.iter().position(|&value| {
+            let magnitude = if value > S::MAX_SIGNED { -value } else { value };
+            magnitude >= bound
+        }) {
+            return Err(OwnedColumnError::DecimalValueExceedsPrecision {
+                precision: precision.value(),
+                index,
+            });
+        }
+        Ok(OwnedColumn::Decimal75(precision, scale, scalars))
+    }
+
+    /// Concatenates the columns, in order, into a single column.
+    ///
+    /// The result is built with a single allocation, which avoids the reallocations of concatenating pairwise.
+    ///
+    /// # Errors
+    /// Returns [`OwnedColumnError::ColumnTypeMismatch`] if the columns do not all have the same type,
+    /// including the precision and scale of decimals,
+    /// and [`OwnedColumnError::Unsupported`] if `columns` is empty, since the type of the result would be unknown.
+    pub fn concat_many(columns: &[&OwnedColumn<S>]) -> OwnedColumnResult<Self> {
+        let (first, rest) = columns
+            .split_first()
+            .ok_or_else(|| OwnedColumnError::Unsupported {
+                error: "concatenating zero columns".to_string(),
+            })?;
+        let expected = first.column_type();
+        if let Some(column) = rest.iter().find(|column| column.column_type() != expected) {
+            return Err(OwnedColumnError::ColumnTypeMismatch {
+                expected,
+                actual: column.column_type(),
+            });
+        }
+        let mut result = first.empty_with_capacity(columns.iter().map(|column| column.len()).sum());
+        for column in columns {
+            result.extend_from_same_type(column);
+        }
+        Ok(result)
+    }
+
+    /// Returns an empty column of type `column_type`.
+    pub(super) fn empty_of_type(column_type: ColumnType) -> Self {
+        match column_type {
+            ColumnType::Boolean => OwnedColumn::Boolean(Vec::new()),
+            ColumnType::TinyInt => OwnedColumn::TinyInt(Vec::new()),
+            ColumnType::SmallInt => OwnedColumn::SmallInt(Vec::new()),
+            ColumnType::Int => OwnedColumn::Int(Vec::new()),
+            ColumnType::BigInt => OwnedColumn::BigInt(Vec::new()),
+            ColumnType::VarChar => OwnedColumn::VarChar(Vec::new()),
+            ColumnType::VarBinary => OwnedColumn::VarBinary(Vec::new()),
+            ColumnType::Int128 => OwnedColumn::Int128(Vec::new()),
+            ColumnType::Decimal75(precision, scale) => {
+                OwnedColumn::Decimal75(precision, scale, Vec::new())
+            }
+            ColumnType::Scalar => OwnedColumn::Scalar(Vec::new()),
+            ColumnType::TimestampTZ(tu, tz) => OwnedColumn::TimestampTZ(tu, tz, Vec::new()),
+        }
+    }
+
+    /// Returns an empty column of the same type as `self` with room for `capacity` elements.
+    fn empty_with_capacity(&self, capacity: usize) -> Self {
+        match self {
+            OwnedColumn::Boolean(_) => OwnedColumn::Boolean(Vec::with_capacity(capacity)),
+            OwnedColumn::TinyInt(_) => OwnedColumn::TinyInt(Vec::with_capacity(capacity)),
+            OwnedColumn::SmallInt(_) => OwnedColumn::SmallInt(Vec::with_capacity(capacity)),
+            OwnedColumn::Int(_) => OwnedColumn::Int(Vec::with_capacity(capacity)),
+            OwnedColumn::BigInt(_) => OwnedColumn::BigInt(Vec::with_capacity(capacity)),
+            OwnedColumn::VarChar(_) => OwnedColumn::VarChar(Vec::with_capacity(capacity)),
+            OwnedColumn::VarBinary(_) => OwnedColumn::VarBinary(Vec::with_capacity(capacity)),
+            OwnedColumn::Int128(_) => OwnedColumn::Int128(Vec::with_capacity(capacity)),
+            OwnedColumn::Decimal75(precision, scale, _) => {
+                OwnedColumn::Decimal75(*precision, *scale, Vec::with_capacity(capacity))
+            }
+            OwnedColumn::Scalar(_) => OwnedColumn::Scalar(Vec::with_capacity(capacity)),
+            OwnedColumn::TimestampTZ(tu, tz, _) => {
+                OwnedColumn::TimestampTZ(*tu, *tz, Vec::with_capacity(capacity))
+            }
+        }
+    }
+
+    /// Appends the elements of `other`, which must have the same type as `self`.
+    ///
+    /// # Panics
+    /// Panics if `other` is a different kind of column than `self`.
+    fn extend_from_same_type(&mut self, other: &Self) {
+        match (self, other) {
+            (OwnedColumn::Boolean(col), OwnedColumn::Boolean(other)) => {
+                col.extend_from_slice(other);
+            }
+            (OwnedColumn::TinyInt(col), OwnedColumn::TinyInt(other)) => {
+                col.extend_from_slice(other);
+            }
+            (OwnedColumn::SmallInt(col), OwnedColumn::SmallInt(other)) => {
+                col.extend_from_slice(other);
+            }
+            (OwnedColumn::Int(col), OwnedColumn::Int(other)) => col.extend_from_slice(other),
+            (OwnedColumn::BigInt(col), OwnedColumn::BigInt(other)) => col.extend_from_slice(other),
+            (OwnedColumn::VarChar(col), OwnedColumn::VarChar(other)) => {
+                col.extend_from_slice(other);
+            }
+            (OwnedColumn::VarBinary(col), OwnedColumn::VarBinary(other)) => {
+                col.extend_from_slice(other);
+            }
+            (OwnedColumn::Int128(col), OwnedColumn::Int128(other)) => col.extend_from_slice(other),
+            (OwnedColumn::Decimal75(_, _, col), OwnedColumn::Decimal75(_, _, other))
+            | (OwnedColumn::Scalar(col), OwnedColumn::Scalar(other)) => {
+                col.extend_from_slice(other);
+            }
+            (OwnedColumn::TimestampTZ(_, _, col), OwnedColumn::TimestampTZ(_, _, other)) => {
+                col.extend_from_slice(other);
+            }
+            _ => panic!("columns of different types can not be concatenated"),
+        }
+    }
+
     /// Returns true if the column is empty.
     #[must_use]
     pub fn is_empty(&self) -> bool {
@@ -113,6 +359,7 @@ impl<S: Scalar> OwnedColumn<S> {
             OwnedColumn::Int(col) => col.is_empty(),
             OwnedColumn::BigInt(col) | OwnedColumn::TimestampTZ(_, _, col) => col.is_empty(),
             OwnedColumn::VarChar(col) => col.is_empty(),
+            OwnedColumn::VarBinary(col) => col.is_empty(),
             OwnedColumn::Int128(col) => col.is_empty(),
             OwnedColumn::Scalar(col) | OwnedColumn::Decimal75(_, _, col) => col.is_empty(),
         }
@@ -127,6 +374,7 @@ impl<S: Scalar> OwnedColumn<S> {
             OwnedColumn::Int(_) => ColumnType::Int,
             OwnedColumn::BigInt(_) => ColumnType::BigInt,
             OwnedColumn::VarChar(_) => ColumnType::VarChar,
+            OwnedColumn::VarBinary(_) => ColumnType::VarBinary,
             OwnedColumn::Int128(_) => ColumnType::Int128,
             OwnedColumn::Scalar(_) => ColumnType::Scalar,
             OwnedColumn::Decimal75(precision, scale, _) => {
@@ -136,6 +384,152 @@ impl<S: Scalar> OwnedColumn<S> {
         }
     }
 
+    /// Returns a `BigInt` column holding the length of each string of a `VarChar` column.
+    ///
+    /// `VarChar` columns are committed to as hashes of their strings, so lengths can not be proven.
+    /// This is meant to be applied to a verified result instead, whose strings are known to be correct.
+    ///
+    /// # Errors
+    /// Returns [`OwnedColumnError::ColumnTypeMismatch`] if the column is not a `VarChar` column.
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn try_length(&self, mode: LengthMode) -> OwnedColumnResult<Self> {
+        let OwnedColumn::VarChar(col) = self else {
+            return Err(OwnedColumnError::ColumnTypeMismatch {
+                expected: ColumnType::VarChar,
+                actual: self.column_type(),
+            });
+        };
+        Ok(OwnedColumn::BigInt(
+            col.iter()
+                .map(|s| match mode {
+                    LengthMode::Bytes => s.len(),
+                    LengthMode::Characters => s.chars().count(),
+                } as i64)
+                .collect(),
+        ))
+    }
+
+    /// Casts the column to `to_type`, e.g. to coerce ingested data to a declared schema.
+    ///
+    /// Only lossless casts are supported:
+    /// - integers widen to larger integer types,
+    /// - integers and decimals convert to a `Decimal75` with at least their scale,
+    ///   as long as every value fits the target precision,
+    /// - integers convert to `Scalar`.
+    ///
+    /// Casting to the column's own type returns a copy.
+    ///
+    /// # Errors
+    /// Returns [`OwnedColumnError::TypeCastError`] if the cast is not supported,
+    /// and [`OwnedColumnError::DecimalValueExceedsPrecision`] or [`OwnedColumnError::DecimalScaleExceedsPrecision`]
+    /// if the values do not fit the target decimal type.
+    pub fn try_cast(&self, to_type: ColumnType) -> OwnedColumnResult<Self> {
+        fn widen<T: Copy, U: From<T>>(values: &[T]) -> Vec<U> {
+            values.iter().map(|&value| U::from(value)).collect()
+        }
+        let from_type = self.column_type();
+        if from_type == to_type {
+            return Ok(self.clone());
+        }
+        match (self, to_type) {
+            (OwnedColumn::TinyInt(col), ColumnType::SmallInt) => {
+                Ok(OwnedColumn::SmallInt(widen(col)))
+            }
+            (OwnedColumn::TinyInt(col), ColumnType::Int) => Ok(OwnedColumn::Int(widen(col))),
+            (OwnedColumn::TinyInt(col), ColumnType::BigInt) => Ok(OwnedColumn::BigInt(widen(col))),
+            (OwnedColumn::TinyInt(col), ColumnType::Int128) => Ok(OwnedColumn::Int128(widen(col))),
+            (OwnedColumn::SmallInt(col), ColumnType::Int) => Ok(OwnedColumn::Int(widen(col))),
+            (OwnedColumn::SmallInt(col), ColumnType::BigInt) => Ok(OwnedColumn::BigInt(widen(col))),
+            (OwnedColumn::SmallInt(col), ColumnType::Int128) => Ok(OwnedColumn::Int128(widen(col))),
+            (OwnedColumn::Int(col), ColumnType::BigInt) => Ok(OwnedColumn::BigInt(widen(col))),
+            (OwnedColumn::Int(col), ColumnType::Int128) => Ok(OwnedColumn::Int128(widen(col))),
+            (OwnedColumn::BigInt(col), ColumnType::Int128) => Ok(OwnedColumn::Int128(widen(col))),
+            (_, ColumnType::Decimal75(precision, to_scale))
+                if from_type.is_integer() || matches!(from_type, ColumnType::Decimal75(..)) =>
+            {
+                let from_scale = from_type.scale().unwrap_or(0);
+                let upscale = i8::try_from(i16::from(to_scale) - i16::from(from_scale))
+                    .ok()
+                    .filter(|upscale| *upscale >= 0)
+                    .ok_or_else(|| self.type_cast_error(to_type))?;
+                let alloc = Bump::new();
+                let column = Column::from_owned_column(self, &alloc);
+                // Upscaling a value with too many digits would wrap around in the field,
+                // so the digits are checked before upscaling.
+                let max_digits = i16::from(precision.value()) - i16::from(upscale);
+                if let Some(index) = column.to_scalar_with_scaling(0).iter().position(|&value| {
+                    let magnitude = if value > S::MAX_SIGNED { -value } else { value };
+                    u32::try_from(max_digits)
+                        .map_or(magnitude != S::ZERO, |digits| magnitude >= S::pow10(digits))
+                }) {
+                    return Err(OwnedColumnError::DecimalValueExceedsPrecision {
+                        precision: precision.value(),
+                        index,
+                    });
+                }
+                Self::try_new_decimal75(precision, to_scale, column.to_scalar_with_scaling(upscale))
+            }
+            (_, ColumnType::Scalar) if from_type.is_integer() => {
+                let alloc = Bump::new();
+                Ok(OwnedColumn::Scalar(
+                    Column::from_owned_column(self, &alloc).to_scalar_with_scaling(0),
+                ))
+            }
+            _ => Err(self.type_cast_error(to_type)),
+        }
+    }
+
+    /// Converts every value of the column into a literal of the column's type.
+    pub fn to_literal_values(&self) -> Vec<LiteralValue<S>> {
+        match self {
+            OwnedColumn::Boolean(col) => col.iter().copied().map(LiteralValue::Boolean).collect(),
+            OwnedColumn::TinyInt(col) => col.iter().copied().map(LiteralValue::TinyInt).collect(),
+            OwnedColumn::SmallInt(col) => col.iter().copied().map(LiteralValue::SmallInt).collect(),
+            OwnedColumn::Int(col) => col.iter().copied().map(LiteralValue::Int).collect(),
+            OwnedColumn::BigInt(col) => col.iter().copied().map(LiteralValue::BigInt).collect(),
+            OwnedColumn::VarChar(col) => col
+                .iter()
+                .map(|s| LiteralValue::VarChar((s.clone(), s.into())))
+                .collect(),
+            OwnedColumn::VarBinary(col) => col
+                .iter()
+                .map(|b| LiteralValue::VarBinary((b.clone(), S::from_byte_slice_via_hash(b))))
+                .collect(),
+            OwnedColumn::Int128(col) => col.iter().copied().map(LiteralValue::Int128).collect(),
+            OwnedColumn::Decimal75(precision, scale, col) => col
+                .iter()
+                .map(|&value| LiteralValue::Decimal75(*precision, *scale, value))
+                .collect(),
+            OwnedColumn::Scalar(col) => col.iter().copied().map(LiteralValue::Scalar).collect(),
+            OwnedColumn::TimestampTZ(tu, tz, col) => col
+                .iter()
+                .map(|&time| LiteralValue::TimeStampTZ(*tu, *tz, time))
+                .collect(),
+        }
+    }
+
+    /// Returns each distinct value of the column with the number of times it occurs, e.g. to estimate
+    /// group sizes before a group by.
+    ///
+    /// Values are returned in order of first occurrence. Values are compared through their scalars,
+    /// so `VarChar` and `VarBinary` values are compared by hash.
+    ///
+    /// # Errors
+    /// This currently never fails; the result type leaves room for types that cannot be counted.
+    pub fn value_counts(&self) -> OwnedColumnResult<Vec<(LiteralValue<S>, usize)>> {
+        let alloc = Bump::new();
+        let scalars = Column::from_owned_column(self, &alloc).to_scalar_with_scaling(0);
+        let mut counts: IndexMap<[u64; 4], (usize, usize)> = IndexMap::default();
+        for (index, scalar) in scalars.iter().enumerate() {
+            counts.entry((*scalar).into()).or_insert((index, 0)).1 += 1;
+        }
+        let values = self.to_literal_values();
+        Ok(counts
+            .into_values()
+            .map(|(first_index, count)| (values[first_index].clone(), count))
+            .collect())
+    }
+
     /// Convert a slice of scalars to a vec of owned columns
     pub fn try_from_scalars(scalars: &[S], column_type: ColumnType) -> OwnedColumnResult<Self> {
         match column_type {
@@ -207,10 +601,10 @@ impl<S: Scalar> OwnedColumn<S> {
                     })?;
                 Ok(OwnedColumn::TimestampTZ(tu, tz, raw_values))
             }
-            // Can not convert scalars to VarChar
-            ColumnType::VarChar => Err(OwnedColumnError::TypeCastError {
+            // Can not convert scalars to VarChar or VarBinary
+            ColumnType::VarChar | ColumnType::VarBinary => Err(OwnedColumnError::TypeCastError {
                 from_type: ColumnType::Scalar,
-                to_type: ColumnType::VarChar,
+                to_type: column_type,
             }),
         }
     }
@@ -230,6 +624,90 @@ impl<S: Scalar> OwnedColumn<S> {
         Self::try_from_scalars(&scalars, column_type)
     }
 
+    /// Returns the data of a `BigInt` column.
+    ///
+    /// # Errors
+    /// Returns an error if the column is not a `BigInt` column.
+    pub fn try_into_i64_vec(self) -> OwnedColumnResult<Vec<i64>> {
+        match self {
+            OwnedColumn::BigInt(col) => Ok(col),
+            _ => Err(self.type_cast_error(ColumnType::BigInt)),
+        }
+    }
+
+    /// Returns the data of an `Int128` column.
+    ///
+    /// # Errors
+    /// Returns an error if the column is not an `Int128` column.
+    pub fn try_into_i128_vec(self) -> OwnedColumnResult<Vec<i128>> {
+        match self {
+            OwnedColumn::Int128(col) => Ok(col),
+            _ => Err(self.type_cast_error(ColumnType::Int128)),
+        }
+    }
+
+    /// Returns the data of a `VarChar` column.
+    ///
+    /// # Errors
+    /// Returns an error if the column is not a `VarChar` column.
+    pub fn try_into_string_vec(self) -> OwnedColumnResult<Vec<String>> {
+        match self {
+            OwnedColumn::VarChar(col) => Ok(col),
+            _ => Err(self.type_cast_error(ColumnType::VarChar)),
+        }
+    }
+
+    /// Returns the data of a `Boolean` column.
+    ///
+    /// # Errors
+    /// Returns an error if the column is not a `Boolean` column.
+    pub fn try_into_bool_vec(self) -> OwnedColumnResult<Vec<bool>> {
+        match self {
+            OwnedColumn::Boolean(col) => Ok(col),
+            _ => Err(self.type_cast_error(ColumnType::Boolean)),
+        }
+    }
+
+    /// Returns the values of a `Decimal75` column as decimal strings with `scale` fractional digits,
+    /// e.g. `"-1.50"` for the value `-15` of a column with a scale of 1 when `scale` is 2.
+    ///
+    /// # Errors
+    /// Returns an error if the column is not a `Decimal75` column,
+    /// or if `scale` is smaller than the scale of the column, since that would lose digits.
+    pub fn try_into_decimal_strings(self, scale: i8) -> OwnedColumnResult<Vec<String>> {
+        match self {
+            OwnedColumn::Decimal75(precision, column_scale, col) => {
+                if scale < column_scale {
+                    return Err(OwnedColumnError::TypeCastError {
+                        from_type: ColumnType::Decimal75(precision, column_scale),
+                        to_type: ColumnType::Decimal75(precision, scale),
+                    });
+                }
+                let scale_factor = BigInt::from(10).pow(u32::from(
+                    (i16::from(scale) - i16::from(column_scale)).unsigned_abs(),
+                ));
+                Ok(col
+                    .into_iter()
+                    .map(|value| {
+                        format_decimal(&(Into::<BigInt>::into(value) * &scale_factor), scale)
+                    })
+                    .collect())
+            }
+            _ => Err(OwnedColumnError::TypeCastError {
+                from_type: self.column_type(),
+                to_type: ColumnType::Decimal75(Precision::MAX, scale),
+            }),
+        }
+    }
+
+    /// The error returned when this column cannot be extracted as a column of type `to_type`.
+    fn type_cast_error(&self, to_type: ColumnType) -> OwnedColumnError {
+        OwnedColumnError::TypeCastError {
+            from_type: self.column_type(),
+            to_type,
+        }
+    }
+
     #[cfg(test)]
     /// Returns an iterator over the raw data of the column
     /// assuming the underlying type is [i8], panicking if it is not.
@@ -302,6 +780,15 @@ impl<S: Scalar> OwnedColumn<S> {
             _ => panic!("Expected VarChar column"),
         }
     }
+    #[cfg(test)]
+    /// Returns an iterator over the raw data of the column
+    /// assuming the underlying type is [Vec<u8>], panicking if it is not.
+    pub fn bytes_iter(&self) -> impl Iterator<Item = &Vec<u8>> {
+        match self {
+            OwnedColumn::VarBinary(col) => col.iter(),
+            _ => panic!("Expected VarBinary column"),
+        }
+    }
 }
 
 impl<'a, S: Scalar> From<&Column<'a, S>> for OwnedColumn<S> {
@@ -315,6 +802,9 @@ impl<'a, S: Scalar> From<&Column<'a, S>> for OwnedColumn<S> {
             Column::VarChar((col, _)) => {
                 OwnedColumn::VarChar(col.iter().map(ToString::to_string).collect())
             }
+            Column::VarBinary((col, _)) => {
+                OwnedColumn::VarBinary(col.iter().map(|b| b.to_vec()).collect())
+            }
             Column::Int128(col) => OwnedColumn::Int128(col.to_vec()),
             Column::Decimal75(precision, scale, col) => {
                 OwnedColumn::Decimal75(*precision, *scale, col.to_vec())
@@ -325,6 +815,41 @@ impl<'a, S: Scalar> From<&Column<'a, S>> for OwnedColumn<S> {
     }
 }
 
+fn repeat_whole_slice<T: Clone>(slice: &[T], n: usize) -> Vec<T> {
+    core::iter::repeat(slice)
+        .take(n)
+        .flatten()
+        .cloned()
+        .collect()
+}
+
+fn repeat_each_slice<T: Clone>(slice: &[T], n: usize) -> Vec<T> {
+    slice
+        .iter()
+        .flat_map(|value| core::iter::repeat(value).take(n))
+        .cloned()
+        .collect()
+}
+
+/// Renders the decimal `value * 10^-scale` in fixed-point notation, e.g. `-0.05` for `-5` with a scale of 2.
+pub(super) fn format_decimal(value: &BigInt, scale: i8) -> String {
+    let sign = if value.sign() == Sign::Minus { "-" } else { "" };
+    let digits = value.magnitude().to_string();
+    let num_fractional_digits = usize::from(scale.unsigned_abs());
+    if scale <= 0 {
+        let trailing_zeros = if digits == "0" {
+            0
+        } else {
+            num_fractional_digits
+        };
+        format!("{sign}{digits}{}", "0".repeat(trailing_zeros))
+    } else {
+        let digits = format!("{digits:0>width$}", width = num_fractional_digits + 1);
+        let (integer_part, fractional_part) = digits.split_at(digits.len() - num_fractional_digits);
+        format!("{sign}{integer_part}.{fractional_part}")
+    }
+}
+
 /// Compares the tuples `(order_by_pairs[0][i], order_by_pairs[1][i], ...)` and
 /// `(order_by_pairs[0][j], order_by_pairs[1][j], ...)` in lexicographic order.
 /// Note that direction flips the ordering.
@@ -347,6 +872,7 @@ pub(crate) fn compare_indexes_by_owned_columns_with_direction<S: Scalar>(
                 OwnedColumn::Int128(col) => col[i].cmp(&col[j]),
                 OwnedColumn::Decimal75(_, _, col) | OwnedColumn::Scalar(col) => col[i].cmp(&col[j]),
                 OwnedColumn::VarChar(col) => col[i].cmp(&col[j]),
+                OwnedColumn::VarBinary(col) => col[i].cmp(&col[j]),
             };
             match direction {
                 OrderByDirection::Asc => ordering,
@@ -371,6 +897,527 @@ mod test {
         assert_eq!(col.slice(1, 4), OwnedColumn::Int128(vec![2, 3, 4]));
     }
 
+    #[test]
+    fn we_can_repeat_a_column() {
+        let col: OwnedColumn<Curve25519Scalar> = OwnedColumn::BigInt(vec![1, 2, 3]);
+        let repeated = col.repeat_whole(3);
+        assert_eq!(repeated.len(), 9);
+        assert_eq!(
+            repeated,
+            OwnedColumn::BigInt(vec![1, 2, 3, 1, 2, 3, 1, 2, 3])
+        );
+        let repeated = col.repeat_each(2);
+        assert_eq!(repeated.len(), 6);
+        assert_eq!(repeated, OwnedColumn::BigInt(vec![1, 1, 2, 2, 3, 3]));
+
+        let col: OwnedColumn<Curve25519Scalar> =
+            OwnedColumn::VarChar(vec!["a".to_string(), "b".to_string()]);
+        assert_eq!(
+            col.repeat_whole(3),
+            OwnedColumn::VarChar(
+                ["a", "b", "a", "b", "a", "b"]
+                    .map(ToString::to_string)
+                    .to_vec()
+            )
+        );
+        assert_eq!(
+            col.repeat_each(2),
+            OwnedColumn::VarChar(["a", "a", "b", "b"].map(ToString::to_string).to_vec())
+        );
+        assert!(col.repeat_whole(0).is_empty());
+        assert!(col.repeat_each(0).is_empty());
+    }
+
+    #[test]
+    fn we_can_get_the_lengths_of_a_varchar_column() {
+        let col: OwnedColumn<Curve25519Scalar> = OwnedColumn::VarChar(
+            ["abc", "", "héllo", "日本"]
+                .map(ToString::to_string)
+                .to_vec(),
+        );
+        assert_eq!(
+            col.try_length(LengthMode::Bytes).unwrap(),
+            OwnedColumn::BigInt(vec![3, 0, 6, 6])
+        );
+        assert_eq!(
+            col.try_length(LengthMode::Characters).unwrap(),
+            OwnedColumn::BigInt(vec![3, 0, 5, 2])
+        );
+        assert_eq!(
+            col.try_length(LengthMode::default()),
+            col.try_length(LengthMode::Characters)
+        );
+
+        let col: OwnedColumn<Curve25519Scalar> = OwnedColumn::VarChar(vec![]);
+        assert_eq!(
+            col.try_length(LengthMode::Bytes).unwrap(),
+            OwnedColumn::BigInt(vec![])
+        );
+    }
+
+    #[test]
+    fn we_can_count_the_values_of_a_bigint_column() {
+        let col: OwnedColumn<Curve25519Scalar> = OwnedColumn::BigInt(vec![3, 1, 3, -2, 1, 3]);
+        assert_eq!(
+            col.value_counts().unwrap(),
+            vec![
+                (LiteralValue::BigInt(3), 3),
+                (LiteralValue::BigInt(1), 2),
+                (LiteralValue::BigInt(-2), 1),
+            ]
+        );
+
+        let col: OwnedColumn<Curve25519Scalar> = OwnedColumn::BigInt(vec![]);
+        assert_eq!(col.value_counts().unwrap(), vec![]);
+    }
+
+    #[test]
+    fn we_can_count_the_values_of_a_varchar_column() {
+        let col: OwnedColumn<Curve25519Scalar> = OwnedColumn::VarChar(
+            ["b", "a", "", "a", "b", "a"]
+                .map(ToString::to_string)
+                .to_vec(),
+        );
+        let varchar = |s: &str| LiteralValue::VarChar((s.to_string(), s.into()));
+        assert_eq!(
+            col.value_counts().unwrap(),
+            vec![(varchar("b"), 2), (varchar("a"), 3), (varchar(""), 1)]
+        );
+    }
+
+    #[test]
+    fn we_cannot_get_the_lengths_of_a_non_varchar_column() {
+        let col: OwnedColumn<Curve25519Scalar> = OwnedColumn::BigInt(vec![1, 2]);
+        assert_eq!(
+            col.try_length(LengthMode::Bytes),
+            Err(OwnedColumnError::ColumnTypeMismatch {
+                expected: ColumnType::VarChar,
+                actual: ColumnType::BigInt,
+            })
+        );
+    }
+
+    #[test]
+    fn we_can_widen_integer_columns() {
+        let col: OwnedColumn<Curve25519Scalar> = OwnedColumn::TinyInt(vec![-128, 0, 127]);
+        assert_eq!(
+            col.try_cast(ColumnType::Int).unwrap(),
+            OwnedColumn::Int(vec![-128, 0, 127])
+        );
+        assert_eq!(
+            col.try_cast(ColumnType::Int128).unwrap(),
+            OwnedColumn::Int128(vec![-128, 0, 127])
+        );
+        let col: OwnedColumn<Curve25519Scalar> = OwnedColumn::BigInt(vec![i64::MIN, 1]);
+        assert_eq!(
+            col.try_cast(ColumnType::Int128).unwrap(),
+            OwnedColumn::Int128(vec![i128::from(i64::MIN), 1])
+        );
+        assert_eq!(col.try_cast(ColumnType::BigInt).unwrap(), col);
+        assert_eq!(
+            col.try_cast(ColumnType::Scalar).unwrap(),
+            OwnedColumn::Scalar(vec![
+                Curve25519Scalar::from(i64::MIN),
+                Curve25519Scalar::ONE
+            ])
+        );
+    }
+
+    #[test]
+    fn we_cannot_narrow_integer_columns() {
+        let col: OwnedColumn<Curve25519Scalar> = OwnedColumn::Int(vec![1, 2]);
+        assert_eq!(
+            col.try_cast(ColumnType::SmallInt),
+            Err(OwnedColumnError::TypeCastError {
+                from_type: ColumnType::Int,
+                to_type: ColumnType::SmallInt,
+            })
+        );
+    }
+
+    #[test]
+    fn we_can_cast_numeric_columns_to_decimals() {
+        let col: OwnedColumn<Curve25519Scalar> = OwnedColumn::Int128(vec![-5, 12]);
+        assert_eq!(
+            col.try_cast(ColumnType::Decimal75(Precision::new(5).unwrap(), 2))
+                .unwrap(),
+            OwnedColumn::Decimal75(
+                Precision::new(5).unwrap(),
+                2,
+                vec![Curve25519Scalar::from(-500), Curve25519Scalar::from(1200)]
+            )
+        );
+        let col: OwnedColumn<Curve25519Scalar> = OwnedColumn::Decimal75(
+            Precision::new(4).unwrap(),
+            1,
+            vec![Curve25519Scalar::from(-15)],
+        );
+        assert_eq!(
+            col.try_cast(ColumnType::Decimal75(Precision::new(6).unwrap(), 3))
+                .unwrap(),
+            OwnedColumn::Decimal75(
+                Precision::new(6).unwrap(),
+                3,
+                vec![Curve25519Scalar::from(-1500)]
+            )
+        );
+        // Lowering the scale would lose digits.
+        assert_eq!(
+            col.try_cast(ColumnType::Decimal75(Precision::new(6).unwrap(), 0)),
+            Err(OwnedColumnError::TypeCastError {
+                from_type: ColumnType::Decimal75(Precision::new(4).unwrap(), 1),
+                to_type: ColumnType::Decimal75(Precision::new(6).unwrap(), 0),
+            })
+        );
+    }
+
+    #[test]
+    fn we_cannot_cast_to_a_decimal_that_is_too_small() {
+        let col: OwnedColumn<Curve25519Scalar> = OwnedColumn::BigInt(vec![1, 1000]);
+        assert_eq!(
+            col.try_cast(ColumnType::Decimal75(Precision::new(5).unwrap(), 2)),
+            Err(OwnedColumnError::DecimalValueExceedsPrecision {
+                precision: 5,
+                index: 1
+            })
+        );
+    }
+
+    #[test]
+    fn we_can_rescale_to_decimals_at_the_maximum_precision() {
+        let col: OwnedColumn<Curve25519Scalar> = OwnedColumn::BigInt(vec![-9, 0, 9]);
+        let max_precision = Precision::new(75).unwrap();
+        assert_eq!(
+            col.try_cast(ColumnType::Decimal75(max_precision, 74))
+                .unwrap(),
+            OwnedColumn::Decimal75(
+                max_precision,
+                74,
+                [-9, 0, 9]
+                    .map(|value| Curve25519Scalar::from(value) * Curve25519Scalar::pow10(74))
+                    .to_vec()
+            )
+        );
+        // 9 * 10^75 has 76 digits
+        assert_eq!(
+            col.try_cast(ColumnType::Decimal75(max_precision, 75)),
+            Err(OwnedColumnError::DecimalValueExceedsPrecision {
+                precision: 75,
+                index: 0
+            })
+        );
+    }
+
+    #[test]
+    fn we_cannot_cast_to_a_decimal_whose_upscaled_values_would_wrap_around() {
+        // 8 * 10^75 is larger than the field, so upscaling it first would wrap around
+        let col: OwnedColumn<Curve25519Scalar> = OwnedColumn::BigInt(vec![0, 8]);
+        assert_eq!(
+            col.try_cast(ColumnType::Decimal75(Precision::new(75).unwrap(), 75)),
+            Err(OwnedColumnError::DecimalValueExceedsPrecision {
+                precision: 75,
+                index: 1
+            })
+        );
+        let col: OwnedColumn<Curve25519Scalar> = OwnedColumn::BigInt(vec![i64::MIN]);
+        assert_eq!(
+            col.try_cast(ColumnType::Decimal75(Precision::new(75).unwrap(), 60)),
+            Err(OwnedColumnError::DecimalValueExceedsPrecision {
+                precision: 75,
+                index: 0
+            })
+        );
+    }
+
+    #[test]
+    fn we_cannot_cast_between_unrelated_types() {
+        let col: OwnedColumn<Curve25519Scalar> = OwnedColumn::VarChar(vec!["1".to_string()]);
+        for to_type in [
+            ColumnType::BigInt,
+            ColumnType::Boolean,
+            ColumnType::VarBinary,
+        ] {
+            assert_eq!(
+                col.try_cast(to_type),
+                Err(OwnedColumnError::TypeCastError {
+                    from_type: ColumnType::VarChar,
+                    to_type,
+                })
+            );
+        }
+        let col: OwnedColumn<Curve25519Scalar> = OwnedColumn::Boolean(vec![true]);
+        assert!(col.try_cast(ColumnType::BigInt).is_err());
+    }
+
+    #[test]
+    fn we_can_convert_columns_to_literal_values() {
+        assert_eq!(
+            OwnedColumn::<Curve25519Scalar>::BigInt(vec![1, -2]).to_literal_values(),
+            vec![LiteralValue::BigInt(1), LiteralValue::BigInt(-2)]
+        );
+        assert_eq!(
+            OwnedColumn::<Curve25519Scalar>::VarChar(vec!["a".to_string()]).to_literal_values(),
+            vec![LiteralValue::VarChar(("a".to_string(), "a".into()))]
+        );
+        let precision = Precision::new(5).unwrap();
+        assert_eq!(
+            OwnedColumn::Decimal75(precision, 2, vec![Curve25519Scalar::from(123)])
+                .to_literal_values(),
+            vec![LiteralValue::Decimal75(
+                precision,
+                2,
+                Curve25519Scalar::from(123)
+            )]
+        );
+        assert_eq!(
+            OwnedColumn::<Curve25519Scalar>::TimestampTZ(
+                PoSQLTimeUnit::Second,
+                PoSQLTimeZone::Utc,
+                vec![7]
+            )
+            .to_literal_values(),
+            vec![LiteralValue::TimeStampTZ(
+                PoSQLTimeUnit::Second,
+                PoSQLTimeZone::Utc,
+                7
+            )]
+        );
+        assert!(OwnedColumn::<Curve25519Scalar>::Int(vec![])
+            .to_literal_values()
+            .is_empty());
+    }
+
+    #[test]
+    fn we_can_create_a_decimal75_column_within_its_precision() {
+        let precision = Precision::new(3).unwrap();
+        let scalars: Vec<Curve25519Scalar> =
+            [999, -999, 0, 12].map(Curve25519Scalar::from).to_vec();
+        assert_eq!(
+            OwnedColumn::try_new_decimal75(precision, 2, scalars.clone()),
+            Ok(OwnedColumn::Decimal75(precision, 2, scalars.clone()))
+        );
+        assert_eq!(
+            OwnedColumn::try_new_decimal75(precision, -2, scalars.clone()),
+            Ok(OwnedColumn::Decimal75(precision, -2, scalars))
+        );
+        let scalars = vec![Curve25519Scalar::MAX_SIGNED, -Curve25519Scalar::MAX_SIGNED];
+        assert_eq!(
+            OwnedColumn::try_new_decimal75(Precision::new(75).unwrap(), 0, scalars.clone()),
+            Err(OwnedColumnError::DecimalValueExceedsPrecision {
+                precision: 75,
+                index: 0
+            })
+        );
+    }
+
+    #[test]
+    fn we_cannot_create_a_decimal75_column_with_values_exceeding_its_precision() {
+        let precision = Precision::new(3).unwrap();
+        assert_eq!(
+            OwnedColumn::try_new_decimal75(
+                precision,
+                1,
+                [999, 1000].map(Curve25519Scalar::from).to_vec()
+            ),
+            Err(OwnedColumnError::DecimalValueExceedsPrecision {
+                precision: 3,
+                index: 1
+            })
+        );
+        assert_eq!(
+            OwnedColumn::try_new_decimal75(
+                precision,
+                1,
+                [0, 5, -1000].map(Curve25519Scalar::from).to_vec()
+            ),
+            Err(OwnedColumnError::DecimalValueExceedsPrecision {
+                precision: 3,
+                index: 2
+            })
+        );
+    }
+
+    #[test]
+    fn we_cannot_create_a_decimal75_column_with_a_scale_exceeding_its_precision() {
+        assert_eq!(
+            OwnedColumn::<Curve25519Scalar>::try_new_decimal75(
+                Precision::new(3).unwrap(),
+                4,
+                vec![]
+            ),
+            Err(OwnedColumnError::DecimalScaleExceedsPrecision {
+                precision: 3,
+                scale: 4
+            })
+        );
+    }
+
+    #[test]
+    fn we_cannot_deserialize_a_decimal75_column_with_values_exceeding_its_precision() {
+        let precision = Precision::new(3).unwrap();
+        let column = OwnedColumn::Decimal75(
+            precision,
+            1,
+            [999, -999].map(Curve25519Scalar::from).to_vec(),
+        );
+        let serialized = serde_json::to_string(&column).unwrap();
+        assert_eq!(
+            serde_json::from_str::<OwnedColumn<Curve25519Scalar>>(&serialized).unwrap(),
+            column
+        );
+
+        let invalid = OwnedColumn::Decimal75(
+            precision,
+            1,
+            [999, 1000].map(Curve25519Scalar::from).to_vec(),
+        );
+        let serialized = serde_json::to_string(&invalid).unwrap();
+        assert!(serde_json::from_str::<OwnedColumn<Curve25519Scalar>>(&serialized).is_err());
+    }
+
+    #[test]
+    fn we_can_concatenate_many_columns_at_once() {
+        let columns: Vec<OwnedColumn<Curve25519Scalar>> = (0..5)
+            .map(|i| OwnedColumn::BigInt((0..i).map(|j| 10 * i + j).collect()))
+            .collect();
+        let column_refs: Vec<_> = columns.iter().collect();
+        let concatenated = OwnedColumn::concat_many(&column_refs).unwrap();
+        let pairwise = column_refs[1..]
+            .iter()
+            .try_fold(columns[0].clone(), |acc, column| {
+                OwnedColumn::concat_many(&[&acc, column])
+            })
+            .unwrap();
+        assert_eq!(concatenated, pairwise);
+        assert_eq!(
+            concatenated,
+            OwnedColumn::BigInt(vec![10, 20, 21, 30, 31, 32, 40, 41, 42, 43])
+        );
+
+        let col: OwnedColumn<Curve25519Scalar> = OwnedColumn::Decimal75(
+            Precision::new(10).unwrap(),
+            2,
+            vec![Curve25519Scalar::from(1)],
+        );
+        assert_eq!(
+            OwnedColumn::concat_many(&[&col, &col]).unwrap(),
+            OwnedColumn::Decimal75(
+                Precision::new(10).unwrap(),
+                2,
+                vec![Curve25519Scalar::from(1); 2]
+            )
+        );
+        assert_eq!(OwnedColumn::concat_many(&[&col]).unwrap(), col);
+    }
+
+    #[test]
+    fn we_cannot_concatenate_columns_of_different_types_or_no_columns() {
+        let precision = Precision::new(10).unwrap();
+        let scale_2: OwnedColumn<Curve25519Scalar> =
+            OwnedColumn::Decimal75(precision, 2, vec![Curve25519Scalar::from(1)]);
+        let scale_3: OwnedColumn<Curve25519Scalar> =
+            OwnedColumn::Decimal75(precision, 3, vec![Curve25519Scalar::from(1)]);
+        assert_eq!(
+            OwnedColumn::concat_many(&[&scale_2, &scale_2, &scale_3]),
+            Err(OwnedColumnError::ColumnTypeMismatch {
+                expected: ColumnType::Decimal75(precision, 2),
+                actual: ColumnType::Decimal75(precision, 3),
+            })
+        );
+        let bigint: OwnedColumn<Curve25519Scalar> = OwnedColumn::BigInt(vec![1]);
+        assert!(matches!(
+            OwnedColumn::concat_many(&[&bigint, &scale_2]),
+            Err(OwnedColumnError::ColumnTypeMismatch { .. })
+        ));
+        assert!(matches!(
+            OwnedColumn::<Curve25519Scalar>::concat_many(&[]),
+            Err(OwnedColumnError::Unsupported { .. })
+        ));
+    }
+
+    #[test]
+    fn we_can_extract_the_data_of_owned_columns() {
+        let col: OwnedColumn<Curve25519Scalar> = OwnedColumn::BigInt(vec![1, -2, 3]);
+        assert_eq!(col.try_into_i64_vec().unwrap(), vec![1, -2, 3]);
+
+        let col: OwnedColumn<Curve25519Scalar> = OwnedColumn::Int128(vec![i128::MIN, 0]);
+        assert_eq!(col.try_into_i128_vec().unwrap(), vec![i128::MIN, 0]);
+
+        let col: OwnedColumn<Curve25519Scalar> =
+            OwnedColumn::VarChar(vec!["a".to_string(), String::new()]);
+        assert_eq!(
+            col.try_into_string_vec().unwrap(),
+            vec!["a".to_string(), String::new()]
+        );
+
+        let col: OwnedColumn<Curve25519Scalar> = OwnedColumn::Boolean(vec![true, false]);
+        assert_eq!(col.try_into_bool_vec().unwrap(), vec![true, false]);
+
+        let col: OwnedColumn<Curve25519Scalar> = OwnedColumn::Decimal75(
+            Precision::new(10).unwrap(),
+            1,
+            [-15, 3, 0, 12345].map(Curve25519Scalar::from).to_vec(),
+        );
+        assert_eq!(
+            col.clone().try_into_decimal_strings(1).unwrap(),
+            vec!["-1.5", "0.3", "0.0", "1234.5"]
+        );
+        assert_eq!(
+            col.try_into_decimal_strings(3).unwrap(),
+            vec!["-1.500", "0.300", "0.000", "1234.500"]
+        );
+
+        let col: OwnedColumn<Curve25519Scalar> = OwnedColumn::Decimal75(
+            Precision::new(10).unwrap(),
+            -2,
+            [-15, 0].map(Curve25519Scalar::from).to_vec(),
+        );
+        assert_eq!(col.try_into_decimal_strings(0).unwrap(), vec!["-1500", "0"]);
+    }
+
+    #[test]
+    fn we_cannot_extract_the_data_of_owned_columns_of_a_different_type() {
+        let col: OwnedColumn<Curve25519Scalar> = OwnedColumn::VarChar(vec!["1".to_string()]);
+        assert_eq!(
+            col.try_into_i64_vec(),
+            Err(OwnedColumnError::TypeCastError {
+                from_type: ColumnType::VarChar,
+                to_type: ColumnType::BigInt,
+            })
+        );
+
+        let col: OwnedColumn<Curve25519Scalar> = OwnedColumn::BigInt(vec![1]);
+        assert!(matches!(
+            col.clone().try_into_i128_vec(),
+            Err(OwnedColumnError::TypeCastError { .. })
+        ));
+        assert!(matches!(
+            col.clone().try_into_string_vec(),
+            Err(OwnedColumnError::TypeCastError { .. })
+        ));
+        assert!(matches!(
+            col.clone().try_into_bool_vec(),
+            Err(OwnedColumnError::TypeCastError { .. })
+        ));
+        assert!(matches!(
+            col.try_into_decimal_strings(0),
+            Err(OwnedColumnError::TypeCastError { .. })
+        ));
+
+        let col: OwnedColumn<Curve25519Scalar> = OwnedColumn::Decimal75(
+            Precision::new(10).unwrap(),
+            2,
+            vec![Curve25519Scalar::from(1)],
+        );
+        assert_eq!(
+            col.try_into_decimal_strings(1),
+            Err(OwnedColumnError::TypeCastError {
+                from_type: ColumnType::Decimal75(Precision::new(10).unwrap(), 2),
+                to_type: ColumnType::Decimal75(Precision::new(10).unwrap(), 1),
+            })
+        );
+    }
+
     #[test]
     fn we_can_permute_a_column() {
         let col: OwnedColumn<Curve25519Scalar> = OwnedColumn::Int128(vec![1, 2, 3, 4, 5]);
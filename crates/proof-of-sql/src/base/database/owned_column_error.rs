@@ -1,5 +1,6 @@
 use crate::base::database::ColumnType;
 use alloc::string::String;
+use proof_of_sql_parser::Identifier;
 use snafu::Snafu;
 
 /// Errors from operations related to `OwnedColumn`s.
@@ -25,6 +26,42 @@ pub enum OwnedColumnError {
         /// The underlying error
         error: String,
     },
+    /// Columns that must have the same type have different types.
+    #[snafu(display("Column type mismatch: expected {expected}, found {actual}"))]
+    ColumnTypeMismatch {
+        /// The type of the first column.
+        expected: ColumnType,
+        /// The type of the mismatching column.
+        actual: ColumnType,
+    },
+    /// The scale of a decimal is larger than its precision.
+    #[snafu(display("Decimal scale {scale} exceeds the precision {precision}"))]
+    DecimalScaleExceedsPrecision {
+        /// The declared precision.
+        precision: u8,
+        /// The declared scale.
+        scale: i8,
+    },
+    /// A decimal value has more digits than the declared precision allows.
+    #[snafu(display("Decimal value at index {index} does not fit in precision {precision}"))]
+    DecimalValueExceedsPrecision {
+        /// The declared precision.
+        precision: u8,
+        /// The index of the offending value.
+        index: usize,
+    },
+    /// A column could not be found in a table.
+    #[snafu(display("Column {column} not found"))]
+    ColumnNotFound {
+        /// The missing column
+        column: Identifier,
+    },
+    /// A column with the same name already exists in a table.
+    #[snafu(display("Column {column} already exists"))]
+    DuplicateColumn {
+        /// The duplicate column
+        column: Identifier,
+    },
 }
 
 /// Result type for operations related to `OwnedColumn`s.
@@ -237,6 +237,7 @@ impl<S: Scalar> OwnedColumn<S> {
             (Self::Boolean(lhs), Self::Boolean(rhs)) => Ok(Self::Boolean(slice_eq(lhs, rhs))),
             (Self::Scalar(lhs), Self::Scalar(rhs)) => Ok(Self::Boolean(slice_eq(lhs, rhs))),
             (Self::VarChar(lhs), Self::VarChar(rhs)) => Ok(Self::Boolean(slice_eq(lhs, rhs))),
+            (Self::VarBinary(lhs), Self::VarBinary(rhs)) => Ok(Self::Boolean(slice_eq(lhs, rhs))),
             (Self::TimestampTZ(_, _, _), Self::TimestampTZ(_, _, _)) => {
                 todo!("Implement equality check for TimeStampTZ")
             }
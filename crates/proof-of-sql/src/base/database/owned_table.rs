@@ -1,6 +1,22 @@
-use super::OwnedColumn;
-use crate::base::{map::IndexMap, scalar::Scalar};
+use super::{
+    owned_column::format_decimal, Column, ColumnField, OwnedColumn, OwnedColumnError,
+    OwnedColumnResult,
+};
+use crate::base::{
+    map::IndexMap,
+    scalar::{Scalar, ScalarExt},
+};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use bumpalo::Bump;
+use core::fmt::Write;
+use num_bigint::{BigInt, Sign};
+use num_traits::Zero;
 use proof_of_sql_parser::Identifier;
+use serde::{Deserialize, Serialize};
 use snafu::Snafu;
 
 /// An error that occurs when working with tables.
@@ -9,13 +25,25 @@ pub enum OwnedTableError {
     /// The columns have different lengths.
     #[snafu(display("Columns have different lengths"))]
     ColumnLengthMismatch,
+    /// More than one column has the same name.
+    #[snafu(display("Column {column} appears more than once"))]
+    DuplicateColumnName {
+        /// The repeated name
+        column: Identifier,
+    },
 }
 /// A table of data, with schema included. This is simply a map from `Identifier` to `OwnedColumn`,
 /// where columns order matters.
 /// This is primarily used as an internal result that is used before
 /// converting to the final result in either Arrow format or JSON.
 /// This is the analog of an arrow [`RecordBatch`](arrow::record_batch::RecordBatch).
-#[derive(Debug, Clone, Eq)]
+///
+/// A table serializes as its map of columns. Deserialization checks that the columns have the same length.
+#[derive(Debug, Clone, Eq, Serialize, Deserialize)]
+#[serde(
+    into = "IndexMap<Identifier, OwnedColumn<S>>",
+    try_from = "IndexMap<Identifier, OwnedColumn<S>>"
+)]
 pub struct OwnedTable<S: Scalar> {
     table: IndexMap<Identifier, OwnedColumn<S>>,
 }
@@ -71,6 +99,264 @@ impl<S: Scalar> OwnedTable<S> {
     pub fn column_names(&self) -> impl Iterator<Item = &Identifier> {
         self.table.keys()
     }
+    /// Returns the schema of this table, i.e. the names and types of its columns in order.
+    #[must_use]
+    pub fn schema(&self) -> Vec<ColumnField> {
+        self.table
+            .iter()
+            .map(|(name, column)| ColumnField::new(*name, column.column_type()))
+            .collect()
+    }
+    /// Creates an [`OwnedTable`] with no rows whose schema is exactly `fields`,
+    /// e.g. to represent the result of a query that matches no rows.
+    ///
+    /// # Errors
+    /// Returns [`OwnedTableError::DuplicateColumnName`] if two fields have the same name.
+    pub fn empty_with_schema(fields: &[ColumnField]) -> Result<Self, OwnedTableError> {
+        let mut table = IndexMap::default();
+        for field in fields {
+            if table
+                .insert(field.name(), OwnedColumn::empty_of_type(field.data_type()))
+                .is_some()
+            {
+                return Err(OwnedTableError::DuplicateColumnName {
+                    column: field.name(),
+                });
+            }
+        }
+        Ok(Self { table })
+    }
+    /// Returns a new table with the column `old` renamed to `new`, keeping the order of the columns and their data.
+    ///
+    /// This is the equivalent of applying a SQL `AS` to the column after the fact.
+    ///
+    /// # Errors
+    /// Returns an error if there is no column named `old`, or if another column named `new` already exists.
+    pub fn rename_column(&self, old: &Identifier, new: Identifier) -> OwnedColumnResult<Self> {
+        if !self.table.contains_key(old) {
+            return Err(OwnedColumnError::ColumnNotFound { column: *old });
+        }
+        if new != *old && self.table.contains_key(&new) {
+            return Err(OwnedColumnError::DuplicateColumn { column: new });
+        }
+        Ok(Self {
+            table: self
+                .table
+                .iter()
+                .map(|(id, column)| (if id == old { new } else { *id }, column.clone()))
+                .collect(),
+        })
+    }
+
+    /// Returns a new table with the given schema, casting each column to its declared type with [`OwnedColumn::try_cast`].
+    ///
+    /// This is meant for loading heterogeneous sources into a single declared schema.
+    /// Columns are looked up by name and returned in the order of `fields`. Columns not in `fields` are dropped.
+    ///
+    /// # Errors
+    /// Returns [`OwnedColumnError::ColumnNotFound`] if a field has no column in the table,
+    /// and the errors of [`OwnedColumn::try_cast`] if a column can not be cast to its declared type.
+    pub fn coerce_to_schema(&self, fields: &[ColumnField]) -> OwnedColumnResult<Self> {
+        Ok(Self {
+            table: fields
+                .iter()
+                .map(|field| {
+                    let column =
+                        self.table
+                            .get(&field.name())
+                            .ok_or(OwnedColumnError::ColumnNotFound {
+                                column: field.name(),
+                            })?;
+                    Ok((field.name(), column.try_cast(field.data_type())?))
+                })
+                .collect::<OwnedColumnResult<_>>()?,
+        })
+    }
+
+    /// Returns a hash of the values of the `keys` columns for every row, e.g. to bucket rows by join key.
+    ///
+    /// Rows with equal key tuples hash equally and the hashes are stable across runs.
+    /// Numeric values are hashed by their value, with the trailing zeros of the unscaled value folded
+    /// into the scale, so equal integers of different widths hash equally, as do equal decimals with
+    /// different scales, such as `1`, `1.0` and `1.00`.
+    /// Other values are hashed through the canonical encoding of their scalar.
+    /// The hash is the first 8 bytes of a blake3 hash, so distinct keys are unlikely to collide.
+    ///
+    /// # Errors
+    /// Returns [`OwnedColumnError::ColumnNotFound`] if a key column is not in the table.
+    pub fn row_hashes(&self, keys: &[Identifier]) -> OwnedColumnResult<Vec<u64>> {
+        let alloc = Bump::new();
+        let key_columns = keys
+            .iter()
+            .map(|key| {
+                self.table
+                    .get(key)
+                    .map(|column| {
+                        let column_type = column.column_type();
+                        let scale = column_type
+                            .is_numeric()
+                            .then(|| column_type.scale().unwrap_or(0));
+                        let scalars =
+                            Column::from_owned_column(column, &alloc).to_scalar_with_scaling(0);
+                        (scalars, scale)
+                    })
+                    .ok_or(OwnedColumnError::ColumnNotFound { column: *key })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok((0..self.num_rows())
+            .map(|row| {
+                let mut hasher = blake3::Hasher::new();
+                for (scalars, scale) in &key_columns {
+                    match scale {
+                        Some(scale) => hash_decimal(&mut hasher, scalars[row].into(), *scale),
+                        None => {
+                            hasher.update(&scalars[row].to_canonical_bytes());
+                        }
+                    }
+                }
+                let hash: [u8; 32] = hasher.finalize().into();
+                u64::from_le_bytes(core::array::from_fn(|i| hash[i]))
+            })
+            .collect())
+    }
+
+    /// Whether the two tables have the same schema, ignoring their data and number of rows.
+    ///
+    /// Column order, names and types, including decimal precision and scale, must all match.
+    #[must_use]
+    pub fn schema_eq(&self, other: &Self) -> bool {
+        self.schema() == other.schema()
+    }
+
+    /// Renders the table as an ASCII table, for debugging.
+    ///
+    /// The header shows the name and type of each column. At most `max_rows` rows are shown,
+    /// followed by a note with the number of omitted rows. `VarChar` values longer than 32 characters
+    /// are truncated, and `Decimal75` values are shown with their scale applied.
+    #[must_use]
+    pub fn to_pretty_string(&self, max_rows: usize) -> String {
+        let num_shown_rows = self.num_rows().min(max_rows);
+        let cells: Vec<Vec<String>> = self
+            .table
+            .iter()
+            .map(|(name, column)| {
+                [name.to_string(), column.column_type().to_string()]
+                    .into_iter()
+                    .chain((0..num_shown_rows).map(|index| pretty_value(column, index)))
+                    .collect()
+            })
+            .collect();
+        let widths: Vec<usize> = cells
+            .iter()
+            .map(|column| {
+                column
+                    .iter()
+                    .map(|cell| cell.chars().count())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+        let separator = widths.iter().fold(String::from("+"), |separator, width| {
+            // `as_str` rather than `&`: the `polars` feature adds `Add` impls for `String`,
+            // which stops `&String` from being coerced to `&str` here.
+            separator + "-".repeat(width + 2).as_str() + "+"
+        });
+        let row = |index: usize| {
+            cells
+                .iter()
+                .zip(&widths)
+                .fold(String::from("|"), |mut row, (column, width)| {
+                    // Writing to a `String` cannot fail.
+                    let _ = write!(row, " {:<width$} |", column[index]);
+                    row
+                })
+        };
+
+        let mut lines = Vec::with_capacity(num_shown_rows + 6);
+        lines.extend([separator.clone(), row(0), row(1), separator.clone()]);
+        lines.extend((2..num_shown_rows + 2).map(row));
+        if num_shown_rows > 0 {
+            lines.push(separator);
+        }
+        if num_shown_rows < self.num_rows() {
+            lines.push(format!("({} more rows)", self.num_rows() - num_shown_rows));
+        }
+        lines.join("\n")
+    }
+}
+
+/// The maximum number of characters of a `VarChar` value shown by [`OwnedTable::to_pretty_string`].
+const MAX_PRETTY_VARCHAR_LENGTH: usize = 32;
+
+/// Renders the value at `index` in `column` for [`OwnedTable::to_pretty_string`].
+fn pretty_value<S: Scalar>(column: &OwnedColumn<S>, index: usize) -> String {
+    match column {
+        OwnedColumn::Boolean(col) => col[index].to_string(),
+        OwnedColumn::TinyInt(col) => col[index].to_string(),
+        OwnedColumn::SmallInt(col) => col[index].to_string(),
+        OwnedColumn::Int(col) => col[index].to_string(),
+        OwnedColumn::BigInt(col) | OwnedColumn::TimestampTZ(_, _, col) => col[index].to_string(),
+        OwnedColumn::Int128(col) => col[index].to_string(),
+        OwnedColumn::VarChar(col) => {
+            if col[index].chars().count() > MAX_PRETTY_VARCHAR_LENGTH {
+                let truncated: String = col[index]
+                    .chars()
+                    .take(MAX_PRETTY_VARCHAR_LENGTH - 3)
+                    .collect();
+                truncated + "..."
+            } else {
+                col[index].clone()
+            }
+        }
+        OwnedColumn::VarBinary(col) => {
+            col[index].iter().fold(String::from("0x"), |mut hex, byte| {
+                let _ = write!(hex, "{byte:02x}");
+                hex
+            })
+        }
+        OwnedColumn::Decimal75(_, scale, col) => format_decimal(&col[index].into(), *scale),
+        OwnedColumn::Scalar(col) => Into::<BigInt>::into(col[index]).to_string(),
+    }
+}
+
+/// Adds the value `unscaled * 10^-scale` to `hasher` for [`OwnedTable::row_hashes`].
+///
+/// The trailing zeros of `unscaled` are folded into the scale first, so that the input only depends on the value.
+/// The unscaled value is written as 32 bytes of two's complement, which fits every scalar.
+fn hash_decimal(hasher: &mut blake3::Hasher, unscaled: BigInt, scale: i8) {
+    let ten = BigInt::from(10);
+    let mut value = unscaled;
+    let mut scale = i16::from(scale);
+    if value.is_zero() {
+        scale = 0;
+    } else {
+        while (&value % &ten).is_zero() {
+            value /= &ten;
+            scale -= 1;
+        }
+    }
+    let bytes = value.to_signed_bytes_le();
+    let mut word = [if value.sign() == Sign::Minus {
+        u8::MAX
+    } else {
+        0
+    }; 32];
+    word[..bytes.len()].copy_from_slice(&bytes);
+    hasher.update(&word);
+    hasher.update(&scale.to_le_bytes());
+}
+
+impl<S: Scalar> TryFrom<IndexMap<Identifier, OwnedColumn<S>>> for OwnedTable<S> {
+    type Error = OwnedTableError;
+    fn try_from(table: IndexMap<Identifier, OwnedColumn<S>>) -> Result<Self, Self::Error> {
+        Self::try_new(table)
+    }
+}
+
+impl<S: Scalar> From<OwnedTable<S>> for IndexMap<Identifier, OwnedColumn<S>> {
+    fn from(table: OwnedTable<S>) -> Self {
+        table.into_inner()
+    }
 }
 
 // Note: we modify the default PartialEq for IndexMap to also check for column ordering.
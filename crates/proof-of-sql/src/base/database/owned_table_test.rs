@@ -1,7 +1,11 @@
 use crate::{
     base::{
-        database::{owned_table_utility::*, OwnedColumn, OwnedTable, OwnedTableError},
+        database::{
+            owned_table_utility::*, ColumnField, ColumnType, OwnedColumn, OwnedColumnError,
+            OwnedTable, OwnedTableError,
+        },
         map::IndexMap,
+        math::decimal::Precision,
         scalar::Curve25519Scalar,
     },
     proof_primitive::dory::DoryScalar,
@@ -185,3 +189,376 @@ fn we_cannot_create_an_owned_table_with_differing_column_lengths() {
         Err(OwnedTableError::ColumnLengthMismatch)
     ));
 }
+#[test]
+fn we_can_get_the_schema_of_an_owned_table() {
+    let owned_table: OwnedTable<Curve25519Scalar> = owned_table([
+        bigint("a", [0, 1]),
+        varchar("b", ["0", "1"]),
+        decimal75("c", 10, 2, [0, 1]),
+    ]);
+    assert_eq!(
+        owned_table.schema(),
+        vec![
+            ColumnField::new("a".parse().unwrap(), ColumnType::BigInt),
+            ColumnField::new("b".parse().unwrap(), ColumnType::VarChar),
+            ColumnField::new(
+                "c".parse().unwrap(),
+                ColumnType::Decimal75(Precision::new(10).unwrap(), 2)
+            ),
+        ]
+    );
+    assert_eq!(
+        OwnedTable::<Curve25519Scalar>::try_new(IndexMap::default())
+            .unwrap()
+            .schema(),
+        vec![]
+    );
+}
+#[test]
+fn we_can_compare_schemas_of_tables_with_differing_data() {
+    let owned_table_a: OwnedTable<Curve25519Scalar> = owned_table([
+        bigint("a", [0, 1]),
+        varchar("b", ["0", "1"]),
+        decimal75("c", 10, 2, [0, 1]),
+    ]);
+    let owned_table_b: OwnedTable<Curve25519Scalar> = owned_table([
+        bigint("a", [2, 3, 4]),
+        varchar("b", ["2", "3", "4"]),
+        decimal75("c", 10, 2, [2, 3, 4]),
+    ]);
+    let owned_table_c: OwnedTable<Curve25519Scalar> = owned_table([
+        bigint("a", [0; 0]),
+        varchar("b", [""; 0]),
+        decimal75("c", 10, 2, [0; 0]),
+    ]);
+    assert_ne!(owned_table_a, owned_table_b);
+    assert!(owned_table_a.schema_eq(&owned_table_b));
+    assert!(owned_table_a.schema_eq(&owned_table_c));
+}
+#[test]
+fn we_get_schema_inequality_between_tables_with_differing_column_types() {
+    let owned_table_a: OwnedTable<Curve25519Scalar> =
+        owned_table([bigint("a", [0]), decimal75("c", 10, 2, [0])]);
+    let differing_scale: OwnedTable<Curve25519Scalar> =
+        owned_table([bigint("a", [0]), decimal75("c", 10, 3, [0])]);
+    let differing_precision: OwnedTable<Curve25519Scalar> =
+        owned_table([bigint("a", [0]), decimal75("c", 11, 2, [0])]);
+    let differing_type: OwnedTable<Curve25519Scalar> =
+        owned_table([int("a", [0]), decimal75("c", 10, 2, [0])]);
+    let differing_order: OwnedTable<Curve25519Scalar> =
+        owned_table([decimal75("c", 10, 2, [0]), bigint("a", [0])]);
+    let differing_name: OwnedTable<Curve25519Scalar> =
+        owned_table([bigint("b", [0]), decimal75("c", 10, 2, [0])]);
+    let missing_column: OwnedTable<Curve25519Scalar> = owned_table([bigint("a", [0])]);
+    for other in [
+        differing_scale,
+        differing_precision,
+        differing_type,
+        differing_order,
+        differing_name,
+        missing_column,
+    ] {
+        assert!(!owned_table_a.schema_eq(&other));
+        assert!(!other.schema_eq(&owned_table_a));
+    }
+}
+#[test]
+fn we_can_rename_a_column_of_an_owned_table() {
+    let owned_table_a: OwnedTable<Curve25519Scalar> = owned_table([
+        bigint("a", [0, 1, 2]),
+        varchar("b", ["0", "1", "2"]),
+        boolean("c", [true, false, true]),
+    ]);
+    let renamed = owned_table_a
+        .rename_column(&"b".parse().unwrap(), "d".parse().unwrap())
+        .unwrap();
+    assert_eq!(
+        renamed,
+        owned_table([
+            bigint("a", [0, 1, 2]),
+            varchar("d", ["0", "1", "2"]),
+            boolean("c", [true, false, true]),
+        ])
+    );
+    assert_eq!(
+        owned_table_a
+            .rename_column(&"b".parse().unwrap(), "b".parse().unwrap())
+            .unwrap(),
+        owned_table_a
+    );
+}
+#[test]
+fn we_cannot_rename_a_missing_column_of_an_owned_table() {
+    let owned_table_a: OwnedTable<Curve25519Scalar> =
+        owned_table([bigint("a", [0, 1, 2]), varchar("b", ["0", "1", "2"])]);
+    assert_eq!(
+        owned_table_a.rename_column(&"c".parse().unwrap(), "d".parse().unwrap()),
+        Err(OwnedColumnError::ColumnNotFound {
+            column: "c".parse().unwrap()
+        })
+    );
+}
+#[test]
+fn we_cannot_rename_a_column_of_an_owned_table_to_an_existing_name() {
+    let owned_table_a: OwnedTable<Curve25519Scalar> =
+        owned_table([bigint("a", [0, 1, 2]), varchar("b", ["0", "1", "2"])]);
+    assert_eq!(
+        owned_table_a.rename_column(&"a".parse().unwrap(), "b".parse().unwrap()),
+        Err(OwnedColumnError::DuplicateColumn {
+            column: "b".parse().unwrap()
+        })
+    );
+}
+
+#[test]
+fn we_can_pretty_print_an_owned_table() {
+    let table = owned_table::<Curve25519Scalar>([
+        bigint("a", [1, -20, 300]),
+        varchar(
+            "name",
+            [
+                "short",
+                "",
+                "a string that is far too long to be shown in full",
+            ],
+        ),
+        decimal75("price", 10, 2, [12345, -5, 0]),
+        boolean("flag", [true, false, true]),
+    ]);
+    let expected = "\
++--------+----------------------------------+------------------------------------+---------+
+| a      | name                             | price                              | flag    |
+| BIGINT | VARCHAR                          | DECIMAL75(PRECISION: 10, SCALE: 2) | BOOLEAN |
++--------+----------------------------------+------------------------------------+---------+
+| 1      | short                            | 123.45                             | true    |
+| -20    |                                  | -0.05                              | false   |
+| 300    | a string that is far too long... | 0.00                               | true    |
++--------+----------------------------------+------------------------------------+---------+";
+    assert_eq!(table.to_pretty_string(10), expected);
+
+    let table = owned_table::<Curve25519Scalar>([
+        decimal75("d", 5, -2, [7, 0]),
+        varbinary("b", [&[0x0a_u8, 0xff][..], &[]]),
+    ]);
+    let expected = "\
++------------------------------------+-----------+
+| d                                  | b         |
+| DECIMAL75(PRECISION: 5, SCALE: -2) | VARBINARY |
++------------------------------------+-----------+
+| 700                                | 0x0aff    |
+| 0                                  | 0x        |
++------------------------------------+-----------+";
+    assert_eq!(table.to_pretty_string(10), expected);
+}
+
+#[test]
+fn we_can_pretty_print_an_owned_table_with_truncated_rows() {
+    let table = owned_table::<Curve25519Scalar>([int("a", 0..1000)]);
+    let expected = "\
++-----+
+| a   |
+| INT |
++-----+
+| 0   |
+| 1   |
++-----+
+(998 more rows)";
+    assert_eq!(table.to_pretty_string(2), expected);
+
+    let expected = "\
++-----+
+| a   |
+| INT |
++-----+
+(1000 more rows)";
+    assert_eq!(table.to_pretty_string(0), expected);
+}
+
+#[test]
+fn we_can_hash_rows_by_key_columns() {
+    let table: OwnedTable<Curve25519Scalar> = owned_table([
+        bigint("a", [1, 2, 1, 1, 3]),
+        varchar("b", ["x", "y", "x", "z", "x"]),
+        decimal75("c", 5, 2, [100, 200, 100, 100, -100]),
+        boolean("d", [true, false, false, true, true]),
+    ]);
+    let keys = ["a", "b", "c"].map(|key| key.parse().unwrap());
+    let hashes = table.row_hashes(&keys).unwrap();
+    assert_eq!(hashes.len(), 5);
+    // Rows 0 and 2 have the same key tuple, even though they differ in `d`.
+    assert_eq!(hashes[0], hashes[2]);
+    for (i, j) in [(0, 1), (0, 3), (0, 4), (1, 3), (1, 4), (3, 4)] {
+        assert_ne!(hashes[i], hashes[j]);
+    }
+    assert_eq!(table.row_hashes(&keys).unwrap(), hashes);
+
+    // The key order matters.
+    let reordered_keys = ["b", "a", "c"].map(|key| key.parse().unwrap());
+    assert_ne!(table.row_hashes(&reordered_keys).unwrap()[0], hashes[0]);
+}
+
+#[test]
+fn we_get_equal_row_hashes_for_equal_keys_across_tables() {
+    let left: OwnedTable<Curve25519Scalar> =
+        owned_table([int("k", [7, 8]), varchar("s", ["p", "q"])]);
+    let right: OwnedTable<Curve25519Scalar> =
+        owned_table([varchar("s", ["q", "p", "r"]), bigint("k", [8, 7, 7])]);
+    let keys = ["k", "s"].map(|key| key.parse().unwrap());
+    let left_hashes = left.row_hashes(&keys).unwrap();
+    let right_hashes = right.row_hashes(&keys).unwrap();
+    assert_eq!(left_hashes[0], right_hashes[1]);
+    assert_eq!(left_hashes[1], right_hashes[0]);
+    assert!(!left_hashes.contains(&right_hashes[2]));
+}
+
+#[test]
+fn we_get_row_hashes_that_depend_on_the_value_rather_than_the_scale_of_decimals() {
+    let keys = ["k".parse().unwrap()];
+    // 1, 0.1, 1.00, 10.0 and 0
+    let decimals: OwnedTable<Curve25519Scalar> =
+        owned_table([decimal75("k", 10, 1, [10, 1, 10, 100, 0])]);
+    let hashes = decimals.row_hashes(&keys).unwrap();
+    let integers: OwnedTable<Curve25519Scalar> = owned_table([bigint("k", [1, 10, 0])]);
+    let integer_hashes = integers.row_hashes(&keys).unwrap();
+    let scaled: OwnedTable<Curve25519Scalar> = owned_table([decimal75("k", 10, 2, [100, 0])]);
+    let scaled_hashes = scaled.row_hashes(&keys).unwrap();
+
+    assert_eq!(hashes[0], integer_hashes[0]);
+    assert_eq!(hashes[0], scaled_hashes[0]);
+    assert_ne!(hashes[0], hashes[1]);
+    assert_eq!(hashes[3], integer_hashes[1]);
+    assert_eq!(hashes[4], integer_hashes[2]);
+    assert_eq!(hashes[4], scaled_hashes[1]);
+}
+
+#[test]
+fn we_cannot_hash_rows_by_a_missing_column() {
+    let table: OwnedTable<Curve25519Scalar> = owned_table([bigint("a", [1, 2])]);
+    assert_eq!(
+        table.row_hashes(&["a".parse().unwrap(), "b".parse().unwrap()]),
+        Err(OwnedColumnError::ColumnNotFound {
+            column: "b".parse().unwrap()
+        })
+    );
+    assert_eq!(table.row_hashes(&[]).unwrap().len(), 2);
+}
+
+#[test]
+fn we_can_create_an_empty_owned_table_with_a_schema() {
+    let schema = [
+        ColumnField::new("a".parse().unwrap(), ColumnType::Boolean),
+        ColumnField::new("b".parse().unwrap(), ColumnType::BigInt),
+        ColumnField::new(
+            "c".parse().unwrap(),
+            ColumnType::Decimal75(Precision::new(10).unwrap(), -2),
+        ),
+        ColumnField::new("d".parse().unwrap(), ColumnType::VarChar),
+        ColumnField::new(
+            "e".parse().unwrap(),
+            ColumnType::TimestampTZ(PoSQLTimeUnit::Millisecond, PoSQLTimeZone::Utc),
+        ),
+        ColumnField::new("f".parse().unwrap(), ColumnType::Scalar),
+    ];
+    let table = OwnedTable::<Curve25519Scalar>::empty_with_schema(&schema).unwrap();
+    assert_eq!(table.num_rows(), 0);
+    assert_eq!(table.num_columns(), schema.len());
+    assert_eq!(table.schema(), schema);
+}
+
+#[test]
+fn we_can_create_an_owned_table_with_an_empty_schema() {
+    let table = OwnedTable::<Curve25519Scalar>::empty_with_schema(&[]).unwrap();
+    assert!(table.is_empty());
+    assert_eq!(table.num_rows(), 0);
+}
+
+#[test]
+fn we_cannot_create_an_empty_owned_table_with_a_repeated_column_name() {
+    let schema = [
+        ColumnField::new("a".parse().unwrap(), ColumnType::BigInt),
+        ColumnField::new("b".parse().unwrap(), ColumnType::VarChar),
+        ColumnField::new("a".parse().unwrap(), ColumnType::Boolean),
+    ];
+    assert_eq!(
+        OwnedTable::<Curve25519Scalar>::empty_with_schema(&schema),
+        Err(OwnedTableError::DuplicateColumnName {
+            column: "a".parse().unwrap()
+        })
+    );
+}
+
+#[test]
+fn we_can_coerce_an_owned_table_to_a_schema() {
+    let table: OwnedTable<Curve25519Scalar> = owned_table([
+        varchar("name", ["x", "y", "z"]),
+        bigint("amount", [1, -20, 300]),
+        smallint("extra", [1_i16, 2, 3]),
+    ]);
+    let schema = [
+        ColumnField::new(
+            "amount".parse().unwrap(),
+            ColumnType::Decimal75(Precision::new(10).unwrap(), 2),
+        ),
+        ColumnField::new("name".parse().unwrap(), ColumnType::VarChar),
+    ];
+    let coerced = table.coerce_to_schema(&schema).unwrap();
+    assert_eq!(coerced.schema(), schema);
+    assert_eq!(
+        coerced,
+        owned_table([
+            decimal75("amount", 10, 2, [100, -2000, 30000]),
+            varchar("name", ["x", "y", "z"]),
+        ])
+    );
+}
+
+#[test]
+fn we_cannot_coerce_an_owned_table_to_a_schema_with_a_missing_column() {
+    let table: OwnedTable<Curve25519Scalar> = owned_table([bigint("a", [1, 2])]);
+    let schema = [
+        ColumnField::new("a".parse().unwrap(), ColumnType::Int128),
+        ColumnField::new("b".parse().unwrap(), ColumnType::BigInt),
+    ];
+    assert_eq!(
+        table.coerce_to_schema(&schema),
+        Err(OwnedColumnError::ColumnNotFound {
+            column: "b".parse().unwrap()
+        })
+    );
+}
+
+#[test]
+fn we_cannot_coerce_an_owned_table_to_a_schema_with_an_impossible_cast() {
+    let table: OwnedTable<Curve25519Scalar> = owned_table([varchar("a", ["1", "2"])]);
+    let schema = [ColumnField::new("a".parse().unwrap(), ColumnType::BigInt)];
+    assert_eq!(
+        table.coerce_to_schema(&schema),
+        Err(OwnedColumnError::TypeCastError {
+            from_type: ColumnType::VarChar,
+            to_type: ColumnType::BigInt,
+        })
+    );
+}
+
+#[test]
+fn we_can_serialize_and_deserialize_an_owned_table() {
+    let table = owned_table::<Curve25519Scalar>([
+        bigint("a", [1_i64, -2]),
+        varchar("b", ["x", "y"]),
+        decimal75("c", 10, 2, [100_i64, 250]),
+        timestamptz("d", PoSQLTimeUnit::Second, PoSQLTimeZone::Utc, [0_i64, 1]),
+    ]);
+    let serialized = serde_json::to_string(&table).unwrap();
+    let deserialized: OwnedTable<Curve25519Scalar> = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(deserialized, table);
+}
+
+#[test]
+fn we_cannot_deserialize_an_owned_table_with_columns_of_different_lengths() {
+    let ragged = IndexMap::<Identifier, OwnedColumn<Curve25519Scalar>>::from_iter([
+        ("a".parse().unwrap(), OwnedColumn::BigInt(vec![1, 2])),
+        ("b".parse().unwrap(), OwnedColumn::BigInt(vec![1])),
+    ]);
+    let serialized = serde_json::to_string(&ragged).unwrap();
+    assert!(serde_json::from_str::<OwnedTable<Curve25519Scalar>>(&serialized).is_err());
+}
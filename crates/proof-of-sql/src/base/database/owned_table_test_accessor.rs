@@ -5,6 +5,7 @@ use super::{
 use crate::base::{
     commitment::{CommitmentEvaluationProof, VecCommitmentExt},
     map::IndexMap,
+    scalar::ScalarExt,
 };
 use alloc::{string::String, vec::Vec};
 use bumpalo::Bump;
@@ -110,6 +111,15 @@ impl<CP: CommitmentEvaluationProof> DataAccessor<CP::Scalar> for OwnedTableTestA
                     .alloc_slice_fill_iter(col.iter().map(|s| (*s).into()));
                 Column::VarChar((col, scals))
             }
+            OwnedColumn::VarBinary(col) => {
+                let col: &mut [&[u8]] = self
+                    .alloc
+                    .alloc_slice_fill_iter(col.iter().map(Vec::as_slice));
+                let scals: &mut [_] = self.alloc.alloc_slice_fill_iter(
+                    col.iter().map(|b| ScalarExt::from_byte_slice_via_hash(b)),
+                );
+                Column::VarBinary((col, scals))
+            }
             OwnedColumn::TimestampTZ(tu, tz, col) => Column::TimestampTZ(*tu, *tz, col),
         }
     }
@@ -195,4 +205,21 @@ impl<'a, CP: CommitmentEvaluationProof> OwnedTableTestAccessor<'a, CP> {
         res.add_table(table_ref, owned_table, offset);
         res
     }
+
+    /// Replace the data and offset of an existing table.
+    ///
+    /// Commitments are computed from the stored data on demand,
+    /// so proofs created against the old data no longer verify against this accessor.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the `table_ref` is not found in `self.tables`, indicating that an invalid reference was provided.
+    pub fn update_table(
+        &mut self,
+        table_ref: TableRef,
+        new_data: OwnedTable<CP::Scalar>,
+        offset: usize,
+    ) {
+        *self.tables.get_mut(&table_ref).unwrap() = (new_data, offset);
+    }
 }
@@ -2,10 +2,17 @@ use super::{
     Column, ColumnRef, ColumnType, CommitmentAccessor, DataAccessor, MetadataAccessor,
     OwnedTableTestAccessor, SchemaAccessor, TestAccessor,
 };
-use crate::base::{
-    commitment::{Commitment, CommittableColumn},
-    database::owned_table_utility::*,
-    scalar::Curve25519Scalar,
+use crate::{
+    base::{
+        commitment::{Commitment, CommittableColumn},
+        database::owned_table_utility::*,
+        scalar::Curve25519Scalar,
+    },
+    sql::{
+        proof::VerifiableQueryResult,
+        proof_exprs::test_utility::*,
+        proof_plans::{test_utility::*, DynProofPlan},
+    },
 };
 use blitzar::proof::InnerProductProof;
 use curve25519_dalek::ristretto::RistrettoPoint;
@@ -267,3 +274,96 @@ fn we_can_correctly_update_offsets() {
     assert_eq!(accessor1.get_offset(table_ref), offset);
     assert_eq!(accessor2.get_offset(table_ref), offset);
 }
+
+#[test]
+fn we_can_update_the_data_of_a_table() {
+    let table_ref = "sxt.test".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        table_ref,
+        owned_table([bigint("a", [1, 2, 3])]),
+        0_usize,
+        (),
+    );
+    let column = ColumnRef::new(table_ref, "a".parse().unwrap(), ColumnType::BigInt);
+    let old_commitment = accessor.get_commitment(column);
+
+    accessor.update_table(
+        table_ref,
+        owned_table([
+            bigint("a", [4, 5, 6, 7]),
+            varchar("b", ["x", "y", "z", "w"]),
+        ]),
+        2_usize,
+    );
+    assert_eq!(accessor.get_length(table_ref), 4);
+    assert_eq!(accessor.get_offset(table_ref), 2);
+    assert_eq!(accessor.get_column_names(table_ref), vec!["a", "b"]);
+    match accessor.get_column(column) {
+        Column::BigInt(col) => assert_eq!(col.to_vec(), vec![4, 5, 6, 7]),
+        _ => panic!("Invalid column type"),
+    };
+    assert_ne!(accessor.get_commitment(column), old_commitment);
+    assert_eq!(
+        accessor.get_commitment(column),
+        RistrettoPoint::compute_commitments(&[CommittableColumn::BigInt(&[4, 5, 6, 7])], 2, &())[0]
+    );
+}
+
+#[test]
+fn we_cannot_verify_a_proof_of_old_data_after_updating_a_table() {
+    let table_ref = "sxt.test".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        table_ref,
+        owned_table([bigint("a", [1, 2, 3])]),
+        0_usize,
+        (),
+    );
+    let ast: DynProofPlan<RistrettoPoint> = filter(
+        cols_expr_plan(table_ref, &["a"], &accessor),
+        tab(table_ref),
+        equal(column(table_ref, "a", &accessor), const_bigint(2)),
+    );
+    let old_res = VerifiableQueryResult::<InnerProductProof>::new(&ast, &accessor, &());
+    assert_eq!(
+        old_res.clone().verify(&ast, &accessor, &()).unwrap().table,
+        owned_table([bigint("a", [2])])
+    );
+
+    accessor.update_table(table_ref, owned_table([bigint("a", [2, 2, 5])]), 0_usize);
+    assert!(old_res.verify(&ast, &accessor, &()).is_err());
+
+    let new_res = VerifiableQueryResult::<InnerProductProof>::new(&ast, &accessor, &());
+    assert_eq!(
+        new_res.verify(&ast, &accessor, &()).unwrap().table,
+        owned_table([bigint("a", [2, 2])])
+    );
+}
+
+#[test]
+fn we_can_access_columns_without_copying_their_data() {
+    let table_ref = "sxt.test".parse().unwrap();
+    let data = owned_table([bigint("a", [1, 2, 3]), varchar("b", ["x", "yz", "w"])]);
+    let accessor =
+        OwnedTableTestAccessor::<InnerProductProof>::new_from_table(table_ref, data, 0, ());
+
+    // Every access returns a view of the same storage.
+    let a = ColumnRef::new(table_ref, "a".parse().unwrap(), ColumnType::BigInt);
+    let (Column::BigInt(first), Column::BigInt(second)) =
+        (accessor.get_column(a), accessor.get_column(a))
+    else {
+        panic!("Invalid column type");
+    };
+    assert_eq!(first.as_ptr(), second.as_ptr());
+
+    // The strings of a VarChar column are borrowed, not cloned.
+    let b = ColumnRef::new(table_ref, "b".parse().unwrap(), ColumnType::VarChar);
+    let (Column::VarChar((first, _)), Column::VarChar((second, _))) =
+        (accessor.get_column(b), accessor.get_column(b))
+    else {
+        panic!("Invalid column type");
+    };
+    assert_eq!(first, second);
+    for (first, second) in first.iter().zip(second) {
+        assert_eq!(first.as_ptr(), second.as_ptr());
+    }
+}
@@ -15,7 +15,7 @@
 //! ```
 use super::{OwnedColumn, OwnedTable};
 use crate::base::scalar::Scalar;
-use alloc::string::String;
+use alloc::{string::String, vec::Vec};
 use core::ops::Deref;
 use proof_of_sql_parser::{
     posql_time::{PoSQLTimeUnit, PoSQLTimeZone},
@@ -219,6 +219,28 @@ pub fn varchar<S: Scalar>(
     )
 }
 
+/// Creates a `(Identifier, OwnedColumn)` pair for a varbinary column.
+/// This is primarily intended for use in conjunction with [`owned_table`].
+/// # Example
+/// ```
+/// use proof_of_sql::base::{database::owned_table_utility::*, scalar::Curve25519Scalar};
+/// let result = owned_table::<Curve25519Scalar>([
+///     varbinary("a", [&[0x00, 0xff][..], b"", b"abc"]),
+/// ]);
+/// ```
+///
+/// # Panics
+/// - Panics if `name.parse()` fails to convert the name into an `Identifier`.
+pub fn varbinary<S: Scalar>(
+    name: impl Deref<Target = str>,
+    data: impl IntoIterator<Item = impl Into<Vec<u8>>>,
+) -> (Identifier, OwnedColumn<S>) {
+    (
+        name.parse().unwrap(),
+        OwnedColumn::VarBinary(data.into_iter().map(Into::into).collect()),
+    )
+}
+
 /// Creates a `(Identifier, OwnedColumn)` pair for a decimal75 column.
 /// This is primarily intended for use in conjunction with [`owned_table`].
 /// # Example
@@ -92,6 +92,9 @@ pub struct Precision(u8);
 pub(crate) const MAX_SUPPORTED_PRECISION: u8 = 75;
 
 impl Precision {
+    /// The largest supported precision
+    pub(crate) const MAX: Self = Self(MAX_SUPPORTED_PRECISION);
+
     /// Constructor for creating a Precision instance
     pub fn new(value: u8) -> Result<Self, DecimalError> {
         if value > MAX_SUPPORTED_PRECISION || value == 0 {
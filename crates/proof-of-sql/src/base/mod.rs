@@ -9,6 +9,8 @@ pub mod database;
 /// TODO: add docs
 pub(crate) mod encode;
 pub mod math;
+#[cfg(feature = "polars")]
+pub mod polars;
 /// TODO: add docs
 pub(crate) mod polynomial;
 pub(crate) mod proof;
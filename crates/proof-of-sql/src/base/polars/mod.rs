@@ -0,0 +1,12 @@
+//! This module provides conversions between owned types and Polars data structures.
+
+/// Module for converting between owned and Polars data structures.
+pub mod owned_and_polars_conversions;
+
+#[cfg(test)]
+/// Tests for owned and Polars conversions.
+mod owned_and_polars_conversions_test;
+
+#[cfg(all(test, feature = "blitzar"))]
+/// Tests that compare proven query results against results computed by Polars.
+mod polars_query_test;
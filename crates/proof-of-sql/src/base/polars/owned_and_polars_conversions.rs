@@ -0,0 +1,282 @@
+//! This module provide `TryFrom` implementations to go between Polars and owned types
+//! The mapping is as follows:
+//! `OwnedColumn` <-> `Series`
+//! `OwnedTable` <-> `DataFrame`
+//! `Boolean` <-> `Boolean`
+//! `TinyInt` <-> `Int8`
+//! `SmallInt` <-> `Int16`
+//! `Int` <-> `Int32`
+//! `BigInt` <-> `Int64`
+//! `VarChar` <-> `String`
+//! `VarBinary` <-> `Binary`
+//! `Int128` <-> `Decimal(38,0)`
+//! `Decimal75(p,s)` <-> `Decimal(p,s)`
+//! `TimestampTZ` <-> `Datetime`
+//!
+//! Note: Polars decimals are backed by `i128`, so only `Decimal75` columns with a precision of at most 38
+//! and a non-negative scale can be converted. As with Arrow, any Polars decimal with scale 0 is read back as `Int128`.
+//! Polars has no second time unit, so timestamps in seconds can not be converted.
+//! `Scalar` columns can not be converted either.
+use crate::base::{
+    database::{ColumnType, OwnedColumn, OwnedTable, OwnedTableError},
+    map::IndexMap,
+    math::decimal::Precision,
+    scalar::Scalar,
+};
+use alloc::{string::ToString, sync::Arc, vec::Vec};
+use polars::prelude::{
+    DataFrame, DataType, Int128Chunked, Int64Chunked, IntoSeries, NamedFrom, PolarsError, Series,
+    TimeUnit as PolarsTimeUnit,
+};
+use proof_of_sql_parser::{
+    posql_time::{PoSQLTimeUnit, PoSQLTimeZone, PoSQLTimestampError},
+    Identifier, ParseError,
+};
+use snafu::Snafu;
+
+/// The precision of the Polars decimal that `Int128` columns are exported as.
+const INT128_PRECISION: usize = 38;
+
+#[derive(Snafu, Debug)]
+#[non_exhaustive]
+/// Errors caused by conversions between Polars and owned types.
+pub enum OwnedPolarsConversionError {
+    /// This error occurs when trying to convert from an unsupported Polars type.
+    #[snafu(display(
+        "unsupported type: attempted conversion from Series of type {datatype} to OwnedColumn"
+    ))]
+    UnsupportedType {
+        /// The unsupported datatype
+        datatype: DataType,
+    },
+    /// This error occurs when trying to convert a column whose type has no Polars equivalent.
+    #[snafu(display(
+        "unsupported type: attempted conversion from OwnedColumn of type {column_type} to Series"
+    ))]
+    UnsupportedColumnType {
+        /// The unsupported column type
+        column_type: ColumnType,
+    },
+    /// This error occurs when trying to convert from a data frame with duplicate identifiers (e.g. `"a"` and `"A"`).
+    #[snafu(display("conversion resulted in duplicate identifiers"))]
+    DuplicateIdentifiers,
+    /// This error occurs when converting a series name to an identifier fails.
+    #[snafu(transparent)]
+    FieldParseFail {
+        /// The underlying source error
+        source: ParseError,
+    },
+    /// This error occurs when creating an owned table fails, which should only occur when there are zero columns.
+    #[snafu(transparent)]
+    InvalidTable {
+        /// The underlying source error
+        source: OwnedTableError,
+    },
+    /// This error occurs when trying to convert from a Polars series with nulls.
+    #[snafu(display("null values are not supported in OwnedColumn yet"))]
+    NullNotSupportedYet,
+    /// Using `TimeError` to handle all time-related errors
+    #[snafu(transparent)]
+    TimestampConversionError {
+        /// The underlying source error
+        source: PoSQLTimestampError,
+    },
+    /// This error occurs when Polars fails to build or read a series or data frame.
+    #[snafu(transparent)]
+    PolarsError {
+        /// The underlying source error
+        source: PolarsError,
+    },
+}
+
+impl<S: Scalar> OwnedColumn<S> {
+    /// Converts the column into a Polars [`Series`] with the given name.
+    ///
+    /// # Errors
+    /// Returns [`OwnedPolarsConversionError::UnsupportedColumnType`] if the column type has no Polars equivalent.
+    pub fn try_into_series(self, name: &str) -> Result<Series, OwnedPolarsConversionError> {
+        let column_type = self.column_type();
+        let unsupported = || OwnedPolarsConversionError::UnsupportedColumnType { column_type };
+        Ok(match self {
+            OwnedColumn::Boolean(col) => Series::new(name, col),
+            OwnedColumn::TinyInt(col) => Series::new(name, col),
+            OwnedColumn::SmallInt(col) => Series::new(name, col),
+            OwnedColumn::Int(col) => Series::new(name, col),
+            OwnedColumn::BigInt(col) => Series::new(name, col),
+            OwnedColumn::VarChar(col) => Series::new(name, col),
+            OwnedColumn::VarBinary(col) => {
+                Series::new(name, col.iter().map(Vec::as_slice).collect::<Vec<_>>())
+            }
+            OwnedColumn::Int128(col) => Int128Chunked::from_vec(name, col)
+                .into_decimal(Some(INT128_PRECISION), 0)?
+                .into_series(),
+            OwnedColumn::Decimal75(precision, scale, col) => {
+                let precision = usize::from(precision.value());
+                let scale = usize::try_from(scale).map_err(|_| unsupported())?;
+                if precision > INT128_PRECISION {
+                    return Err(unsupported());
+                }
+                let values = col
+                    .into_iter()
+                    .map(TryInto::<i128>::try_into)
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|_| unsupported())?;
+                Int128Chunked::from_vec(name, values)
+                    .into_decimal(Some(precision), scale)?
+                    .into_series()
+            }
+            OwnedColumn::TimestampTZ(time_unit, timezone, col) => {
+                let polars_time_unit = match time_unit {
+                    PoSQLTimeUnit::Second => return Err(unsupported()),
+                    PoSQLTimeUnit::Millisecond => PolarsTimeUnit::Milliseconds,
+                    PoSQLTimeUnit::Microsecond => PolarsTimeUnit::Microseconds,
+                    PoSQLTimeUnit::Nanosecond => PolarsTimeUnit::Nanoseconds,
+                };
+                Int64Chunked::from_vec(name, col)
+                    .into_datetime(polars_time_unit, Some(timezone.to_string()))
+                    .into_series()
+            }
+            OwnedColumn::Scalar(_) => return Err(unsupported()),
+        })
+    }
+}
+
+impl<S: Scalar> TryFrom<&Series> for OwnedColumn<S> {
+    type Error = OwnedPolarsConversionError;
+
+    fn try_from(value: &Series) -> Result<Self, Self::Error> {
+        let null_error = || OwnedPolarsConversionError::NullNotSupportedYet;
+        match value.dtype() {
+            DataType::Boolean => Ok(Self::Boolean(
+                value
+                    .bool()?
+                    .iter()
+                    .collect::<Option<_>>()
+                    .ok_or_else(null_error)?,
+            )),
+            DataType::Int8 => Ok(Self::TinyInt(
+                value
+                    .i8()?
+                    .iter()
+                    .collect::<Option<_>>()
+                    .ok_or_else(null_error)?,
+            )),
+            DataType::Int16 => Ok(Self::SmallInt(
+                value
+                    .i16()?
+                    .iter()
+                    .collect::<Option<_>>()
+                    .ok_or_else(null_error)?,
+            )),
+            DataType::Int32 => Ok(Self::Int(
+                value
+                    .i32()?
+                    .iter()
+                    .collect::<Option<_>>()
+                    .ok_or_else(null_error)?,
+            )),
+            DataType::Int64 => Ok(Self::BigInt(
+                value
+                    .i64()?
+                    .iter()
+                    .collect::<Option<_>>()
+                    .ok_or_else(null_error)?,
+            )),
+            DataType::String => Ok(Self::VarChar(
+                value
+                    .str()?
+                    .iter()
+                    .map(|s| s.map(ToString::to_string))
+                    .collect::<Option<_>>()
+                    .ok_or_else(null_error)?,
+            )),
+            DataType::Binary => Ok(Self::VarBinary(
+                value
+                    .binary()?
+                    .iter()
+                    .map(|b| b.map(<[u8]>::to_vec))
+                    .collect::<Option<_>>()
+                    .ok_or_else(null_error)?,
+            )),
+            DataType::Decimal(precision, scale) => {
+                let values = value
+                    .decimal()?
+                    .physical()
+                    .iter()
+                    .collect::<Option<Vec<_>>>()
+                    .ok_or_else(null_error)?;
+                match scale.unwrap_or(0) {
+                    0 => Ok(Self::Int128(values)),
+                    scale => Ok(Self::Decimal75(
+                        Precision::new(
+                            precision
+                                .unwrap_or(INT128_PRECISION)
+                                .try_into()
+                                .expect("Polars decimal precision is at most 38"),
+                        )
+                        .expect("Polars decimal precision is at most 38"),
+                        scale
+                            .try_into()
+                            .expect("Polars decimal scale is at most 38"),
+                        values.into_iter().map(S::from).collect(),
+                    )),
+                }
+            }
+            DataType::Datetime(time_unit, timezone) => {
+                let posql_time_unit = match time_unit {
+                    PolarsTimeUnit::Milliseconds => PoSQLTimeUnit::Millisecond,
+                    PolarsTimeUnit::Microseconds => PoSQLTimeUnit::Microsecond,
+                    PolarsTimeUnit::Nanoseconds => PoSQLTimeUnit::Nanosecond,
+                };
+                let timezone = timezone.as_deref().map(Arc::from);
+                Ok(Self::TimestampTZ(
+                    posql_time_unit,
+                    PoSQLTimeZone::try_from(&timezone)?,
+                    value
+                        .datetime()?
+                        .physical()
+                        .iter()
+                        .collect::<Option<_>>()
+                        .ok_or_else(null_error)?,
+                ))
+            }
+            data_type => Err(OwnedPolarsConversionError::UnsupportedType {
+                datatype: data_type.clone(),
+            }),
+        }
+    }
+}
+
+impl<S: Scalar> TryFrom<OwnedTable<S>> for DataFrame {
+    type Error = OwnedPolarsConversionError;
+    fn try_from(value: OwnedTable<S>) -> Result<Self, Self::Error> {
+        let series = value
+            .into_inner()
+            .into_iter()
+            .map(|(identifier, owned_column)| owned_column.try_into_series(identifier.name()))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(DataFrame::new(series)?)
+    }
+}
+
+impl<S: Scalar> TryFrom<DataFrame> for OwnedTable<S> {
+    type Error = OwnedPolarsConversionError;
+    fn try_from(value: DataFrame) -> Result<Self, Self::Error> {
+        let num_columns = value.width();
+        let table = value
+            .get_columns()
+            .iter()
+            .map(|series| {
+                let owned_column = OwnedColumn::try_from(series)?;
+                let identifier = Identifier::try_from_name(series.name())?;
+                Ok((identifier, owned_column))
+            })
+            .collect::<Result<IndexMap<_, _>, Self::Error>>()?;
+        let owned_table = Self::try_new(table)?;
+        if num_columns == owned_table.num_columns() {
+            Ok(owned_table)
+        } else {
+            Err(OwnedPolarsConversionError::DuplicateIdentifiers)
+        }
+    }
+}
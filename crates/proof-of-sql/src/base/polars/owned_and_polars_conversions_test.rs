@@ -0,0 +1,105 @@
+use super::owned_and_polars_conversions::OwnedPolarsConversionError;
+use crate::base::{
+    database::{owned_table_utility::*, ColumnType, OwnedColumn, OwnedTable},
+    math::decimal::Precision,
+    scalar::Curve25519Scalar,
+};
+use polars::prelude::{DataFrame, DataType, NamedFrom, Series, TimeUnit};
+use proof_of_sql_parser::posql_time::{PoSQLTimeUnit, PoSQLTimeZone};
+
+#[test]
+fn we_can_round_trip_a_mixed_type_table_through_a_data_frame() {
+    let table: OwnedTable<Curve25519Scalar> = owned_table([
+        boolean("boolean", [true, false, true]),
+        tinyint("tinyint", [i8::MIN, 0, i8::MAX]),
+        smallint("smallint", [i16::MIN, 0, i16::MAX]),
+        int("int", [i32::MIN, 0, i32::MAX]),
+        bigint("bigint", [i64::MIN, 0, i64::MAX]),
+        int128("int128", [-1, 0, 10_i128.pow(37)]),
+        decimal75("decimal", 12, 2, [-123_456, 0, 999]),
+        varchar("varchar", ["a", "", "héllo"]),
+        varbinary("varbinary", [&[1_u8, 2][..], &[], &[0xff]]),
+        timestamptz(
+            "ts",
+            PoSQLTimeUnit::Millisecond,
+            PoSQLTimeZone::Utc,
+            [-1, 0, 1_700_000_000_000],
+        ),
+        timestamptz(
+            "ts_offset",
+            PoSQLTimeUnit::Nanosecond,
+            PoSQLTimeZone::FixedOffset(3600),
+            [1, 2, 3],
+        ),
+    ]);
+    let data_frame = DataFrame::try_from(table.clone()).unwrap();
+    assert_eq!(data_frame.shape(), (3, 11));
+    assert_eq!(
+        data_frame.column("decimal").unwrap().dtype(),
+        &DataType::Decimal(Some(12), Some(2))
+    );
+    assert_eq!(
+        data_frame.column("int128").unwrap().dtype(),
+        &DataType::Decimal(Some(38), Some(0))
+    );
+    assert_eq!(
+        data_frame.column("ts").unwrap().dtype(),
+        &DataType::Datetime(TimeUnit::Milliseconds, Some("+00:00".to_string()))
+    );
+    assert_eq!(OwnedTable::try_from(data_frame).unwrap(), table);
+}
+
+#[test]
+fn we_can_convert_a_data_frame_with_a_mixed_case_column_name_to_an_owned_table() {
+    let data_frame = DataFrame::new(vec![
+        Series::new("a", [1_i64, 2]),
+        Series::new("B", ["x", "y"]),
+    ])
+    .unwrap();
+    assert_eq!(
+        OwnedTable::<Curve25519Scalar>::try_from(data_frame).unwrap(),
+        owned_table([bigint("a", [1, 2]), varchar("b", ["x", "y"])])
+    );
+}
+
+#[test]
+fn we_cannot_convert_unsupported_columns_to_series() {
+    let unsupported = [
+        OwnedColumn::<Curve25519Scalar>::Scalar(vec![Curve25519Scalar::from(1)]),
+        OwnedColumn::Decimal75(Precision::new(39).unwrap(), 0, vec![]),
+        OwnedColumn::Decimal75(Precision::new(10).unwrap(), -1, vec![]),
+        OwnedColumn::TimestampTZ(PoSQLTimeUnit::Second, PoSQLTimeZone::Utc, vec![]),
+    ];
+    for column in unsupported {
+        let column_type: ColumnType = column.column_type();
+        assert!(matches!(
+            column.try_into_series("a"),
+            Err(OwnedPolarsConversionError::UnsupportedColumnType { column_type: t }) if t == column_type
+        ));
+    }
+}
+
+#[test]
+fn we_cannot_convert_unsupported_or_null_series_to_owned_columns() {
+    assert!(matches!(
+        OwnedColumn::<Curve25519Scalar>::try_from(&Series::new("a", [1.0_f64])),
+        Err(OwnedPolarsConversionError::UnsupportedType { .. })
+    ));
+    assert!(matches!(
+        OwnedColumn::<Curve25519Scalar>::try_from(&Series::new("a", [Some(1_i64), None])),
+        Err(OwnedPolarsConversionError::NullNotSupportedYet)
+    ));
+}
+
+#[test]
+fn we_cannot_convert_a_data_frame_with_duplicate_identifiers() {
+    let data_frame = DataFrame::new(vec![
+        Series::new("a", [1_i64, 2]),
+        Series::new("A", [1_i64, 2]),
+    ])
+    .unwrap();
+    assert!(matches!(
+        OwnedTable::<Curve25519Scalar>::try_from(data_frame),
+        Err(OwnedPolarsConversionError::DuplicateIdentifiers)
+    ));
+}
@@ -0,0 +1,95 @@
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{owned_table_utility::*, OwnedTable, OwnedTableTestAccessor},
+    },
+    sql::{
+        proof::{exercise_verification, VerifiableQueryResult},
+        proof_exprs::test_utility::*,
+        proof_plans::test_utility::*,
+    },
+};
+use polars::prelude::{ChunkCompare, DataFrame};
+use rand::{
+    distributions::{Distribution, Uniform},
+    rngs::StdRng,
+};
+use rand_core::SeedableRng;
+
+fn test_random_tables_against_polars_with_given_offset(offset: usize) {
+    let dist = Uniform::new(-3, 4);
+    let mut rng = StdRng::from_seed([0u8; 32]);
+    for _ in 0..20 {
+        // Generate random table
+        let n = Uniform::new(1, 21).sample(&mut rng);
+        let data = owned_table([
+            bigint("a", dist.sample_iter(&mut rng).take(n)),
+            varchar(
+                "b",
+                dist.sample_iter(&mut rng).take(n).map(|v| format!("s{v}")),
+            ),
+            bigint("c", dist.sample_iter(&mut rng).take(n)),
+            decimal75("d", 10, 2, dist.sample_iter(&mut rng).take(n)),
+        ]);
+
+        // Generate random values to filter by
+        let filter_val1 = format!("s{}", dist.sample(&mut rng));
+        let filter_val2 = dist.sample(&mut rng);
+
+        // Create and verify proof
+        let t = "sxt.t".parse().unwrap();
+        let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+            t,
+            data.clone(),
+            offset,
+            (),
+        );
+        let ast = filter(
+            cols_expr_plan(t, &["a", "d"], &accessor),
+            tab(t),
+            or(
+                equal(
+                    column(t, "b", &accessor),
+                    const_varchar(filter_val1.as_str()),
+                ),
+                equal(column(t, "c", &accessor), const_bigint(filter_val2)),
+            ),
+        );
+        let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+        exercise_verification(&verifiable_res, &ast, &accessor, t);
+        let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+
+        // Calculate/compare expected result with Polars
+        let data_frame = DataFrame::try_from(data).unwrap();
+        let mask = data_frame
+            .column("b")
+            .unwrap()
+            .str()
+            .unwrap()
+            .equal(filter_val1.as_str())
+            | data_frame
+                .column("c")
+                .unwrap()
+                .i64()
+                .unwrap()
+                .equal(filter_val2);
+        let expected_data_frame = data_frame
+            .filter(&mask)
+            .unwrap()
+            .select(["a", "d"])
+            .unwrap();
+        let expected_result = OwnedTable::try_from(expected_data_frame).unwrap();
+
+        assert_eq!(expected_result, res);
+    }
+}
+
+#[test]
+fn we_can_query_random_tables_and_match_polars_with_a_zero_offset() {
+    test_random_tables_against_polars_with_given_offset(0);
+}
+
+#[test]
+fn we_can_query_random_tables_and_match_polars_with_a_non_zero_offset() {
+    test_random_tables_against_polars_with_given_offset(1001);
+}
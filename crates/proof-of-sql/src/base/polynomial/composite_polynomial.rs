@@ -1,4 +1,7 @@
-use crate::base::{map::IndexMap, scalar::Scalar};
+use crate::base::{
+    map::IndexMap,
+    scalar::{zeroize_scalars, Scalar},
+};
 use alloc::{rc::Rc, vec::Vec};
 /*
  * Adapted from arkworks
@@ -167,3 +170,14 @@ impl<S: Scalar> CompositePolynomial<S> {
         }
     }
 }
+
+impl<S: Scalar> Drop for CompositePolynomial<S> {
+    /// Clears the multilinear extensions that are not shared with anything else, since they may contain secret witness data.
+    fn drop(&mut self) {
+        for mle in &mut self.flattened_ml_extensions {
+            if let Some(mle) = Rc::get_mut(mle) {
+                zeroize_scalars(mle);
+            }
+        }
+    }
+}
@@ -100,9 +100,10 @@ impl<S: Scalar> MultilinearExtension<S> for &Column<'_, S> {
     fn inner_product(&self, evaluation_vec: &[S]) -> S {
         match self {
             Column::Boolean(c) => c.inner_product(evaluation_vec),
-            Column::Scalar(c) | Column::VarChar((_, c)) | Column::Decimal75(_, _, c) => {
-                c.inner_product(evaluation_vec)
-            }
+            Column::Scalar(c)
+            | Column::VarChar((_, c))
+            | Column::VarBinary((_, c))
+            | Column::Decimal75(_, _, c) => c.inner_product(evaluation_vec),
             Column::TinyInt(c) => c.inner_product(evaluation_vec),
             Column::SmallInt(c) => c.inner_product(evaluation_vec),
             Column::Int(c) => c.inner_product(evaluation_vec),
@@ -114,7 +115,10 @@ impl<S: Scalar> MultilinearExtension<S> for &Column<'_, S> {
     fn mul_add(&self, res: &mut [S], multiplier: &S) {
         match self {
             Column::Boolean(c) => c.mul_add(res, multiplier),
-            Column::Scalar(c) | Column::VarChar((_, c)) | Column::Decimal75(_, _, c) => {
+            Column::Scalar(c)
+            | Column::VarChar((_, c))
+            | Column::VarBinary((_, c))
+            | Column::Decimal75(_, _, c) => {
                 c.mul_add(res, multiplier);
             }
             Column::TinyInt(c) => c.mul_add(res, multiplier),
@@ -128,9 +132,10 @@ impl<S: Scalar> MultilinearExtension<S> for &Column<'_, S> {
     fn to_sumcheck_term(&self, num_vars: usize) -> Rc<Vec<S>> {
         match self {
             Column::Boolean(c) => c.to_sumcheck_term(num_vars),
-            Column::Scalar(c) | Column::VarChar((_, c)) | Column::Decimal75(_, _, c) => {
-                c.to_sumcheck_term(num_vars)
-            }
+            Column::Scalar(c)
+            | Column::VarChar((_, c))
+            | Column::VarBinary((_, c))
+            | Column::Decimal75(_, _, c) => c.to_sumcheck_term(num_vars),
             Column::TinyInt(c) => c.to_sumcheck_term(num_vars),
             Column::SmallInt(c) => c.to_sumcheck_term(num_vars),
             Column::Int(c) => c.to_sumcheck_term(num_vars),
@@ -142,9 +147,10 @@ impl<S: Scalar> MultilinearExtension<S> for &Column<'_, S> {
     fn id(&self) -> *const c_void {
         match self {
             Column::Boolean(c) => MultilinearExtension::<S>::id(c),
-            Column::Scalar(c) | Column::VarChar((_, c)) | Column::Decimal75(_, _, c) => {
-                MultilinearExtension::<S>::id(c)
-            }
+            Column::Scalar(c)
+            | Column::VarChar((_, c))
+            | Column::VarBinary((_, c))
+            | Column::Decimal75(_, _, c) => MultilinearExtension::<S>::id(c),
             Column::TinyInt(c) => MultilinearExtension::<S>::id(c),
             Column::SmallInt(c) => MultilinearExtension::<S>::id(c),
             Column::Int(c) => MultilinearExtension::<S>::id(c),
@@ -1,9 +1,45 @@
+use crate::base::database::{ColumnRef, ColumnType};
+use alloc::boxed::Box;
 use snafu::Snafu;
 
 #[derive(Snafu, Debug)]
-/// These errors occur when a proof failed to verify.
+/// These errors occur when a proof could not be created or failed to verify.
 pub enum ProofError {
     #[snafu(display("Verification error: {error}"))]
     /// This error occurs when a proof failed to verify.
     VerificationError { error: &'static str },
+    #[snafu(display(
+        "Column type mismatch: {}.{} has type {actual}, but the plan expects {expected}",
+        column.table_ref(),
+        column.column_id()
+    ))]
+    /// This error occurs when the accessor reports a different type for a column than the plan expects.
+    ColumnTypeMismatch {
+        /// The column whose type differs
+        column: Box<ColumnRef>,
+        /// The type the plan expects
+        expected: ColumnType,
+        /// The type reported by the accessor
+        actual: ColumnType,
+    },
+    #[snafu(display(
+        "Unknown column: {}.{} is referenced by the plan, but not known to the accessor",
+        column.table_ref(),
+        column.column_id()
+    ))]
+    /// This error occurs when the accessor does not know a column that the plan references.
+    UnknownColumn {
+        /// The column that is not known
+        column: Box<ColumnRef>,
+    },
+    #[snafu(display(
+        "Result has {num_rows} rows, which exceeds the maximum of {max_output_rows} rows"
+    ))]
+    /// This error occurs when the prover refuses to prove a result with too many rows.
+    ResultTooLarge {
+        /// The number of rows in the result
+        num_rows: usize,
+        /// The maximum number of rows the prover was allowed to prove
+        max_output_rows: usize,
+    },
 }
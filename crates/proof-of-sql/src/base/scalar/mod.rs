@@ -15,4 +15,5 @@ pub mod test_scalar;
 mod test_scalar_test;
 
 mod scalar_ext;
+pub(crate) use scalar_ext::zeroize_scalars;
 pub use scalar_ext::ScalarExt;
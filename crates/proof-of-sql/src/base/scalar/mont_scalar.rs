@@ -18,6 +18,8 @@ use core::{
 use num_bigint::BigInt;
 use num_traits::{Signed, Zero};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use subtle::{Choice, ConstantTimeEq};
+use zeroize::Zeroize;
 
 #[derive(CanonicalSerialize, CanonicalDeserialize, TransparentWrapper)]
 /// A wrapper struct around a `Fp256<MontBackend<T, 4>>` that can easily implement the `Scalar` trait.
@@ -120,6 +122,19 @@ impl<T: MontConfig<4>> Ord for MontScalar<T> {
 // end replacement for #[derive(...)]
 // --------------------------------------------------------------------------------
 
+impl<T: MontConfig<4>> ConstantTimeEq for MontScalar<T> {
+    /// Compares the limbs of the Montgomery representations in constant time.
+    /// The representation is canonical, so this agrees with `==`.
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0 .0 .0[..].ct_eq(&other.0 .0 .0[..])
+    }
+}
+impl<T: MontConfig<4>> Zeroize for MontScalar<T> {
+    fn zeroize(&mut self) {
+        self.0 .0 .0.zeroize();
+    }
+}
+
 /// TODO: add docs
 macro_rules! impl_from_for_mont_scalar_for_type_supported_by_from {
     ($tt:ty) => {
@@ -496,3 +496,36 @@ fn test_bigint_to_scalar_overflow() {
         Err(ScalarConversionError::Overflow { .. })
     ));
 }
+
+#[test]
+fn constant_time_equality_agrees_with_equality() {
+    use subtle::ConstantTimeEq;
+
+    let mut rng = StdRng::seed_from_u64(0);
+    let scalars = [
+        Curve25519Scalar::ZERO,
+        Curve25519Scalar::ONE,
+        -Curve25519Scalar::ONE,
+        Curve25519Scalar::MAX_SIGNED,
+        Curve25519Scalar::from(rng.gen::<i128>()),
+        Curve25519Scalar::from(rng.gen::<i128>()),
+    ];
+    for a in scalars {
+        for b in scalars {
+            assert_eq!(bool::from(a.ct_eq(&b)), a == b);
+        }
+    }
+}
+
+#[test]
+fn we_can_zeroize_a_scalar() {
+    use zeroize::Zeroize;
+
+    let mut scalar = Curve25519Scalar::from(-123);
+    scalar.zeroize();
+    assert_eq!(scalar, Curve25519Scalar::ZERO);
+
+    let mut scalars = [TestScalar::from(1), TestScalar::MAX_SIGNED];
+    scalars.zeroize();
+    assert_eq!(scalars, [TestScalar::ZERO; 2]);
+}
@@ -1,13 +1,127 @@
-use super::Scalar;
+use super::{Scalar, ScalarConversionError};
+use crate::base::math::{
+    decimal::{DecimalError, DecimalResult, IntermediateDecimalError, MAX_SUPPORTED_PRECISION},
+    BigDecimalExt,
+};
+use alloc::{format, string::ToString};
+use bigdecimal::BigDecimal;
 use core::cmp::Ordering;
 
+/// Key derivation context used when hashing binary values into scalars.
+const VARBINARY_HASH_CONTEXT: &str = "proof-of-sql 2024 varbinary hash";
+
+/// Overwrites `scalars` with zeros, to clear witness data that may be secret.
+///
+/// Unlike `zeroize::Zeroize`, this works for every [`Scalar`]. The writes are kept alive with
+/// [`core::hint::black_box`], which is a best effort rather than a guarantee.
+pub(crate) fn zeroize_scalars<S: Scalar>(scalars: &mut [S]) {
+    scalars.fill(S::ZERO);
+    core::hint::black_box(scalars);
+}
+
 /// Extention trait for blanket implementations for `Scalar` types.
 /// This trait is primarily to avoid cluttering the core `Scalar` implementation with default implemenentations
 /// and provides helper methods for `Scalar`.
 pub trait ScalarExt: Scalar {
-    /// Compute 10^exponent for the Scalar. Note that we do not check for overflow.
-    fn pow10(exponent: u8) -> Self {
-        itertools::repeat_n(Self::TEN, exponent as usize).product()
+    /// Compute 10^exponent for the Scalar.
+    ///
+    /// This is the factor used to adjust the scale of decimals. The result is exact for every exponent up to
+    /// the maximum `Decimal75` precision of 75, since 10^75 is below the order of every supported field.
+    /// Larger exponents wrap around the field order, see [`ScalarExt::pow`].
+    fn pow10(exponent: u32) -> Self {
+        Self::TEN.pow(exponent.into())
+    }
+    /// Compute self^exponent by repeated squaring.
+    ///
+    /// The computation happens in the scalar field, so overflow is not detected: once the integer power
+    /// exceeds the field order the result is reduced modulo that order. Callers that need the integer value
+    /// must bound the exponent themselves.
+    #[must_use]
+    fn pow(self, exponent: u64) -> Self {
+        let mut result = Self::ONE;
+        let mut base = self;
+        let mut exponent = exponent;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result *= base;
+            }
+            base = base * base;
+            exponent >>= 1;
+        }
+        result
+    }
+    /// Hash a binary value into a `Scalar`.
+    ///
+    /// Strings are hashed with plain blake3 (see `From<&str>`), while binary values are hashed in blake3's
+    /// key derivation mode. The two modes are domain separated, so no string collides with a binary value,
+    /// whatever their bytes.
+    #[must_use]
+    fn from_byte_slice_via_hash(bytes: &[u8]) -> Self {
+        let mut hasher = blake3::Hasher::new_derive_key(VARBINARY_HASH_CONTEXT);
+        hasher.update(bytes);
+        let mut hash: [u8; 32] = hasher.finalize().into();
+        // Clear the top bits so that the value is always smaller than the field order.
+        hash[31] &= 0b0000_1111_u8;
+        let limbs = core::array::from_fn(|i| {
+            u64::from_le_bytes(hash[8 * i..8 * (i + 1)].try_into().unwrap())
+        });
+        Self::from(limbs)
+    }
+    /// Serialize the `Scalar` as its canonical 32-byte little-endian representation.
+    ///
+    /// The bytes are the value itself (not its Montgomery form), which is always less than the field modulus.
+    #[must_use]
+    fn to_canonical_bytes(&self) -> [u8; 32] {
+        let limbs: [u64; 4] = (*self).into();
+        let mut bytes = [0u8; 32];
+        for (chunk, limb) in bytes.chunks_exact_mut(8).zip(limbs) {
+            chunk.copy_from_slice(&limb.to_le_bytes());
+        }
+        bytes
+    }
+    /// Deserialize a `Scalar` from its canonical 32-byte little-endian representation.
+    ///
+    /// Returns `None` if the bytes represent a value greater than or equal to the field modulus,
+    /// so every `Scalar` has exactly one accepted encoding.
+    #[must_use]
+    fn from_canonical_bytes(bytes: [u8; 32]) -> Option<Self> {
+        let limbs: [u64; 4] = core::array::from_fn(|i| {
+            u64::from_le_bytes(bytes[8 * i..8 * (i + 1)].try_into().unwrap())
+        });
+        // Conversion from limbs reduces modulo the field order, so the value is canonical exactly when it round trips.
+        let scalar = Self::from(limbs);
+        (Into::<[u64; 4]>::into(scalar) == limbs).then_some(scalar)
+    }
+    /// Parse a decimal string into the `Scalar` that represents it in a `Decimal75` with the given `scale`,
+    /// e.g. `"3.14"` at scale 2 is `314`.
+    ///
+    /// # Errors
+    /// Returns [`DecimalError::InvalidDecimal`] if `s` is not a decimal,
+    /// [`DecimalError::RoundingError`] if `s` has more fractional digits than `scale`,
+    /// and [`DecimalError::InvalidPrecision`] if the scaled value has more than 75 digits.
+    fn try_from_decimal_str(s: &str, scale: i8) -> DecimalResult<Self> {
+        let decimal: BigDecimal = s.parse().map_err(|e: bigdecimal::ParseBigDecimalError| {
+            DecimalError::InvalidDecimal {
+                error: e.to_string(),
+            }
+        })?;
+        let value = decimal
+            .try_into_bigint_with_precision_and_scale(MAX_SUPPORTED_PRECISION, scale)
+            .map_err(|e| match e {
+                IntermediateDecimalError::LossyCast => DecimalError::InvalidPrecision {
+                    error: format!(
+                        "{s} at scale {scale} has more than {MAX_SUPPORTED_PRECISION} digits"
+                    ),
+                },
+                _ => DecimalError::RoundingError {
+                    error: format!("{s} has more than {scale} fractional digits"),
+                },
+            })?;
+        value
+            .try_into()
+            .map_err(|e: ScalarConversionError| DecimalError::InvalidDecimal {
+                error: e.to_string(),
+            })
     }
     /// Compare two `Scalar`s as signed numbers.
     fn signed_cmp(&self, other: &Self) -> Ordering {
@@ -23,7 +137,58 @@ impl<S: Scalar> ScalarExt for S {}
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::base::scalar::{test_scalar::TestScalar, Curve25519Scalar, MontScalar};
+    use crate::{
+        base::scalar::{test_scalar::TestScalar, Curve25519Scalar, MontScalar},
+        proof_primitive::dory::DoryScalar,
+    };
+    #[test]
+    fn we_can_parse_a_decimal_string_at_a_scale() {
+        let parse = Curve25519Scalar::try_from_decimal_str;
+        assert_eq!(parse("3.14", 2), Ok(Curve25519Scalar::from(314)));
+        assert_eq!(parse("-3.14", 4), Ok(Curve25519Scalar::from(-31_400)));
+        assert_eq!(parse("3.1400", 2), Ok(Curve25519Scalar::from(314)));
+        assert_eq!(parse("42", 0), Ok(Curve25519Scalar::from(42)));
+        assert_eq!(parse("1200", -2), Ok(Curve25519Scalar::from(12)));
+        assert_eq!(parse("0", 10), Ok(Curve25519Scalar::ZERO));
+        let max = "9".repeat(75);
+        assert_eq!(
+            parse(&max, 0),
+            Ok(Curve25519Scalar::try_from(max.parse::<num_bigint::BigInt>().unwrap()).unwrap())
+        );
+    }
+
+    #[test]
+    fn we_cannot_parse_a_decimal_string_with_too_many_fractional_digits() {
+        assert!(matches!(
+            Curve25519Scalar::try_from_decimal_str("3.141", 2),
+            Err(DecimalError::RoundingError { .. })
+        ));
+        assert!(matches!(
+            Curve25519Scalar::try_from_decimal_str("1234", -2),
+            Err(DecimalError::RoundingError { .. })
+        ));
+    }
+
+    #[test]
+    fn we_cannot_parse_a_decimal_string_that_overflows_the_precision() {
+        assert!(matches!(
+            Curve25519Scalar::try_from_decimal_str(&"9".repeat(76), 0),
+            Err(DecimalError::InvalidPrecision { .. })
+        ));
+        assert!(matches!(
+            Curve25519Scalar::try_from_decimal_str(&"9".repeat(74), 2),
+            Err(DecimalError::InvalidPrecision { .. })
+        ));
+        assert!(matches!(
+            Curve25519Scalar::try_from_decimal_str("1e80", 0),
+            Err(DecimalError::InvalidPrecision { .. })
+        ));
+        assert!(matches!(
+            Curve25519Scalar::try_from_decimal_str("notadecimal", 0),
+            Err(DecimalError::InvalidDecimal { .. })
+        ));
+    }
+
     #[test]
     fn scalar_comparison_works() {
         let zero = Curve25519Scalar::ZERO;
@@ -38,12 +203,48 @@ mod tests {
         assert_eq!(two * max + one, zero);
     }
     #[test]
+    fn binary_values_hash_differently_from_strings_with_the_same_bytes() {
+        for value in ["", "abc", "hello world"] {
+            assert_ne!(
+                Curve25519Scalar::from_byte_slice_via_hash(value.as_bytes()),
+                Curve25519Scalar::from(value)
+            );
+        }
+        assert_eq!(
+            Curve25519Scalar::from_byte_slice_via_hash(&[0xff, 0x00]),
+            Curve25519Scalar::from_byte_slice_via_hash(&[0xff, 0x00])
+        );
+        assert_ne!(
+            Curve25519Scalar::from_byte_slice_via_hash(&[0xff, 0x00]),
+            Curve25519Scalar::from_byte_slice_via_hash(&[0xff])
+        );
+    }
+    #[test]
+    fn binary_values_do_not_collide_with_strings_prefixed_by_a_domain_separator() {
+        let binary = b"abc";
+        for prefix in [VARBINARY_HASH_CONTEXT, "proof-of-sql:varbinary"] {
+            let string = [prefix.as_bytes(), binary].concat();
+            assert_ne!(
+                Curve25519Scalar::from_byte_slice_via_hash(binary),
+                Curve25519Scalar::from(core::str::from_utf8(&string).unwrap())
+            );
+        }
+    }
+    #[test]
+    fn we_can_zeroize_scalars() {
+        let mut scalars = [
+            TestScalar::from(-5),
+            TestScalar::MAX_SIGNED,
+            TestScalar::ONE,
+        ];
+        zeroize_scalars(&mut scalars);
+        assert_eq!(scalars, [TestScalar::ZERO; 3]);
+        zeroize_scalars::<TestScalar>(&mut []);
+    }
+    #[test]
     fn we_can_compute_powers_of_10() {
         for i in 0..=u128::MAX.ilog10() {
-            assert_eq!(
-                TestScalar::pow10(u8::try_from(i).unwrap()),
-                TestScalar::from(u128::pow(10, i))
-            );
+            assert_eq!(TestScalar::pow10(i), TestScalar::from(u128::pow(10, i)));
         }
         assert_eq!(
             TestScalar::pow10(76),
@@ -52,4 +253,99 @@ mod tests {
             ))
         );
     }
+    #[test]
+    fn we_can_compute_powers() {
+        let three = Curve25519Scalar::from(3);
+        let mut expected = Curve25519Scalar::ONE;
+        for exponent in 0..=64 {
+            assert_eq!(three.pow(exponent), expected);
+            expected *= three;
+        }
+        assert_eq!(Curve25519Scalar::ZERO.pow(0), Curve25519Scalar::ONE);
+        assert_eq!(Curve25519Scalar::ZERO.pow(5), Curve25519Scalar::ZERO);
+        assert_eq!(
+            Curve25519Scalar::from(-2).pow(63),
+            Curve25519Scalar::from(i64::MIN)
+        );
+        assert_eq!(
+            Curve25519Scalar::TWO.pow(127),
+            Curve25519Scalar::from(1_u128 << 127)
+        );
+        // Powers wrap around the field order, as in Fermat's little theorem
+        let large_exponent = u64::MAX;
+        assert_eq!(
+            three.pow(large_exponent),
+            three.pow(large_exponent - 1) * three
+        );
+    }
+    #[test]
+    fn we_can_compute_powers_of_10_up_to_the_maximum_precision() {
+        assert_eq!(Curve25519Scalar::pow10(0), Curve25519Scalar::ONE);
+        assert_eq!(
+            Curve25519Scalar::pow10(18),
+            Curve25519Scalar::from(1_000_000_000_000_000_000_i64)
+        );
+        for exponent in 1..=75 {
+            assert_eq!(
+                Curve25519Scalar::pow10(exponent),
+                Curve25519Scalar::pow10(exponent - 1) * Curve25519Scalar::TEN
+            );
+        }
+        assert_eq!(
+            DoryScalar::pow10(75),
+            MontScalar(ark_ff::MontFp!(
+                "1000000000000000000000000000000000000000000000000000000000000000000000000000"
+            ))
+        );
+    }
+    #[test]
+    fn we_can_round_trip_scalars_through_canonical_bytes() {
+        let mut one = [0u8; 32];
+        one[0] = 1;
+        assert_eq!(Curve25519Scalar::ZERO.to_canonical_bytes(), [0u8; 32]);
+        assert_eq!(Curve25519Scalar::ONE.to_canonical_bytes(), one);
+        assert_eq!(
+            Curve25519Scalar::from(0x0102_0304_0506_0708_i64).to_canonical_bytes()[..9],
+            [8, 7, 6, 5, 4, 3, 2, 1, 0]
+        );
+        for value in [
+            Curve25519Scalar::ZERO,
+            Curve25519Scalar::ONE,
+            Curve25519Scalar::MAX_SIGNED,
+            Curve25519Scalar::from(-1),
+            Curve25519Scalar::from(i128::MIN),
+            Curve25519Scalar::from("a string"),
+        ] {
+            let bytes = value.to_canonical_bytes();
+            assert_eq!(bytes, value.to_canonical_bytes());
+            assert_eq!(Curve25519Scalar::from_canonical_bytes(bytes), Some(value));
+        }
+        for value in [
+            DoryScalar::ONE,
+            DoryScalar::from(-1),
+            DoryScalar::MAX_SIGNED,
+        ] {
+            assert_eq!(
+                DoryScalar::from_canonical_bytes(value.to_canonical_bytes()),
+                Some(value)
+            );
+        }
+    }
+    #[test]
+    fn we_cannot_deserialize_non_canonical_bytes() {
+        // The modulus of Curve25519Scalar, 2^252 + 27742317777372353535851937790883648493
+        let mut modulus = [
+            0xed, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58, 0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9,
+            0xde, 0x14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x10,
+        ];
+        assert_eq!(Curve25519Scalar::from_canonical_bytes(modulus), None);
+        assert_eq!(Curve25519Scalar::from_canonical_bytes([0xff; 32]), None);
+        modulus[0] -= 1;
+        assert_eq!(modulus, Curve25519Scalar::from(-1).to_canonical_bytes());
+        assert_eq!(
+            Curve25519Scalar::from_canonical_bytes(modulus),
+            Some(Curve25519Scalar::from(-1))
+        );
+        assert_eq!(DoryScalar::from_canonical_bytes([0xff; 32]), None);
+    }
 }
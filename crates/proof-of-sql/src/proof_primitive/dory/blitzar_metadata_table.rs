@@ -38,6 +38,7 @@ pub const fn min_as_f(column_type: ColumnType) -> F {
         ColumnType::Decimal75(_, _)
         | ColumnType::Scalar
         | ColumnType::VarChar
+        | ColumnType::VarBinary
         | ColumnType::Boolean => MontFp!("0"),
     }
 }
@@ -126,7 +127,8 @@ fn copy_column_data_to_slice(
         }
         CommittableColumn::Scalar(column)
         | CommittableColumn::Decimal75(_, _, column)
-        | CommittableColumn::VarChar(column) => {
+        | CommittableColumn::VarChar(column)
+        | CommittableColumn::VarBinary(column) => {
             scalar_row_slice[start..end].copy_from_slice(&column[index].offset_to_bytes());
         }
         CommittableColumn::RangeCheckWord(_) => todo!(),
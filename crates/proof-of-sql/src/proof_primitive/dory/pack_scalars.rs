@@ -426,7 +426,8 @@ pub fn bit_table_and_scalars_for_packed_msm(
             }
             CommittableColumn::Decimal75(_, _, column)
             | CommittableColumn::Scalar(column)
-            | CommittableColumn::VarChar(column) => {
+            | CommittableColumn::VarChar(column)
+            | CommittableColumn::VarBinary(column) => {
                 pack_bit(
                     column,
                     &mut packed_scalars,
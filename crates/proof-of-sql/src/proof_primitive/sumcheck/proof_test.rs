@@ -1,4 +1,4 @@
-use super::test_cases::sumcheck_test_cases;
+use super::{prover_state::ProverState, test_cases::sumcheck_test_cases};
 use crate::base::{
     polynomial::{CompositePolynomial, CompositePolynomialInfo},
     proof::Transcript as _,
@@ -10,7 +10,7 @@ use crate::base::{
  * See third_party/license/arkworks.LICENSE
  */
 use crate::proof_primitive::sumcheck::proof::*;
-use alloc::rc::Rc;
+use alloc::{rc::Rc, vec};
 use ark_std::UniformRand;
 use merlin::Transcript;
 use num_traits::{One, Zero};
@@ -246,3 +246,18 @@ fn we_can_verify_many_random_test_cases() {
         );
     }
 }
+
+#[test]
+fn we_can_zeroize_the_witness_copies_held_by_the_prover_state() {
+    let mut poly = CompositePolynomial::new(1);
+    let secret = Rc::new(vec![TestScalar::from(123), TestScalar::from(456)]);
+    poly.add_product([secret.clone()], TestScalar::ONE);
+    let mut state = ProverState::create(&poly);
+    assert_eq!(state.flattened_ml_extensions, [secret.as_ref().clone()]);
+
+    state.zeroize();
+    assert!(state.flattened_ml_extensions.is_empty());
+    // The polynomial's own copy is shared with `secret`, so it is left untouched.
+    drop(poly);
+    assert_eq!(*secret, [TestScalar::from(123), TestScalar::from(456)]);
+}
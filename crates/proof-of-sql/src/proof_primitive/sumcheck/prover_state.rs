@@ -4,7 +4,7 @@ use crate::base::polynomial::CompositePolynomial;
  *
  * See third_party/license/arkworks.LICENSE
  */
-use crate::base::scalar::Scalar;
+use crate::base::scalar::{zeroize_scalars, Scalar};
 use alloc::vec::Vec;
 
 pub struct ProverState<S: Scalar> {
@@ -45,3 +45,19 @@ impl<S: Scalar> ProverState<S> {
         }
     }
 }
+
+impl<S: Scalar> ProverState<S> {
+    /// Clears the copies of the witness MLEs, which may contain secret data.
+    pub fn zeroize(&mut self) {
+        for mle in &mut self.flattened_ml_extensions {
+            zeroize_scalars(mle);
+        }
+        self.flattened_ml_extensions.clear();
+    }
+}
+
+impl<S: Scalar> Drop for ProverState<S> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
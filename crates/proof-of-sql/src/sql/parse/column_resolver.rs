@@ -0,0 +1,35 @@
+use super::ConversionError;
+use crate::base::database::{ColumnRef, SchemaAccessor, TableRef};
+use alloc::{boxed::Box, string::ToString, vec::Vec};
+use proof_of_sql_parser::Identifier;
+
+/// Looks up the columns `names` of `table_ref` in `accessor` and builds a [`ColumnRef`] for each,
+/// e.g. when building plans dynamically.
+///
+/// The column types are taken from the accessor. The references are returned in the order of
+/// `names`.
+///
+/// # Errors
+/// Returns [`ConversionError::ParseError`] if a name is not a valid identifier, and
+/// [`ConversionError::MissingColumn`] for the first column that is not in the table.
+pub fn resolve_columns(
+    table_ref: TableRef,
+    names: &[&str],
+    accessor: &impl SchemaAccessor,
+) -> Result<Vec<ColumnRef>, ConversionError> {
+    names
+        .iter()
+        .map(|name| {
+            let column_id: Identifier = name
+                .parse()
+                .map_err(|error: proof_of_sql_parser::ParseError| error.to_string())?;
+            let column_type = accessor
+                .lookup_column(table_ref, column_id)
+                .ok_or_else(|| ConversionError::MissingColumn {
+                    identifier: Box::new(column_id),
+                    resource_id: Box::new(table_ref.resource_id()),
+                })?;
+            Ok(ColumnRef::new(table_ref, column_id, column_type))
+        })
+        .collect()
+}
@@ -0,0 +1,61 @@
+use crate::{
+    base::{
+        database::{ColumnRef, ColumnType, TableRef, TestSchemaAccessor},
+        map::indexmap,
+        math::decimal::Precision,
+    },
+    sql::parse::{resolve_columns, ConversionError},
+};
+use alloc::{boxed::Box, vec};
+
+fn accessor(t: TableRef) -> TestSchemaAccessor {
+    TestSchemaAccessor::new(indexmap! {
+        t => indexmap! {
+            "a".parse().unwrap() => ColumnType::BigInt,
+            "b".parse().unwrap() => ColumnType::VarChar,
+            "c".parse().unwrap() => ColumnType::Decimal75(Precision::new(10).unwrap(), 2),
+        },
+    })
+}
+
+#[test]
+fn we_can_resolve_columns_with_their_types_from_the_accessor() {
+    let t: TableRef = "sxt.t".parse().unwrap();
+    let accessor = accessor(t);
+    assert_eq!(
+        resolve_columns(t, &["c", "a", "b", "a"], &accessor).unwrap(),
+        vec![
+            ColumnRef::new(
+                t,
+                "c".parse().unwrap(),
+                ColumnType::Decimal75(Precision::new(10).unwrap(), 2)
+            ),
+            ColumnRef::new(t, "a".parse().unwrap(), ColumnType::BigInt),
+            ColumnRef::new(t, "b".parse().unwrap(), ColumnType::VarChar),
+            ColumnRef::new(t, "a".parse().unwrap(), ColumnType::BigInt),
+        ]
+    );
+    assert_eq!(resolve_columns(t, &[], &accessor).unwrap(), vec![]);
+}
+
+#[test]
+fn we_cannot_resolve_unknown_columns() {
+    let t: TableRef = "sxt.t".parse().unwrap();
+    let accessor = accessor(t);
+    assert_eq!(
+        resolve_columns(t, &["a", "missing", "other_missing"], &accessor),
+        Err(ConversionError::MissingColumn {
+            identifier: Box::new("missing".parse().unwrap()),
+            resource_id: Box::new(t.resource_id()),
+        })
+    );
+    let u: TableRef = "sxt.u".parse().unwrap();
+    assert!(matches!(
+        resolve_columns(u, &["a"], &accessor),
+        Err(ConversionError::MissingColumn { .. })
+    ));
+    assert!(matches!(
+        resolve_columns(t, &["not a name"], &accessor),
+        Err(ConversionError::ParseError { .. })
+    ));
+}
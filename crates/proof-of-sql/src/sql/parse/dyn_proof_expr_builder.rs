@@ -14,10 +14,10 @@ use crate::{
             dyn_proof_expr_builder::DecimalError::{InvalidPrecision, InvalidScale},
             ConversionError::DecimalConversionError,
         },
-        proof_exprs::{ColumnExpr, DynProofExpr, ProofExpr},
+        proof_exprs::{ColumnExpr, DynProofExpr, ProofExpr, DEFAULT_MAX_EXPR_DEPTH},
     },
 };
-use alloc::{borrow::ToOwned, boxed::Box, format, string::ToString};
+use alloc::{borrow::ToOwned, boxed::Box, format, string::ToString, vec, vec::Vec};
 use proof_of_sql_parser::{
     intermediate_ast::{AggregationOperator, BinaryOperator, Expression, Literal, UnaryOperator},
     posql_time::{PoSQLTimeUnit, PoSQLTimestampError},
@@ -47,30 +47,231 @@ impl<'a> DynProofExprBuilder<'a> {
         }
     }
     /// Builds a `proofs::sql::proof_exprs::DynProofExpr` from a `proof_of_sql_parser::intermediate_ast::Expression`
+    ///
+    /// # Errors
+    /// Returns [`ConversionError::PlanTooDeep`] if the expression is nested deeper than [`DEFAULT_MAX_EXPR_DEPTH`],
+    /// before any of it is visited.
     pub fn build<C: Commitment>(
         &self,
         expr: &Expression,
     ) -> Result<DynProofExpr<C>, ConversionError> {
+        check_expression_depth(expr)?;
         self.visit_expr(expr)
     }
 }
 
+/// Check that an intermediate AST expression is not nested deeper than [`DEFAULT_MAX_EXPR_DEPTH`].
+///
+/// This walks the expression with an explicit worklist, so it should be called before any recursive visit.
+pub(crate) fn check_expression_depth(expr: &Expression) -> Result<(), ConversionError> {
+    let depth = expression_depth(expr);
+    if depth > DEFAULT_MAX_EXPR_DEPTH {
+        Err(ConversionError::PlanTooDeep {
+            depth,
+            max_depth: DEFAULT_MAX_EXPR_DEPTH,
+        })
+    } else {
+        Ok(())
+    }
+}
+
+/// The nesting depth of an intermediate AST expression, computed with an explicit worklist.
+fn expression_depth(expr: &Expression) -> usize {
+    let mut max_depth = 0;
+    let mut worklist = vec![(expr, 1)];
+    while let Some((expr, depth)) = worklist.pop() {
+        max_depth = max_depth.max(depth);
+        match expr {
+            Expression::Literal(_) | Expression::Column(_) | Expression::Wildcard => {}
+            Expression::Unary { expr, .. } | Expression::Aggregation { expr, .. } => {
+                worklist.push((expr, depth + 1));
+            }
+            Expression::Binary { left, right, .. } => {
+                worklist.push((left, depth + 1));
+                worklist.push((right, depth + 1));
+            }
+        }
+    }
+    max_depth
+}
+
+/// The side and operator of a binary operand, used to annotate errors
+type OperandPosition = (&'static str, BinaryOperator);
+
+/// A step of the worklist used by [`DynProofExprBuilder`] to build an expression without recursion
+enum BuildStep<'e> {
+    /// Build a leaf expression, or schedule the operands of a compound one
+    Visit {
+        expr: &'e Expression,
+        level: usize,
+        position: Option<OperandPosition>,
+        in_agg_scope: bool,
+    },
+    /// Combine the built operands of a compound expression
+    Finish { expr: &'e Expression, level: usize },
+}
+
 #[allow(clippy::match_wildcard_for_single_variants)]
 // Private interface
 impl DynProofExprBuilder<'_> {
+    /// Builds the expression with an explicit worklist rather than recursion,
+    /// so that deeply nested expressions cannot overflow the stack.
+    ///
+    /// Operands are built left to right onto a stack of built expressions,
+    /// and combined once all of them are built.
+    /// Errors in binary operands are annotated with their position, as in [`ConversionError::in_operand`].
+    ///
+    /// # Panics
+    /// Never panics, since every visited expression is either built or fails the whole build.
     fn visit_expr<C: Commitment>(
         &self,
         expr: &Expression,
     ) -> Result<DynProofExpr<C>, ConversionError> {
+        let mut steps = vec![BuildStep::Visit {
+            expr,
+            level: 0,
+            position: None,
+            in_agg_scope: self.in_agg_scope,
+        }];
+        // The positions of the operands leading to the current expression, indexed by level
+        let mut path: Vec<Option<OperandPosition>> = Vec::new();
+        let mut built: Vec<DynProofExpr<C>> = Vec::new();
+        while let Some(step) = steps.pop() {
+            let result = match step {
+                BuildStep::Visit {
+                    expr,
+                    level,
+                    position,
+                    in_agg_scope,
+                } => {
+                    path.truncate(level);
+                    path.push(position);
+                    self.visit_node(expr, level, in_agg_scope, &mut steps)
+                }
+                BuildStep::Finish { expr, level } => {
+                    path.truncate(level + 1);
+                    Self::finish_node(expr, &mut built).map(Some)
+                }
+            };
+            match result {
+                Ok(Some(expr)) => built.push(expr),
+                Ok(None) => {}
+                Err(error) => {
+                    return Err(path
+                        .iter()
+                        .rev()
+                        .flatten()
+                        .fold(error, |error, (side, op)| {
+                            error.in_operand(format!("{side} operand of {op:?}"))
+                        }))
+                }
+            }
+        }
+        Ok(built
+            .pop()
+            .expect("every visited expression is eventually built"))
+    }
+
+    /// Builds a leaf expression, or schedules the operands of a compound one followed by its [`BuildStep::Finish`].
+    fn visit_node<'e, C: Commitment>(
+        &self,
+        expr: &'e Expression,
+        level: usize,
+        in_agg_scope: bool,
+        steps: &mut Vec<BuildStep<'e>>,
+    ) -> Result<Option<DynProofExpr<C>>, ConversionError> {
+        let operands = match expr {
+            Expression::Column(identifier) => return self.visit_column(*identifier).map(Some),
+            Expression::Literal(lit) => return self.visit_literal(lit).map(Some),
+            Expression::Binary {
+                op: BinaryOperator::Division,
+                ..
+            } => {
+                return Err(ConversionError::Unprovable {
+                    error: "Binary operator Division is not supported at this location".to_string(),
+                })
+            }
+            Expression::Binary { op, left, right } => {
+                vec![(left, Some(("left", *op))), (right, Some(("right", *op)))]
+            }
+            // Unary operands are left unannotated, since `!=` is desugared into a NOT the user never wrote.
+            Expression::Unary { expr: operand, .. } => vec![(operand, None)],
+            Expression::Aggregation { .. } if in_agg_scope => {
+                return Err(ConversionError::InvalidExpression {
+                    expression: "nested aggregations are invalid".to_string(),
+                })
+            }
+            Expression::Aggregation { expr: operand, .. } => {
+                steps.push(BuildStep::Finish { expr, level });
+                steps.push(BuildStep::Visit {
+                    expr: operand,
+                    level: level + 1,
+                    position: None,
+                    in_agg_scope: true,
+                });
+                return Ok(None);
+            }
+            _ => {
+                return Err(ConversionError::Unprovable {
+                    error: format!("Expression {expr:?} is not supported yet"),
+                })
+            }
+        };
+        steps.push(BuildStep::Finish { expr, level });
+        // Pushed in reverse so that the left operand is built first
+        steps.extend(
+            operands
+                .into_iter()
+                .rev()
+                .map(|(operand, position)| BuildStep::Visit {
+                    expr: operand,
+                    level: level + 1,
+                    position,
+                    in_agg_scope,
+                }),
+        );
+        Ok(None)
+    }
+
+    /// Combines the built operands of a compound expression, which are on top of `built`.
+    ///
+    /// # Panics
+    /// Panics if `expr` is not a compound expression scheduled by `visit_node`, or if its operands are missing.
+    fn finish_node<C: Commitment>(
+        expr: &Expression,
+        built: &mut Vec<DynProofExpr<C>>,
+    ) -> Result<DynProofExpr<C>, ConversionError> {
+        let mut pop = || {
+            built
+                .pop()
+                .expect("the operands are built before the expression is finished")
+        };
         match expr {
-            Expression::Column(identifier) => self.visit_column(*identifier),
-            Expression::Literal(lit) => self.visit_literal(lit),
-            Expression::Binary { op, left, right } => self.visit_binary_expr(*op, left, right),
-            Expression::Unary { op, expr } => self.visit_unary_expr(*op, expr),
-            Expression::Aggregation { op, expr } => self.visit_aggregate_expr(*op, expr),
-            _ => Err(ConversionError::Unprovable {
-                error: format!("Expression {expr:?} is not supported yet"),
-            }),
+            Expression::Binary { op, .. } => {
+                let right = pop();
+                let left = pop();
+                match op {
+                    BinaryOperator::And => DynProofExpr::try_new_and(left, right),
+                    BinaryOperator::Or => DynProofExpr::try_new_or(left, right),
+                    BinaryOperator::Equal => DynProofExpr::try_new_equals(left, right),
+                    BinaryOperator::GreaterThanOrEqual => {
+                        DynProofExpr::try_new_inequality(left, right, false)
+                    }
+                    BinaryOperator::LessThanOrEqual => {
+                        DynProofExpr::try_new_inequality(left, right, true)
+                    }
+                    BinaryOperator::Add => DynProofExpr::try_new_add(left, right),
+                    BinaryOperator::Subtract => DynProofExpr::try_new_subtract(left, right),
+                    BinaryOperator::Multiply => DynProofExpr::try_new_multiply(left, right),
+                    BinaryOperator::Division => unreachable!("division is rejected when visited"),
+                }
+            }
+            Expression::Unary {
+                op: UnaryOperator::Not,
+                ..
+            } => DynProofExpr::try_new_not(pop()),
+            Expression::Aggregation { op, .. } => Self::finish_aggregate_expr(*op, pop()),
+            _ => unreachable!("only compound expressions are finished"),
         }
     }
 
@@ -79,7 +280,7 @@ impl DynProofExprBuilder<'_> {
         identifier: Identifier,
     ) -> Result<DynProofExpr<C>, ConversionError> {
         Ok(DynProofExpr::Column(ColumnExpr::new(
-            *self.column_mapping.get(&identifier).ok_or(
+            self.column_mapping.get(&identifier).ok_or(
                 ConversionError::MissingColumnWithoutTable {
                     identifier: Box::new(identifier),
                 },
@@ -141,81 +342,10 @@ impl DynProofExprBuilder<'_> {
         }
     }
 
-    fn visit_unary_expr<C: Commitment>(
-        &self,
-        op: UnaryOperator,
-        expr: &Expression,
-    ) -> Result<DynProofExpr<C>, ConversionError> {
-        let expr = self.visit_expr(expr);
-        match op {
-            UnaryOperator::Not => DynProofExpr::try_new_not(expr?),
-        }
-    }
-
-    fn visit_binary_expr<C: Commitment>(
-        &self,
-        op: BinaryOperator,
-        left: &Expression,
-        right: &Expression,
-    ) -> Result<DynProofExpr<C>, ConversionError> {
-        match op {
-            BinaryOperator::And => {
-                let left = self.visit_expr(left);
-                let right = self.visit_expr(right);
-                DynProofExpr::try_new_and(left?, right?)
-            }
-            BinaryOperator::Or => {
-                let left = self.visit_expr(left);
-                let right = self.visit_expr(right);
-                DynProofExpr::try_new_or(left?, right?)
-            }
-            BinaryOperator::Equal => {
-                let left = self.visit_expr(left);
-                let right = self.visit_expr(right);
-                DynProofExpr::try_new_equals(left?, right?)
-            }
-            BinaryOperator::GreaterThanOrEqual => {
-                let left = self.visit_expr(left);
-                let right = self.visit_expr(right);
-                DynProofExpr::try_new_inequality(left?, right?, false)
-            }
-            BinaryOperator::LessThanOrEqual => {
-                let left = self.visit_expr(left);
-                let right = self.visit_expr(right);
-                DynProofExpr::try_new_inequality(left?, right?, true)
-            }
-            BinaryOperator::Add => {
-                let left = self.visit_expr(left);
-                let right = self.visit_expr(right);
-                DynProofExpr::try_new_add(left?, right?)
-            }
-            BinaryOperator::Subtract => {
-                let left = self.visit_expr(left);
-                let right = self.visit_expr(right);
-                DynProofExpr::try_new_subtract(left?, right?)
-            }
-            BinaryOperator::Multiply => {
-                let left = self.visit_expr(left);
-                let right = self.visit_expr(right);
-                DynProofExpr::try_new_multiply(left?, right?)
-            }
-            BinaryOperator::Division => Err(ConversionError::Unprovable {
-                error: format!("Binary operator {op:?} is not supported at this location"),
-            }),
-        }
-    }
-
-    fn visit_aggregate_expr<C: Commitment>(
-        &self,
+    fn finish_aggregate_expr<C: Commitment>(
         op: AggregationOperator,
-        expr: &Expression,
+        expr: DynProofExpr<C>,
     ) -> Result<DynProofExpr<C>, ConversionError> {
-        if self.in_agg_scope {
-            return Err(ConversionError::InvalidExpression {
-                expression: "nested aggregations are invalid".to_string(),
-            });
-        }
-        let expr = DynProofExprBuilder::new_agg(self.column_mapping).visit_expr(expr)?;
         match (op, expr.data_type().is_numeric()) {
             (AggregationOperator::Count, _) | (AggregationOperator::Sum, true) => {
                 Ok(DynProofExpr::new_aggregate(op, expr))
@@ -6,6 +6,8 @@ use alloc::{
     boxed::Box,
     format,
     string::{String, ToString},
+    vec,
+    vec::Vec,
 };
 use core::result::Result;
 use proof_of_sql_parser::{posql_time::PoSQLTimestampError, Identifier, ResourceId};
@@ -30,6 +32,19 @@ pub enum ConversionError {
         identifier: Box<Identifier>,
     },
 
+    #[snafu(display(
+        "Column '{identifier}' of table '{column_table}' cannot be used in a plan over table '{table}'"
+    ))]
+    /// A plan references a column of a table other than the one it reads from
+    ColumnFromOtherTable {
+        /// The column identifier
+        identifier: Box<Identifier>,
+        /// The table the column belongs to
+        column_table: Box<ResourceId>,
+        /// The table the plan reads from
+        table: Box<ResourceId>,
+    },
+
     #[snafu(display("Expected '{expected}' but found '{actual}'"))]
     /// Invalid data type received
     InvalidDataType {
@@ -133,6 +148,24 @@ pub enum ConversionError {
         source: crate::sql::postprocessing::PostprocessingError,
     },
 
+    #[snafu(display("In {}: {source}", path.join(" → ")))]
+    /// An error in a nested operand, along with the path from the root expression to it
+    InOperand {
+        /// The positions of the operands leading to the offending one, outermost first
+        path: Vec<String>,
+        /// The underlying error
+        source: Box<ConversionError>,
+    },
+
+    #[snafu(display("Expression has depth {depth}, which exceeds the maximum of {max_depth}"))]
+    /// Expression is nested too deeply to be proven safely
+    PlanTooDeep {
+        /// The depth of the expression
+        depth: usize,
+        /// The maximum allowed depth
+        max_depth: usize,
+    },
+
     #[snafu(display("Query not provable because: {error}"))]
     /// Query requires unprovable feature
     Unprovable {
@@ -162,6 +195,24 @@ impl From<IntermediateDecimalError> for ConversionError {
 }
 
 impl ConversionError {
+    /// Adds the position of the operand this error occurred in, e.g. `left operand of Multiply`.
+    ///
+    /// Calling this again while the error propagates up an expression tree prepends the outer positions,
+    /// so that the error pinpoints the offending operand from the root of the expression.
+    #[must_use]
+    pub fn in_operand(self, position: String) -> Self {
+        match self {
+            ConversionError::InOperand { mut path, source } => {
+                path.insert(0, position);
+                ConversionError::InOperand { path, source }
+            }
+            error => ConversionError::InOperand {
+                path: vec![position],
+                source: Box::new(error),
+            },
+        }
+    }
+
     /// Returns a `ConversionError::InvalidExpression` for non-numeric types used in numeric aggregation functions.
     pub fn non_numeric_expr_in_agg<S: Into<String>>(dtype: S, func: S) -> Self {
         ConversionError::InvalidExpression {
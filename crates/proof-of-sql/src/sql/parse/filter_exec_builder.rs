@@ -33,7 +33,10 @@ impl<C: Commitment> FilterExecBuilder<C> {
     }
 
     pub fn add_table_expr(mut self, table_ref: TableRef) -> Self {
-        self.table_expr = Some(TableExpr { table_ref });
+        self.table_expr = Some(TableExpr {
+            table_ref,
+            alias: None,
+        });
         self
     }
 
@@ -69,7 +72,7 @@ impl<C: Commitment> FilterExecBuilder<C> {
             for alias in self.column_mapping.keys().sorted() {
                 let column_ref = self.column_mapping.get(alias).unwrap();
                 self.filter_result_expr_list.push(AliasedDynProofExpr {
-                    expr: DynProofExpr::new_column(*column_ref),
+                    expr: DynProofExpr::new_column(column_ref),
                     alias: *alias,
                 });
             }
@@ -78,8 +81,8 @@ impl<C: Commitment> FilterExecBuilder<C> {
     }
 
     #[allow(clippy::missing_panics_doc)]
-    pub fn build(self) -> FilterExec<C> {
-        FilterExec::new(
+    pub fn build(self) -> Result<FilterExec<C>, ConversionError> {
+        FilterExec::try_new(
             self.filter_result_expr_list,
             self.table_expr.expect("Table expr is required"),
             self.where_expr
@@ -13,6 +13,11 @@ mod query_expr_tests;
 mod query_expr;
 pub use query_expr::QueryExpr;
 
+mod column_resolver;
+pub use column_resolver::resolve_columns;
+#[cfg(test)]
+mod column_resolver_tests;
+
 mod filter_exec_builder;
 pub(crate) use filter_exec_builder::FilterExecBuilder;
 
@@ -24,7 +29,7 @@ mod query_context_builder;
 pub(crate) use query_context_builder::{type_check_binary_operation, QueryContextBuilder};
 
 mod dyn_proof_expr_builder;
-pub(crate) use dyn_proof_expr_builder::DynProofExprBuilder;
+pub(crate) use dyn_proof_expr_builder::{check_expression_depth, DynProofExprBuilder};
 
 mod where_expr_builder;
 pub(crate) use where_expr_builder::WhereExprBuilder;
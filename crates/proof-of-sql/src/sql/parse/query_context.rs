@@ -101,10 +101,10 @@ impl QueryContext {
         self.agg_counter > 0 || !self.group_by_exprs.is_empty()
     }
 
-    pub fn push_column_ref(&mut self, column: Identifier, column_ref: ColumnRef) {
+    pub fn push_column_ref(&mut self, column: Identifier, column_ref: &ColumnRef) {
         self.col_ref_counter += 1;
         self.push_result_column_ref(column);
-        self.column_mapping.insert(column, column_ref);
+        self.column_mapping.insert(column, *column_ref);
     }
 
     fn push_result_column_ref(&mut self, column: Identifier) {
@@ -234,11 +234,15 @@ impl<C: Commitment> TryFrom<&QueryContext> for Option<GroupByExec<C>> {
         let where_clause = WhereExprBuilder::new(&value.column_mapping)
             .build(value.where_expr.clone())?
             .unwrap_or_else(|| DynProofExpr::new_literal(LiteralValue::Boolean(true)));
-        let table = value.table.map(|table_ref| TableExpr { table_ref }).ok_or(
-            ConversionError::InvalidExpression {
+        let table = value
+            .table
+            .map(|table_ref| TableExpr {
+                table_ref,
+                alias: None,
+            })
+            .ok_or(ConversionError::InvalidExpression {
                 expression: "QueryContext has no table_ref".to_owned(),
-            },
-        )?;
+            })?;
         let resource_id = table.table_ref.resource_id();
         let group_by_exprs = value
             .group_by_exprs
@@ -251,7 +255,7 @@ impl<C: Commitment> TryFrom<&QueryContext> for Option<GroupByExec<C>> {
                         identifier: Box::new(*expr),
                         resource_id: Box::new(resource_id),
                     })
-                    .map(|column_ref| ColumnExpr::<C>::new(*column_ref))
+                    .map(ColumnExpr::<C>::new)
             })
             .collect::<Result<Vec<ColumnExpr<C>>, ConversionError>>()?;
         // For a query to be provable the result columns must be of one of three kinds below:
@@ -315,12 +319,12 @@ impl<C: Commitment> TryFrom<&QueryContext> for Option<GroupByExec<C>> {
         if !group_by_compliance || sum_expr.is_none() || !count_column_compliant {
             return Ok(None);
         }
-        Ok(Some(GroupByExec::new(
+        Ok(Some(GroupByExec::try_new(
             group_by_exprs,
             sum_expr.expect("the none case was just checked"),
             count_column.alias,
             table,
             where_clause,
-        )))
+        )?))
     }
 }
@@ -1,4 +1,4 @@
-use super::{ConversionError, ConversionResult, QueryContext};
+use super::{check_expression_depth, ConversionError, ConversionResult, QueryContext};
 use crate::base::{
     database::{
         try_add_subtract_column_types, try_multiply_column_types, ColumnRef, ColumnType,
@@ -9,7 +9,7 @@ use crate::base::{
         BigDecimalExt,
     },
 };
-use alloc::{boxed::Box, string::ToString, vec::Vec};
+use alloc::{boxed::Box, format, string::ToString, vec::Vec};
 use proof_of_sql_parser::{
     intermediate_ast::{
         AggregationOperator, AliasedResultExpr, BinaryOperator, Expression, Literal, OrderBy,
@@ -55,6 +55,7 @@ impl<'a> QueryContextBuilder<'a> {
         mut where_expr: Option<Box<Expression>>,
     ) -> ConversionResult<Self> {
         if let Some(expr) = where_expr.as_deref_mut() {
+            check_expression_depth(expr)?;
             self.visit_expr(expr)?;
         }
         self.context.set_where_expr(where_expr);
@@ -126,6 +127,7 @@ impl<'a> QueryContextBuilder<'a> {
     }
 
     fn visit_aliased_expr(&mut self, aliased_expr: AliasedResultExpr) -> ConversionResult<()> {
+        check_expression_depth(&aliased_expr.expr)?;
         self.visit_expr(&aliased_expr.expr)?;
         self.context.push_aliased_result_expr(aliased_expr)?;
         Ok(())
@@ -160,8 +162,12 @@ impl<'a> QueryContextBuilder<'a> {
         left: &Expression,
         right: &Expression,
     ) -> ConversionResult<ColumnType> {
-        let left_dtype = self.visit_expr(left)?;
-        let right_dtype = self.visit_expr(right)?;
+        let left_dtype = self
+            .visit_expr(left)
+            .map_err(|e| e.in_operand(format!("left operand of {op:?}")))?;
+        let right_dtype = self
+            .visit_expr(right)
+            .map_err(|e| e.in_operand(format!("right operand of {op:?}")))?;
         check_dtypes(left_dtype, right_dtype, op)?;
         match op {
             BinaryOperator::And
@@ -205,7 +211,9 @@ impl<'a> QueryContextBuilder<'a> {
         let expr_dtype = self.visit_expr(expr)?;
 
         // We only support sum/max/min aggregations on numeric columns.
-        if op != AggregationOperator::Count && expr_dtype == ColumnType::VarChar {
+        if op != AggregationOperator::Count
+            && matches!(expr_dtype, ColumnType::VarChar | ColumnType::VarBinary)
+        {
             return Err(ConversionError::non_numeric_expr_in_agg(
                 expr_dtype.to_string(),
                 op.to_string(),
@@ -254,7 +262,7 @@ impl<'a> QueryContextBuilder<'a> {
 
         let column = ColumnRef::new(*table_ref, column_name, column_type);
 
-        self.context.push_column_ref(column_name, column);
+        self.context.push_column_ref(column_name, &column);
 
         Ok(column_type)
     }
@@ -277,6 +285,7 @@ pub(crate) fn type_check_binary_operation(
             matches!(
                 (left_dtype, right_dtype),
                 (ColumnType::VarChar, ColumnType::VarChar)
+                    | (ColumnType::VarBinary, ColumnType::VarBinary)
                     | (ColumnType::TimestampTZ(_, _), ColumnType::TimestampTZ(_, _))
                     | (ColumnType::Boolean, ColumnType::Boolean)
                     | (_, ColumnType::Scalar)
@@ -7,6 +7,7 @@ use crate::{
             GroupByPostprocessing, OrderByPostprocessing, OwnedTablePostprocessing,
             SelectPostprocessing, SlicePostprocessing,
         },
+        proof_exprs::DEFAULT_MAX_EXPR_DEPTH,
         proof_plans::{DynProofPlan, GroupByExec},
     },
 };
@@ -44,10 +45,23 @@ impl<C: Commitment> QueryExpr<C> {
     }
 
     /// Parse an intermediate AST `SelectStatement` into a `QueryExpr`.
+    ///
+    /// Expressions nested deeper than [`DEFAULT_MAX_EXPR_DEPTH`] are rejected
+    /// with [`ConversionError::PlanTooDeep`](super::ConversionError::PlanTooDeep).
     pub fn try_new(
         ast: SelectStatement,
         default_schema: Identifier,
         schema_accessor: &dyn SchemaAccessor,
+    ) -> ConversionResult<Self> {
+        let query_expr = Self::try_new_without_depth_check(ast, default_schema, schema_accessor)?;
+        query_expr.proof_expr.check_depth(DEFAULT_MAX_EXPR_DEPTH)?;
+        Ok(query_expr)
+    }
+
+    fn try_new_without_depth_check(
+        ast: SelectStatement,
+        default_schema: Identifier,
+        schema_accessor: &dyn SchemaAccessor,
     ) -> ConversionResult<Self> {
         let context = match *ast.expr {
             SetExpression::Query {
@@ -98,7 +112,7 @@ impl<C: Commitment> QueryExpr<C> {
                     .add_table_expr(*context.get_table_ref())
                     .add_where_expr(context.get_where_expr().clone())?
                     .add_result_columns(&raw_enriched_exprs)
-                    .build();
+                    .build()?;
 
                 let group_by_postprocessing =
                     GroupByPostprocessing::try_new(group_by.to_vec(), result_aliased_exprs)?;
@@ -140,7 +154,7 @@ impl<C: Commitment> QueryExpr<C> {
                 .add_table_expr(*context.get_table_ref())
                 .add_where_expr(context.get_where_expr().clone())?
                 .add_result_columns(&enriched_exprs)
-                .build();
+                .build()?;
             // Check whether we need to do select postprocessing.
             if select_exprs
                 .iter()
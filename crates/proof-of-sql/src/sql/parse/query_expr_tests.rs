@@ -8,7 +8,7 @@ use crate::{
     sql::{
         parse::QueryExpr,
         postprocessing::{test_utility::*, PostprocessingError},
-        proof_exprs::test_utility::*,
+        proof_exprs::{test_utility::*, DEFAULT_MAX_EXPR_DEPTH},
         proof_plans::{test_utility::*, DynProofPlan},
     },
 };
@@ -18,8 +18,8 @@ use proof_of_sql_parser::{
     intermediate_ast::OrderByDirection::*,
     sql::SelectStatementParser,
     utility::{
-        add as padd, aliased_expr, col, count, count_all, lit, max, min, mul as pmul, sub as psub,
-        sum,
+        add as padd, aliased_expr, col, col_res, count, count_all, equal as pequal, lit, max, min,
+        mul as pmul, query, select, sub as psub, sum, tab as ptab,
     },
     Identifier,
 };
@@ -1668,6 +1668,36 @@ fn varchar_column_is_not_compatible_with_integer_column() {
     }
 }
 
+#[test]
+fn errors_in_nested_operands_report_the_path_to_the_operand() {
+    let t = "sxt.employees".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "salary".parse().unwrap() => ColumnType::BigInt,
+            "name".parse().unwrap() => ColumnType::VarChar,
+        },
+    );
+
+    let query_text = "select (salary + 1) * (2 - (3 * name)) from sxt.employees";
+    let intermediate_ast = SelectStatementParser::new().parse(query_text).unwrap();
+    let result = QueryExpr::<NaiveCommitment>::try_new(intermediate_ast, t.schema_id(), &accessor);
+
+    assert_eq!(
+        result,
+        Err(ConversionError::InOperand {
+            path: vec![
+                "right operand of Multiply".to_string(),
+                "right operand of Subtract".to_string(),
+            ],
+            source: Box::new(ConversionError::DataTypeMismatch {
+                left_type: ColumnType::BigInt.to_string(),
+                right_type: ColumnType::VarChar.to_string(),
+            }),
+        })
+    );
+}
+
 #[test]
 fn arithmetic_operations_are_not_allowed_with_varchar_column() {
     let t = "sxt.employees".parse().unwrap();
@@ -2176,3 +2206,51 @@ fn we_can_serialize_list_of_filters_from_query_expr() {
     assert_eq!(filter_execs.len(), deserialized_as_ref.len());
     assert_eq!(filter_execs[0], deserialized_as_ref[0]);
 }
+
+#[test]
+fn we_cannot_convert_an_ast_with_an_expression_deep_enough_to_overflow_the_stack() {
+    let t = "sxt.sxt_tab".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "a".parse().unwrap() => ColumnType::BigInt,
+        },
+    );
+    // Deep enough that visiting the expression recursively would overflow the stack
+    let depth = 3_000;
+    let sum = (1..depth).fold(col("a"), |sum, _| padd(sum, lit(1)));
+    let where_too_deep = select(
+        query(
+            vec![col_res(col("a"), "a")],
+            ptab(None, "sxt_tab"),
+            pequal(sum.clone(), lit(0)),
+            vec![],
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(
+        QueryExpr::<RistrettoPoint>::try_new(where_too_deep, t.schema_id(), &accessor),
+        Err(ConversionError::PlanTooDeep {
+            depth: depth + 1,
+            max_depth: DEFAULT_MAX_EXPR_DEPTH,
+        })
+    );
+    let result_too_deep = select(
+        query(
+            vec![col_res(sum, "s")],
+            ptab(None, "sxt_tab"),
+            pequal(col("a"), lit(0)),
+            vec![],
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(
+        QueryExpr::<RistrettoPoint>::try_new(result_too_deep, t.schema_id(), &accessor),
+        Err(ConversionError::PlanTooDeep {
+            depth,
+            max_depth: DEFAULT_MAX_EXPR_DEPTH,
+        })
+    );
+}
@@ -6,9 +6,10 @@ use crate::{
     },
     sql::{
         parse::{ConversionError, QueryExpr, WhereExprBuilder},
-        proof_exprs::{ColumnExpr, DynProofExpr, LiteralExpr},
+        proof_exprs::{ColumnExpr, DynProofExpr, LiteralExpr, DEFAULT_MAX_EXPR_DEPTH},
     },
 };
+use alloc::{boxed::Box, string::ToString, vec};
 use bigdecimal::BigDecimal;
 use core::str::FromStr;
 use curve25519_dalek::RistrettoPoint;
@@ -148,7 +149,7 @@ fn we_can_directly_check_whether_bigint_columns_ge_int128() {
         .unwrap()
         .unwrap();
     let expected = DynProofExpr::try_new_inequality(
-        DynProofExpr::Column(ColumnExpr::new(ColumnRef::new(
+        DynProofExpr::Column(ColumnExpr::new(&ColumnRef::new(
             "sxt.sxt_tab".parse().unwrap(),
             ident("bigint_column"),
             ColumnType::BigInt,
@@ -170,7 +171,7 @@ fn we_can_directly_check_whether_bigint_columns_le_int128() {
         .unwrap()
         .unwrap();
     let expected = DynProofExpr::try_new_inequality(
-        DynProofExpr::Column(ColumnExpr::new(ColumnRef::new(
+        DynProofExpr::Column(ColumnExpr::new(&ColumnRef::new(
             "sxt.sxt_tab".parse().unwrap(),
             ident("bigint_column"),
             ColumnType::BigInt,
@@ -373,3 +374,60 @@ fn we_do_not_expect_an_error_while_trying_to_check_bigint_column_eq_decimal_with
     )
     .is_ok());
 }
+
+#[test]
+fn we_get_the_path_to_the_offending_operand_of_a_nested_expression() {
+    let column_mapping = get_column_mappings_for_testing();
+    let builder = WhereExprBuilder::new(&column_mapping);
+    let expr = and(
+        col("boolean_column"),
+        equal(
+            mul(
+                add(col("bigint_column"), col("varchar_column")),
+                col("int128_column"),
+            ),
+            lit(1_i64),
+        ),
+    );
+    let error = builder.build::<RistrettoPoint>(Some(expr)).unwrap_err();
+    assert_eq!(
+        error,
+        ConversionError::InOperand {
+            path: vec![
+                "right operand of And".to_string(),
+                "left operand of Equal".to_string(),
+                "left operand of Multiply".to_string(),
+            ],
+            source: Box::new(ConversionError::DataTypeMismatch {
+                left_type: ColumnType::BigInt.to_string(),
+                right_type: ColumnType::VarChar.to_string(),
+            }),
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "In right operand of And → left operand of Equal → left operand of Multiply: \
+         Left side has 'BIGINT' type but right side has 'VARCHAR' type"
+    );
+}
+
+#[test]
+fn we_cannot_build_an_expression_nested_deeper_than_the_maximum_depth() {
+    let column_mapping = get_column_mappings_for_testing();
+    // The equality and the column at the bottom of the sum add two levels
+    let sum = (0..DEFAULT_MAX_EXPR_DEPTH - 1).fold(col("bigint_column"), |sum, _| add(sum, lit(1)));
+    let expr_too_deep = equal(sum, lit(0));
+    assert_eq!(
+        WhereExprBuilder::new(&column_mapping).build::<RistrettoPoint>(Some(expr_too_deep)),
+        Err(ConversionError::PlanTooDeep {
+            depth: DEFAULT_MAX_EXPR_DEPTH + 1,
+            max_depth: DEFAULT_MAX_EXPR_DEPTH,
+        })
+    );
+
+    let sum = (0..DEFAULT_MAX_EXPR_DEPTH - 2).fold(col("bigint_column"), |sum, _| add(sum, lit(1)));
+    let expr_at_limit = equal(sum, lit(0));
+    assert!(WhereExprBuilder::new(&column_mapping)
+        .build::<RistrettoPoint>(Some(expr_at_limit))
+        .is_ok());
+}
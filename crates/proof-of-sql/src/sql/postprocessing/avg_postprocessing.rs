@@ -0,0 +1,118 @@
+use super::{PostprocessingError, PostprocessingResult, PostprocessingStep};
+use crate::base::{
+    database::{Column, ColumnType, OwnedColumn, OwnedColumnError, OwnedTable},
+    math::decimal::Precision,
+    scalar::Scalar,
+};
+use alloc::{string::ToString, vec::Vec};
+use bumpalo::Bump;
+use num_bigint::BigInt;
+use num_traits::Zero;
+use proof_of_sql_parser::Identifier;
+use serde::{Deserialize, Serialize};
+
+/// The minimum scale of the result of an [`AvgPostprocessing`].
+pub const AVG_MIN_SCALE: i8 = 6;
+
+/// An `AvgPostprocessing` appends the column `sum / count` to an `OwnedTable`, e.g. to compute
+/// `AVG(x)` from the proven `SUM(x)` and `COUNT(*)` of a group by.
+///
+/// The quotient is computed by the verifier from the verified sums and counts, so it is as
+/// trustworthy as those columns and needs no proof of its own.
+///
+/// For a sum of type `Decimal75(p, s)`, or an integer type with precision `p` and scale `s = 0`,
+/// the average is a `Decimal75` with scale `max(s, 6)` and precision `p - s + max(s, 6)`, capped at 75.
+/// The quotient is truncated toward zero at that scale. A table without rows, e.g. a group by whose
+/// rows were all filtered out, gets an empty average column.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AvgPostprocessing {
+    sum: Identifier,
+    count: Identifier,
+    alias: Identifier,
+}
+
+impl AvgPostprocessing {
+    /// Create a new `AvgPostprocessing` that appends `sum / count` as `alias`.
+    #[must_use]
+    pub fn new(sum: Identifier, count: Identifier, alias: Identifier) -> Self {
+        Self { sum, count, alias }
+    }
+}
+
+fn column<S: Scalar>(
+    owned_table: &OwnedTable<S>,
+    name: Identifier,
+) -> PostprocessingResult<&OwnedColumn<S>> {
+    owned_table
+        .inner_table()
+        .get(&name)
+        .ok_or(PostprocessingError::ColumnNotFound {
+            column: name.to_string(),
+        })
+}
+
+impl<S: Scalar> PostprocessingStep<S> for AvgPostprocessing {
+    /// Apply the average to the given `OwnedTable`.
+    fn apply(&self, owned_table: OwnedTable<S>) -> PostprocessingResult<OwnedTable<S>> {
+        let sum = column(&owned_table, self.sum)?;
+        let count = column(&owned_table, self.count)?;
+        let sum_type = sum.column_type();
+        if !sum_type.is_numeric() || sum_type == ColumnType::Scalar {
+            return Err(OwnedColumnError::TypeCastError {
+                from_type: sum_type,
+                to_type: ColumnType::Decimal75(
+                    Precision::new(75).expect("75 is a valid precision"),
+                    0,
+                ),
+            }
+            .into());
+        }
+        if !count.column_type().is_integer() {
+            return Err(OwnedColumnError::ColumnTypeMismatch {
+                expected: ColumnType::BigInt,
+                actual: count.column_type(),
+            }
+            .into());
+        }
+        let sum_precision = sum_type
+            .precision_value()
+            .expect("numeric types have precision");
+        let sum_scale = sum_type.scale().expect("numeric types have scale");
+        let scale = sum_scale.max(AVG_MIN_SCALE);
+        let precision =
+            (i16::from(sum_precision) - i16::from(sum_scale) + i16::from(scale)).clamp(1, 75);
+        let precision = Precision::new(u8::try_from(precision).expect("precision is at most 75"))
+            .expect("precision is between 1 and 75");
+        let scale_factor = BigInt::from(10).pow(u32::from((scale - sum_scale).unsigned_abs()));
+
+        let alloc = Bump::new();
+        let sums = Column::from_owned_column(sum, &alloc).to_scalar_with_scaling(0);
+        let counts = Column::from_owned_column(count, &alloc).to_scalar_with_scaling(0);
+        let averages = sums
+            .into_iter()
+            .zip(counts)
+            .enumerate()
+            .map(|(row, (sum, count))| {
+                let count: BigInt = count.into();
+                if count.is_zero() {
+                    return Err(PostprocessingError::ZeroCountInAverage { row });
+                }
+                let sum: BigInt = sum.into();
+                S::try_from(sum * &scale_factor / count).map_err(|_| {
+                    OwnedColumnError::DecimalValueExceedsPrecision {
+                        precision: precision.value(),
+                        index: row,
+                    }
+                    .into()
+                })
+            })
+            .collect::<PostprocessingResult<Vec<_>>>()?;
+        let average = OwnedColumn::try_new_decimal75(precision, scale, averages)?;
+
+        let mut table = owned_table.into_inner();
+        if table.insert(self.alias, average).is_some() {
+            return Err(OwnedColumnError::DuplicateColumn { column: self.alias }.into());
+        }
+        Ok(OwnedTable::try_new(table)?)
+    }
+}
@@ -0,0 +1,108 @@
+use crate::{
+    base::{
+        database::{owned_table_utility::*, ColumnType, OwnedColumnError, OwnedTable},
+        math::decimal::Precision,
+        scalar::Curve25519Scalar,
+    },
+    sql::postprocessing::{
+        apply_postprocessing_steps, test_utility::*, PostprocessingError, AVG_MIN_SCALE,
+    },
+};
+use alloc::string::ToString;
+use proof_of_sql_parser::Identifier;
+
+#[test]
+fn we_can_average_integer_sums() {
+    let table: OwnedTable<Curve25519Scalar> = owned_table([
+        bigint("a", [1, 2, 3]),
+        bigint("sum_c", [10, 7, -7]),
+        bigint("num_rows", [4, 3, 2]),
+    ]);
+    let expected = owned_table([
+        bigint("a", [1, 2, 3]),
+        bigint("sum_c", [10, 7, -7]),
+        bigint("num_rows", [4, 3, 2]),
+        decimal75("avg_c", 25, 6, [2_500_000, 2_333_333, -3_500_000]),
+    ]);
+    let actual = apply_postprocessing_steps(table, &[avg("sum_c", "num_rows", "avg_c")]).unwrap();
+    assert_eq!(actual, expected);
+    assert_eq!(AVG_MIN_SCALE, 6);
+}
+
+#[test]
+fn we_can_average_decimal_sums_and_keep_a_larger_scale() {
+    let table: OwnedTable<Curve25519Scalar> = owned_table([
+        decimal75("sum_c", 10, 8, [100_000_000, -5]),
+        int("num_rows", [3, 2]),
+    ]);
+    let expected = owned_table([
+        decimal75("sum_c", 10, 8, [100_000_000, -5]),
+        int("num_rows", [3, 2]),
+        decimal75("avg_c", 10, 8, [33_333_333, -2]),
+    ]);
+    let actual = apply_postprocessing_steps(table, &[avg("sum_c", "num_rows", "avg_c")]).unwrap();
+    assert_eq!(actual, expected);
+
+    let table: OwnedTable<Curve25519Scalar> =
+        owned_table([decimal75("sum_c", 5, 2, [1234]), bigint("num_rows", [4])]);
+    let actual = apply_postprocessing_steps(table, &[avg("sum_c", "num_rows", "avg_c")]).unwrap();
+    assert_eq!(
+        actual.inner_table()[&"avg_c".parse::<Identifier>().unwrap()],
+        decimal75::<Curve25519Scalar>("avg_c", 9, 6, [3_085_000]).1
+    );
+}
+
+#[test]
+fn we_can_average_an_empty_table() {
+    let table: OwnedTable<Curve25519Scalar> =
+        owned_table([bigint("sum_c", [0; 0]), bigint("num_rows", [0; 0])]);
+    let actual = apply_postprocessing_steps(table, &[avg("sum_c", "num_rows", "avg_c")]).unwrap();
+    assert_eq!(actual.num_rows(), 0);
+    assert_eq!(
+        actual.inner_table()[&"avg_c".parse::<Identifier>().unwrap()].column_type(),
+        ColumnType::Decimal75(Precision::new(25).unwrap(), 6)
+    );
+}
+
+#[test]
+fn we_cannot_average_over_a_zero_count() {
+    let table: OwnedTable<Curve25519Scalar> =
+        owned_table([bigint("sum_c", [1, 0]), bigint("num_rows", [1, 0])]);
+    assert_eq!(
+        apply_postprocessing_steps(table, &[avg("sum_c", "num_rows", "avg_c")]),
+        Err(PostprocessingError::ZeroCountInAverage { row: 1 })
+    );
+}
+
+#[test]
+fn we_cannot_average_missing_or_non_numeric_columns() {
+    let table: OwnedTable<Curve25519Scalar> = owned_table([
+        varchar("name", ["x"]),
+        bigint("sum_c", [1]),
+        bigint("num_rows", [1]),
+    ]);
+    assert_eq!(
+        apply_postprocessing_steps(table.clone(), &[avg("missing", "num_rows", "avg_c")]),
+        Err(PostprocessingError::ColumnNotFound {
+            column: "missing".to_string()
+        })
+    );
+    assert!(matches!(
+        apply_postprocessing_steps(table.clone(), &[avg("name", "num_rows", "avg_c")]),
+        Err(PostprocessingError::OwnedColumnError {
+            source: OwnedColumnError::TypeCastError { .. }
+        })
+    ));
+    assert!(matches!(
+        apply_postprocessing_steps(table.clone(), &[avg("sum_c", "name", "avg_c")]),
+        Err(PostprocessingError::OwnedColumnError {
+            source: OwnedColumnError::ColumnTypeMismatch { .. }
+        })
+    ));
+    assert!(matches!(
+        apply_postprocessing_steps(table, &[avg("sum_c", "num_rows", "name")]),
+        Err(PostprocessingError::OwnedColumnError {
+            source: OwnedColumnError::DuplicateColumn { .. }
+        })
+    ));
+}
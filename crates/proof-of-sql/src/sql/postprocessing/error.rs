@@ -47,6 +47,12 @@ pub enum PostprocessingError {
         /// The underlying source error
         source: crate::base::database::OwnedColumnError,
     },
+    /// An average is taken over a row whose count is zero
+    #[snafu(display("Average of row {row} has a count of zero"))]
+    ZeroCountInAverage {
+        /// The index of the row
+        row: usize,
+    },
     /// Nested aggregation in `GROUP BY` clause
     #[snafu(display("Nested aggregation in `GROUP BY` clause: {error}"))]
     NestedAggregationInGroupByClause {
@@ -10,6 +10,11 @@ pub use postprocessing_step::PostprocessingStep;
 #[cfg(test)]
 pub mod test_utility;
 
+mod avg_postprocessing;
+pub use avg_postprocessing::{AvgPostprocessing, AVG_MIN_SCALE};
+#[cfg(test)]
+mod avg_postprocessing_test;
+
 mod group_by_postprocessing;
 pub use group_by_postprocessing::GroupByPostprocessing;
 #[cfg(test)]
@@ -42,3 +42,12 @@ pub fn orders(cols: &[&str], directions: &[OrderByDirection]) -> OwnedTablePostp
         .collect();
     OwnedTablePostprocessing::new_order_by(OrderByPostprocessing::new(by_exprs))
 }
+
+#[must_use]
+pub fn avg(sum: &str, count: &str, alias: &str) -> OwnedTablePostprocessing {
+    OwnedTablePostprocessing::new_avg(AvgPostprocessing::new(
+        ident(sum),
+        ident(count),
+        ident(alias),
+    ))
+}
@@ -48,6 +48,9 @@ pub trait ProofPlan<C: Commitment>: Debug + Send + Sync + ProverEvaluate<C::Scal
     fn get_column_references(&self) -> IndexSet<ColumnRef>;
 
     /// Return all the tables referenced in the Query
+    ///
+    /// This includes the tables of all the columns referenced anywhere in the plan,
+    /// and each table is returned once, even if it is referenced more than once.
     fn get_table_references(&self) -> IndexSet<TableRef>;
 }
 
@@ -1,8 +1,12 @@
-use super::{decode_and_convert, decode_multiple_elements, ProvableResultColumn, QueryError};
+use super::{
+    decode_and_convert, decode_multiple_elements, ProofPlan, ProvableResultColumn,
+    ProvableResultElement, QueryError,
+};
 use crate::base::{
+    commitment::Commitment,
     database::{Column, ColumnField, ColumnType, OwnedColumn, OwnedTable},
     polynomial::compute_evaluation_vector,
-    scalar::Scalar,
+    scalar::{Scalar, ScalarExt},
 };
 use alloc::{vec, vec::Vec};
 use num_traits::Zero;
@@ -121,6 +125,8 @@ impl ProvableQueryResult {
                     }
 
                     ColumnType::VarChar => decode_and_convert::<&str, S>(&self.data[offset..]),
+                    ColumnType::VarBinary => <&[u8]>::decode(&self.data[offset..])
+                        .map(|(x, sz)| (S::from_byte_slice_via_hash(x), sz)),
                     ColumnType::TimestampTZ(_, _) => {
                         decode_and_convert::<i64, S>(&self.data[offset..])
                     }
@@ -137,13 +143,23 @@ impl ProvableQueryResult {
         Ok(res)
     }
 
-    #[allow(
-        clippy::missing_panics_doc,
-        reason = "Assertions ensure preconditions are met, eliminating the possibility of panic."
-    )]
+    /// Convert the intermediate query result of `plan` into a final query result
+    ///
+    /// This is [`Self::to_owned_table`] with the fields taken from
+    /// [`ProofPlan::get_column_result_fields`], so the caller does not have to build them.
+    pub fn to_owned_table_for_plan<C: Commitment>(
+        &self,
+        plan: &impl ProofPlan<C>,
+    ) -> Result<OwnedTable<C::Scalar>, QueryError> {
+        self.to_owned_table(&plan.get_column_result_fields())
+    }
+
     /// Convert the intermediate query result into a final query result
     ///
-    /// The result is essentially an `OwnedTable` type.
+    /// The result is essentially an `OwnedTable` type. The schema of the table is taken
+    /// entirely from `column_result_fields`, so it is the same regardless of the number of rows.
+    /// Because the encoded data is untrusted, any malformed or trailing data is reported as an
+    /// error rather than a panic.
     pub fn to_owned_table<S: Scalar>(
         &self,
         column_result_fields: &[ColumnField],
@@ -194,6 +210,11 @@ impl ProvableQueryResult {
                         offset += num_read;
                         Ok((field.name(), OwnedColumn::VarChar(col)))
                     }
+                    ColumnType::VarBinary => {
+                        let (col, num_read) = decode_multiple_elements(&self.data[offset..], n)?;
+                        offset += num_read;
+                        Ok((field.name(), OwnedColumn::VarBinary(col)))
+                    }
                     ColumnType::Scalar => {
                         let (col, num_read) = decode_multiple_elements(&self.data[offset..], n)?;
                         offset += num_read;
@@ -213,8 +234,12 @@ impl ProvableQueryResult {
                 .collect::<Result<_, QueryError>>()?,
         )?;
 
-        assert_eq!(offset, self.data.len());
-        assert_eq!(owned_table.num_columns(), self.num_columns());
+        if offset != self.data.len() {
+            return Err(QueryError::MiscellaneousDecodingError);
+        }
+        if owned_table.num_columns() != self.num_columns() {
+            return Err(QueryError::InvalidColumnCount);
+        }
 
         Ok(owned_table)
     }
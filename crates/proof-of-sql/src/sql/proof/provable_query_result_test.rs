@@ -197,6 +197,18 @@ fn evaluation_fails_if_extra_data_is_included() {
     ));
 }
 
+#[test]
+fn conversion_fails_if_extra_data_is_included_in_an_empty_result() {
+    let cols: [Column<Curve25519Scalar>; 1] = [Column::BigInt(&[0_i64; 0])];
+    let mut res = ProvableQueryResult::new(0, &cols);
+    res.data_mut().push(3u8);
+    let column_fields = vec![ColumnField::new("a".parse().unwrap(), ColumnType::BigInt)];
+    assert!(matches!(
+        res.to_owned_table::<Curve25519Scalar>(&column_fields),
+        Err(QueryError::MiscellaneousDecodingError)
+    ));
+}
+
 #[test]
 fn evaluation_fails_if_the_result_cant_be_decoded() {
     let mut res = ProvableQueryResult::new_from_raw_data(1, 1, vec![0b1111_1111_u8; 38]);
@@ -1,6 +1,6 @@
 use super::{
-    CountBuilder, FinalRoundBuilder, ProofCounts, ProofPlan, ProvableQueryResult, QueryResult,
-    SumcheckMleEvaluations, SumcheckRandomScalars, VerificationBuilder,
+    CountBuilder, FinalRoundBuilder, ProofCounts, ProofPlan, ProvableQueryResult, QueryError,
+    QueryResult, SumcheckMleEvaluations, SumcheckRandomScalars, VerificationBuilder, VerifierStats,
 };
 use crate::{
     base::{
@@ -10,6 +10,7 @@ use crate::{
         math::log2_up,
         polynomial::{compute_evaluation_vector, CompositePolynomialInfo},
         proof::{Keccak256Transcript, ProofError, Transcript},
+        scalar::zeroize_scalars,
     },
     proof_primitive::sumcheck::SumcheckProof,
     sql::proof::{FirstRoundBuilder, QueryData},
@@ -41,12 +42,35 @@ pub struct QueryProof<CP: CommitmentEvaluationProof> {
 
 impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
     /// Create a new `QueryProof`.
-    #[tracing::instrument(name = "QueryProof::new", level = "debug", skip_all)]
     pub fn new(
         expr: &(impl ProofPlan<CP::Commitment> + Serialize),
         accessor: &impl DataAccessor<CP::Scalar>,
         setup: &CP::ProverPublicSetup<'_>,
     ) -> (Self, ProvableQueryResult) {
+        match Self::try_new_with_max_output_rows(expr, accessor, setup, None) {
+            Ok(proof_and_result) => proof_and_result,
+            Err(_) => unreachable!("an uncapped result can not be too large"),
+        }
+    }
+
+    /// Create a new `QueryProof`, refusing to prove results with more than `max_output_rows` rows.
+    ///
+    /// The result is evaluated first, and no proof is generated if it is too large.
+    /// This is a safety guard for the prover, not a query semantic like `LIMIT`,
+    /// which is applied to the verified result afterwards. `None` means no cap.
+    ///
+    /// # Errors
+    /// Returns [`ProofError::ResultTooLarge`] if the result has more than `max_output_rows` rows.
+    #[tracing::instrument(name = "QueryProof::new", level = "debug", skip_all)]
+    pub fn try_new_with_max_output_rows(
+        expr: &(impl ProofPlan<CP::Commitment> + Serialize),
+        accessor: &impl DataAccessor<CP::Scalar>,
+        setup: &CP::ProverPublicSetup<'_>,
+        max_output_rows: Option<usize>,
+    ) -> Result<(Self, ProvableQueryResult), ProofError> {
+        let column_refs: Vec<_> = expr.get_column_references().into_iter().collect();
+        accessor.prefetch(&column_refs);
+
         let table_length = expr.get_length(accessor);
         let num_sumcheck_variables = cmp::max(log2_up(table_length), 1);
         let generator_offset = expr.get_offset(accessor);
@@ -57,6 +81,14 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
         // Evaluate query result
         let result_cols = expr.result_evaluate(table_length, &alloc, accessor);
         let output_length = result_cols.first().map_or(0, Column::len);
+        if let Some(max_output_rows) = max_output_rows {
+            if output_length > max_output_rows {
+                return Err(ProofError::ResultTooLarge {
+                    num_rows: output_length,
+                    max_output_rows,
+                });
+            }
+        }
         let provable_result = ProvableQueryResult::new(output_length as u64, &result_cols);
 
         // Prover First Round
@@ -120,7 +152,8 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
             core::iter::repeat_with(|| transcript.scalar_challenge_as_be())
                 .take(pcs_proof_evaluations.len())
                 .collect();
-        let folded_mle = builder.fold_pcs_proof_mles(&random_scalars);
+        // the folded MLE is a combination of witness columns, so it is cleared once the proof is created
+        let mut folded_mle = builder.fold_pcs_proof_mles(&random_scalars);
 
         // finally, form the inner product proof of the MLEs' evaluations
         let evaluation_proof = CP::new(
@@ -130,6 +163,7 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
             generator_offset as u64,
             setup,
         );
+        zeroize_scalars(&mut folded_mle);
 
         let proof = Self {
             bit_distributions: builder.bit_distributions().to_vec(),
@@ -138,7 +172,7 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
             pcs_proof_evaluations,
             evaluation_proof,
         };
-        (proof, provable_result)
+        Ok((proof, provable_result))
     }
 
     #[tracing::instrument(name = "QueryProof::verify", level = "debug", skip_all, err)]
@@ -150,34 +184,30 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
         result: &ProvableQueryResult,
         setup: &CP::VerifierPublicSetup<'_>,
     ) -> QueryResult<CP::Scalar> {
+        self.verify_with_stats(expr, accessor, result, setup)
+            .map(|(data, _)| data)
+    }
+
+    #[tracing::instrument(name = "QueryProof::verify_with_stats", level = "debug", skip_all, err)]
+    /// Verify a `QueryProof` and count the group operations of the verifier.
+    /// Note: This does NOT transform the result!
+    ///
+    /// # Errors
+    /// Returns an error if the verification fails, exactly as [`Self::verify`].
+    pub fn verify_with_stats(
+        &self,
+        expr: &(impl ProofPlan<CP::Commitment> + Serialize),
+        accessor: &impl CommitmentAccessor<CP::Commitment>,
+        result: &ProvableQueryResult,
+        setup: &CP::VerifierPublicSetup<'_>,
+    ) -> Result<(QueryData<CP::Scalar>, VerifierStats), QueryError> {
         let input_length = expr.get_length(accessor);
         let output_length = result.table_length();
         let generator_offset = expr.get_offset(accessor);
         let num_sumcheck_variables = cmp::max(log2_up(input_length), 1);
         assert!(num_sumcheck_variables > 0);
 
-        // validate bit decompositions
-        for dist in &self.bit_distributions {
-            if !dist.is_valid() {
-                Err(ProofError::VerificationError {
-                    error: "invalid bit distributions",
-                })?;
-            }
-        }
-
-        // count terms
-        let counts = {
-            let mut builder = CountBuilder::new(&self.bit_distributions);
-            expr.count(&mut builder, accessor)?;
-            builder.counts()
-        }?;
-
-        // verify sizes
-        if !self.validate_sizes(&counts) {
-            Err(ProofError::VerificationError {
-                error: "invalid proof size",
-            })?;
-        }
+        let counts = self.validate_counts(expr, accessor)?;
 
         // construct a transcript for the proof
         let mut transcript: Keccak256Transcript =
@@ -288,10 +318,49 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
             })?;
 
         let verification_hash = transcript.challenge_as_le();
-        Ok(QueryData {
-            table: owned_table_result,
-            verification_hash,
-        })
+        let msm_size = builder.pcs_proof_commitments().len();
+        Ok((
+            QueryData {
+                table: owned_table_result,
+                verification_hash,
+            },
+            VerifierStats {
+                msm_size,
+                sumcheck_rounds: num_sumcheck_variables,
+            },
+        ))
+    }
+
+    /// Count the terms of the plan, checking that the bit distributions are valid and that the
+    /// proof has the right size for them.
+    fn validate_counts(
+        &self,
+        expr: &impl ProofPlan<CP::Commitment>,
+        accessor: &impl CommitmentAccessor<CP::Commitment>,
+    ) -> Result<ProofCounts, QueryError> {
+        // validate bit decompositions
+        for dist in &self.bit_distributions {
+            if !dist.is_valid() {
+                Err(ProofError::VerificationError {
+                    error: "invalid bit distributions",
+                })?;
+            }
+        }
+
+        // count terms
+        let counts = {
+            let mut builder = CountBuilder::new(&self.bit_distributions);
+            expr.count(&mut builder, accessor)?;
+            builder.counts()
+        }?;
+
+        // verify sizes
+        if !self.validate_sizes(&counts) {
+            Err(ProofError::VerificationError {
+                error: "invalid proof size",
+            })?;
+        }
+        Ok(counts)
     }
 
     fn validate_sizes(&self, counts: &ProofCounts) -> bool {
@@ -14,9 +14,14 @@ use crate::{
         proof::ProofError,
         scalar::{Curve25519Scalar, Scalar},
     },
-    sql::proof::{FirstRoundBuilder, QueryData, SumcheckSubpolynomialType},
+    sql::{
+        proof::{FirstRoundBuilder, QueryData, SumcheckSubpolynomialType},
+        proof_exprs::test_utility::{cols_expr_plan, column, const_bigint, equal, tab},
+        proof_plans::test_utility::filter,
+    },
 };
 use bumpalo::Bump;
+use core::cell::RefCell;
 use serde::Serialize;
 
 /// Type to allow us to prove and verify an artificial polynomial where we prove
@@ -107,7 +112,7 @@ impl<C: Commitment> ProofPlan<C> for TrivialTestProofPlan {
         vec![ColumnField::new("a1".parse().unwrap(), ColumnType::BigInt)]
     }
     fn get_column_references(&self) -> IndexSet<ColumnRef> {
-        unimplemented!("no real usage for this function yet")
+        IndexSet::default()
     }
     fn get_table_references(&self) -> IndexSet<TableRef> {
         unimplemented!("no real usage for this function yet")
@@ -279,7 +284,11 @@ impl<C: Commitment> ProofPlan<C> for SquareTestProofPlan {
         vec![ColumnField::new("a1".parse().unwrap(), ColumnType::BigInt)]
     }
     fn get_column_references(&self) -> IndexSet<ColumnRef> {
-        unimplemented!("no real usage for this function yet")
+        IndexSet::from_iter([ColumnRef::new(
+            "sxt.test".parse().unwrap(),
+            "x".parse().unwrap(),
+            ColumnType::BigInt,
+        )])
     }
     fn get_table_references(&self) -> IndexSet<TableRef> {
         unimplemented!("no real usage for this function yet")
@@ -485,7 +494,11 @@ impl<C: Commitment> ProofPlan<C> for DoubleSquareTestProofPlan {
         vec![ColumnField::new("a1".parse().unwrap(), ColumnType::BigInt)]
     }
     fn get_column_references(&self) -> IndexSet<ColumnRef> {
-        unimplemented!("no real usage for this function yet")
+        IndexSet::from_iter([ColumnRef::new(
+            "sxt.test".parse().unwrap(),
+            "x".parse().unwrap(),
+            ColumnType::BigInt,
+        )])
     }
     fn get_table_references(&self) -> IndexSet<TableRef> {
         unimplemented!("no real usage for this function yet")
@@ -684,7 +697,11 @@ impl<C: Commitment> ProofPlan<C> for ChallengeTestProofPlan {
         vec![ColumnField::new("a1".parse().unwrap(), ColumnType::BigInt)]
     }
     fn get_column_references(&self) -> IndexSet<ColumnRef> {
-        unimplemented!("no real usage for this function yet")
+        IndexSet::from_iter([ColumnRef::new(
+            "sxt.test".parse().unwrap(),
+            "x".parse().unwrap(),
+            ColumnType::BigInt,
+        )])
     }
     fn get_table_references(&self) -> IndexSet<TableRef> {
         unimplemented!("no real usage for this function yet")
@@ -731,3 +748,72 @@ fn we_can_verify_a_proof_with_a_post_result_challenge_and_with_a_zero_offset() {
 fn we_can_verify_a_proof_with_a_post_result_challenge_and_with_a_non_zero_offset() {
     verify_a_proof_with_a_post_result_challenge_and_given_offset(123);
 }
+
+/// Accessor that simulates a remote store by recording every call it receives
+struct RemoteTestAccessor<'a> {
+    accessor: &'a OwnedTableTestAccessor<'a, InnerProductProof>,
+    /// The columns of each `prefetch` call
+    prefetches: RefCell<Vec<Vec<ColumnRef>>>,
+    /// The column of each `get_column` call, with the number of `prefetch` calls made before it
+    fetches: RefCell<Vec<(ColumnRef, usize)>>,
+}
+
+impl MetadataAccessor for RemoteTestAccessor<'_> {
+    fn get_length(&self, table_ref: TableRef) -> usize {
+        self.accessor.get_length(table_ref)
+    }
+
+    fn get_offset(&self, table_ref: TableRef) -> usize {
+        self.accessor.get_offset(table_ref)
+    }
+}
+
+impl DataAccessor<Curve25519Scalar> for RemoteTestAccessor<'_> {
+    fn get_column(&self, column: ColumnRef) -> Column<Curve25519Scalar> {
+        let prefetch_count = self.prefetches.borrow().len();
+        self.fetches.borrow_mut().push((column, prefetch_count));
+        self.accessor.get_column(column)
+    }
+
+    fn prefetch(&self, column_refs: &[ColumnRef]) {
+        self.prefetches.borrow_mut().push(column_refs.to_vec());
+    }
+}
+
+#[test]
+fn the_prover_prefetches_all_referenced_columns_once_before_evaluation() {
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(
+        t,
+        owned_table([
+            bigint("a", [1, 2, 3]),
+            bigint("b", [4, 5, 6]),
+            bigint("c", [1, 0, 1]),
+        ]),
+        0,
+    );
+    let expr = filter(
+        cols_expr_plan(t, &["a", "b"], &accessor),
+        tab(t),
+        equal(column(t, "c", &accessor), const_bigint(1)),
+    );
+    let remote_accessor = RemoteTestAccessor {
+        accessor: &accessor,
+        prefetches: RefCell::new(Vec::new()),
+        fetches: RefCell::new(Vec::new()),
+    };
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &remote_accessor, &());
+    assert!(proof.verify(&expr, &accessor, &result, &()).is_ok());
+
+    let prefetches = remote_accessor.prefetches.into_inner();
+    assert_eq!(prefetches.len(), 1);
+    let prefetched_columns: IndexSet<ColumnRef> = prefetches[0].iter().copied().collect();
+    assert_eq!(prefetched_columns, expr.get_column_references());
+    let fetches = remote_accessor.fetches.into_inner();
+    assert!(!fetches.is_empty());
+    for (column, prefetch_count) in fetches {
+        assert_eq!(prefetch_count, 1);
+        assert!(prefetched_columns.contains(&column));
+    }
+}
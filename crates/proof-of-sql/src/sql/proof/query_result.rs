@@ -3,6 +3,7 @@ use crate::base::{
     proof::ProofError,
     scalar::Scalar,
 };
+use proof_of_sql_parser::Identifier;
 use snafu::Snafu;
 
 /// Verifiable query errors
@@ -37,6 +38,12 @@ pub enum QueryError {
     /// The number of columns in the table was invalid.
     #[snafu(display("Invalid number of columns"))]
     InvalidColumnCount,
+    /// A column that is not in the query result was requested.
+    #[snafu(display("Column {column} is not in the query result"))]
+    MissingResultColumn {
+        /// The requested column
+        column: Identifier,
+    },
 }
 
 /// The verified results of a query along with metadata produced by verification
@@ -52,5 +59,26 @@ pub struct QueryData<S: Scalar> {
     pub verification_hash: [u8; 32],
 }
 
+/// Counts of the group operations done by the verifier of a query, see
+/// [`VerifiableQueryResult::verify_with_stats`](super::VerifiableQueryResult::verify_with_stats).
+///
+/// The counts only depend on the query plan and the proof, so verifying the same proof twice
+/// reports the same counts.
+///
+/// There is no count of all the scalar multiplications done by the verifier. Outside of the
+/// evaluation proof, the only group operations are the batching of the commitments, counted by
+/// [`Self::msm_size`]. The group operations of
+/// [`CommitmentEvaluationProof::verify_batched_proof`](crate::base::commitment::CommitmentEvaluationProof::verify_batched_proof)
+/// (e.g. inner product rounds or pairings) depend on the commitment scheme and are done inside it,
+/// so they are not counted.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct VerifierStats {
+    /// The number of commitments in the multi-scalar multiplication that batches them,
+    /// which is also the number of scalar multiplications of the batching
+    pub msm_size: usize,
+    /// The number of sumcheck rounds
+    pub sumcheck_rounds: usize,
+}
+
 /// The result of a query -- either an error or a table.
 pub type QueryResult<S> = Result<QueryData<S>, QueryError>;
@@ -96,6 +96,19 @@ impl ProvableResultElement<'_> for String {
     }
 }
 
+/// Implement encode for byte vectors
+impl ProvableResultElement<'_> for Vec<u8> {
+    fn required_bytes(&self) -> usize {
+        self.as_slice().required_bytes()
+    }
+    fn encode(&self, out: &mut [u8]) -> usize {
+        self.as_slice().encode(out)
+    }
+    fn decode(data: &[u8]) -> Result<(Self, usize), QueryError> {
+        decode_and_convert::<&[u8], Vec<u8>>(data)
+    }
+}
+
 pub fn decode_and_convert<'a, F, T>(data: &'a [u8]) -> Result<(T, usize), QueryError>
 where
     F: ProvableResultElement<'a>,
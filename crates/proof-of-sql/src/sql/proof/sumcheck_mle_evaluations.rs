@@ -15,6 +15,8 @@ pub struct SumcheckMleEvaluations<'a, S: Scalar> {
     pub output_length: usize,
     /// The number of sumcheck variables.
     pub num_sumcheck_variables: usize,
+    /// The random point generated by sumcheck, at which all the MLEs are evaluated.
+    pub evaluation_point: &'a [S],
     /// The evaluation (at the random point generated by sumcheck) of an MLE `{x_i}` where
     ///     `x_i = 1` if `i < input_length;`
     ///         = 0, otherwise
@@ -48,7 +50,7 @@ impl<'a, S: Scalar> SumcheckMleEvaluations<'a, S> {
     pub fn new(
         input_length: usize,
         output_length: usize,
-        evaluation_point: &[S],
+        evaluation_point: &'a [S],
         sumcheck_random_scalars: &SumcheckRandomScalars<S>,
         pcs_proof_evaluations: &'a [S],
     ) -> Self {
@@ -70,6 +72,7 @@ impl<'a, S: Scalar> SumcheckMleEvaluations<'a, S> {
             input_length,
             output_length,
             num_sumcheck_variables: evaluation_point.len(),
+            evaluation_point,
             input_one_evaluation,
             output_one_evaluation,
             random_evaluation,
@@ -1,13 +1,14 @@
-use super::{ProofPlan, ProvableQueryResult, QueryData, QueryProof, QueryResult};
+use super::{
+    ProofPlan, ProvableQueryResult, QueryData, QueryError, QueryProof, QueryResult, VerifierStats,
+};
 use crate::base::{
-    commitment::CommitmentEvaluationProof,
-    database::{
-        ColumnField, ColumnType, CommitmentAccessor, DataAccessor, OwnedColumn, OwnedTable,
-    },
+    commitment::{Commitment, CommitmentEvaluationProof},
+    database::{ColumnField, CommitmentAccessor, DataAccessor, OwnedTable, SchemaAccessor},
     proof::ProofError,
     scalar::Scalar,
 };
-use alloc::vec;
+use alloc::{boxed::Box, vec::Vec};
+use proof_of_sql_parser::Identifier;
 use serde::{Deserialize, Serialize};
 
 /// The result of an sql query along with a proof that the query is valid. The
@@ -84,22 +85,61 @@ impl<CP: CommitmentEvaluationProof> VerifiableQueryResult<CP> {
         accessor: &impl DataAccessor<CP::Scalar>,
         setup: &CP::ProverPublicSetup<'_>,
     ) -> Self {
+        match Self::try_new_with_max_output_rows(expr, accessor, setup, None) {
+            Ok(res) => res,
+            Err(_) => unreachable!("an uncapped result can not be too large"),
+        }
+    }
+
+    /// Form a `VerifiableQueryResult` from a query expression,
+    /// refusing to prove results with more than `max_output_rows` rows.
+    ///
+    /// This protects the prover from accidentally proving an enormous result.
+    /// It is unrelated to `LIMIT`, which is applied to the verified result during postprocessing.
+    /// `None` means no cap, which is what [`VerifiableQueryResult::new`] uses.
+    ///
+    /// # Errors
+    /// Returns `ProofError::ResultTooLarge` before generating any proof
+    /// if the result has more than `max_output_rows` rows.
+    pub fn try_new_with_max_output_rows(
+        expr: &(impl ProofPlan<CP::Commitment> + Serialize),
+        accessor: &impl DataAccessor<CP::Scalar>,
+        setup: &CP::ProverPublicSetup<'_>,
+        max_output_rows: Option<usize>,
+    ) -> Result<Self, ProofError> {
         // a query must have at least one result column; if not, it should
         // have been rejected at the parsing stage.
 
         // handle the empty case
         if expr.is_empty(accessor) {
-            return VerifiableQueryResult {
+            return Ok(VerifiableQueryResult {
                 provable_result: None,
                 proof: None,
-            };
+            });
         }
 
-        let (proof, res) = QueryProof::new(expr, accessor, setup);
-        Self {
+        let (proof, res) =
+            QueryProof::try_new_with_max_output_rows(expr, accessor, setup, max_output_rows)?;
+        Ok(Self {
             provable_result: Some(res),
             proof: Some(proof),
-        }
+        })
+    }
+
+    /// Serialize the `VerifiableQueryResult` into bytes.
+    ///
+    /// The serialization is deterministic: the same result and proof always produce the same bytes,
+    /// so two results are equal exactly when their bytes are equal.
+    /// Since proofs are generated deterministically from the plan, data and setup,
+    /// proving the same plan over the same data twice yields identical bytes.
+    ///
+    /// # Panics
+    /// Panics if the proof can not be serialized, which should never happen.
+    pub fn to_bytes(&self) -> Vec<u8>
+    where
+        CP: Serialize,
+    {
+        postcard::to_allocvec(self).expect("VerifiableQueryResult should always be serializable")
     }
 
     /// Verify a `VerifiableQueryResult`. Upon success, this function returns the finalized form of
@@ -109,6 +149,19 @@ impl<CP: CommitmentEvaluationProof> VerifiableQueryResult<CP> {
     /// error.
     ///
     /// Note: This does NOT transform the result!4
+    ///
+    /// Note: the schema of the returned table is always the one declared by `expr` (see
+    /// [`ProofPlan::get_column_result_fields`]), even when no rows are returned.
+    ///
+    /// Note: the types of the columns referenced by `expr` are checked against the schema of `accessor`
+    /// before the proof is. Without this check, a column whose type in `accessor` differs from the type
+    /// expected by `expr` would only surface as a confusing commitment mismatch.
+    ///
+    /// # Errors
+    /// Returns `ProofError::ColumnTypeMismatch` if `accessor` reports a different type for a column
+    /// than `expr` expects, `ProofError::UnknownColumn` if `accessor` does not know a column
+    /// referenced by `expr`, and an error if the proof fails to verify.
+    ///
     /// # Panics
     /// - Panics if:
     ///   - `self.provable_result` is `None` but `self.proof` is `Some()`, or vice versa.
@@ -117,12 +170,34 @@ impl<CP: CommitmentEvaluationProof> VerifiableQueryResult<CP> {
     pub fn verify(
         &self,
         expr: &(impl ProofPlan<CP::Commitment> + Serialize),
-        accessor: &impl CommitmentAccessor<CP::Commitment>,
+        accessor: &(impl CommitmentAccessor<CP::Commitment> + SchemaAccessor),
         setup: &CP::VerifierPublicSetup<'_>,
     ) -> QueryResult<CP::Scalar> {
+        self.verify_with_stats(expr, accessor, setup)
+            .map(|(data, _)| data)
+    }
+
+    /// Verify a `VerifiableQueryResult` exactly as [`Self::verify`],
+    /// and also return counts of the group operations done by the verifier.
+    ///
+    /// A query on an empty table has no proof and reports zero operations.
+    ///
+    /// # Errors
+    /// Returns any error of [`Self::verify`].
+    ///
+    /// # Panics
+    /// Panics in the same cases as [`Self::verify`].
+    pub fn verify_with_stats(
+        &self,
+        expr: &(impl ProofPlan<CP::Commitment> + Serialize),
+        accessor: &(impl CommitmentAccessor<CP::Commitment> + SchemaAccessor),
+        setup: &CP::VerifierPublicSetup<'_>,
+    ) -> Result<(QueryData<CP::Scalar>, VerifierStats), QueryError> {
         // a query must have at least one result column; if not, it should
         // have been rejected at the parsing stage.
 
+        check_column_types(expr, accessor)?;
+
         // handle the empty case
         if expr.is_empty(accessor) {
             if self.provable_result.is_some() || self.proof.is_some() {
@@ -133,7 +208,10 @@ impl<CP: CommitmentEvaluationProof> VerifiableQueryResult<CP> {
 
             let result_fields = expr.get_column_result_fields();
 
-            return make_empty_query_result(&result_fields);
+            return Ok((
+                make_empty_query_result(&result_fields)?,
+                VerifierStats::default(),
+            ));
         }
 
         if self.provable_result.is_none() || self.proof.is_none() {
@@ -141,42 +219,93 @@ impl<CP: CommitmentEvaluationProof> VerifiableQueryResult<CP> {
                 error: "non-zero sumcheck variables but empty result",
             })?;
         }
-        self.proof.as_ref().unwrap().verify(
+        self.proof.as_ref().unwrap().verify_with_stats(
             expr,
             accessor,
             self.provable_result.as_ref().unwrap(),
             setup,
         )
     }
+
+    /// Verify a `VerifiableQueryResult` and return only the requested result columns.
+    ///
+    /// The whole proof is verified exactly as in [`Self::verify`], which needs every result column.
+    /// The columns that were not requested are then dropped
+    /// and the returned table has the `wanted` columns in the given order.
+    /// A column requested more than once is only returned once.
+    ///
+    /// # Errors
+    /// Returns any error of [`Self::verify`],
+    /// and [`QueryError::MissingResultColumn`] if a `wanted` column is not in the result.
+    pub fn verify_columns(
+        &self,
+        expr: &(impl ProofPlan<CP::Commitment> + Serialize),
+        accessor: &(impl CommitmentAccessor<CP::Commitment> + SchemaAccessor),
+        setup: &CP::VerifierPublicSetup<'_>,
+        wanted: &[Identifier],
+    ) -> QueryResult<CP::Scalar> {
+        let QueryData {
+            table,
+            verification_hash,
+        } = self.verify(expr, accessor, setup)?;
+        if let Some(&column) = wanted
+            .iter()
+            .find(|column| !table.inner_table().contains_key(*column))
+        {
+            return Err(QueryError::MissingResultColumn { column });
+        }
+        let mut columns = table.into_inner();
+        let table = OwnedTable::try_from_iter(
+            wanted
+                .iter()
+                .filter_map(|column| columns.swap_remove(column).map(|values| (*column, values))),
+        )?;
+        Ok(QueryData {
+            table,
+            verification_hash,
+        })
+    }
+}
+
+/// Check that the accessor reports the same type for every column referenced by `expr` as the plan expects.
+fn check_column_types<C: Commitment>(
+    expr: &impl ProofPlan<C>,
+    accessor: &impl SchemaAccessor,
+) -> Result<(), ProofError> {
+    for column in expr.get_column_references() {
+        match accessor.lookup_column(column.table_ref(), column.column_id()) {
+            None => {
+                return Err(ProofError::UnknownColumn {
+                    column: Box::new(column),
+                })
+            }
+            Some(actual) if actual != *column.column_type() => {
+                return Err(ProofError::ColumnTypeMismatch {
+                    expected: *column.column_type(),
+                    column: Box::new(column),
+                    actual,
+                });
+            }
+            Some(_) => {}
+        }
+    }
+    Ok(())
 }
 
 fn make_empty_query_result<S: Scalar>(result_fields: &[ColumnField]) -> QueryResult<S> {
-    let table = OwnedTable::try_new(
-        result_fields
-            .iter()
-            .map(|field| {
-                (
-                    field.name(),
-                    match field.data_type() {
-                        ColumnType::Boolean => OwnedColumn::Boolean(vec![]),
-                        ColumnType::TinyInt => OwnedColumn::TinyInt(vec![]),
-                        ColumnType::SmallInt => OwnedColumn::SmallInt(vec![]),
-                        ColumnType::Int => OwnedColumn::Int(vec![]),
-                        ColumnType::BigInt => OwnedColumn::BigInt(vec![]),
-                        ColumnType::Int128 => OwnedColumn::Int128(vec![]),
-                        ColumnType::Decimal75(precision, scale) => {
-                            OwnedColumn::Decimal75(precision, scale, vec![])
-                        }
-                        ColumnType::Scalar => OwnedColumn::Scalar(vec![]),
-                        ColumnType::VarChar => OwnedColumn::VarChar(vec![]),
-                        ColumnType::TimestampTZ(tu, tz) => OwnedColumn::TimestampTZ(tu, tz, vec![]),
-                    },
-                )
-            })
-            .collect(),
-    )?;
     Ok(QueryData {
-        table,
+        table: OwnedTable::empty_with_schema(result_fields)?,
         verification_hash: Default::default(),
     })
 }
+
+/// Two results are equal when they serialize to the same bytes, see [`VerifiableQueryResult::to_bytes`].
+///
+/// This does not require the evaluation proof itself to implement [`PartialEq`].
+impl<CP: CommitmentEvaluationProof + Serialize> PartialEq for VerifiableQueryResult<CP> {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_bytes() == other.to_bytes()
+    }
+}
+
+impl<CP: CommitmentEvaluationProof + Serialize> Eq for VerifiableQueryResult<CP> {}
@@ -6,17 +6,24 @@ use crate::{
     base::{
         commitment::{Commitment, InnerProductProof},
         database::{
-            owned_table_utility::{bigint, owned_table},
+            owned_table_utility::{bigint, owned_table, varchar},
             Column, ColumnField, ColumnRef, ColumnType, CommitmentAccessor, DataAccessor,
-            MetadataAccessor, OwnedTable, TableRef, TestAccessor, UnimplementedTestAccessor,
+            MetadataAccessor, OwnedTable, OwnedTableTestAccessor, TableRef, TestAccessor,
+            UnimplementedTestAccessor,
         },
         map::IndexSet,
         proof::ProofError,
         scalar::Scalar,
     },
-    sql::proof::{FirstRoundBuilder, ProvableQueryResult, QueryData},
+    sql::{
+        postprocessing::{apply_postprocessing_steps, test_utility::slice},
+        proof::{FirstRoundBuilder, ProvableQueryResult, QueryData, QueryError, VerifierStats},
+        proof_exprs::test_utility::*,
+        proof_plans::{test_utility::*, DynProofPlan},
+    },
 };
 use bumpalo::Bump;
+use curve25519_dalek::RistrettoPoint;
 use serde::Serialize;
 
 #[derive(Debug, Serialize, Default)]
@@ -86,7 +93,7 @@ impl<C: Commitment> ProofPlan<C> for EmptyTestQueryExpr {
     }
 
     fn get_column_references(&self) -> IndexSet<ColumnRef> {
-        unimplemented!("no real usage for this function yet")
+        IndexSet::default()
     }
 
     fn get_table_references(&self) -> IndexSet<TableRef> {
@@ -123,3 +130,245 @@ fn empty_verification_fails_if_the_result_contains_non_null_members() {
     };
     assert!(res.verify(&expr, &accessor, &()).is_err());
 }
+
+#[test]
+fn proving_the_same_plan_over_the_same_data_twice_gives_equal_results() {
+    let data = owned_table([
+        bigint("a", [1, 2, 3, 2]),
+        varchar("b", ["x", "y", "z", "w"]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast: DynProofPlan<RistrettoPoint> = filter(
+        cols_expr_plan(t, &["a", "b"], &accessor),
+        tab(t),
+        equal(column(t, "a", &accessor), const_bigint(2)),
+    );
+    let res = VerifiableQueryResult::<InnerProductProof>::new(&ast, &accessor, &());
+    let same_res = VerifiableQueryResult::<InnerProductProof>::new(&ast, &accessor, &());
+    assert!(res == same_res);
+    assert_eq!(res.to_bytes(), same_res.to_bytes());
+    assert!(res.clone() == res);
+
+    let other_ast: DynProofPlan<RistrettoPoint> = filter(
+        cols_expr_plan(t, &["a", "b"], &accessor),
+        tab(t),
+        equal(column(t, "a", &accessor), const_bigint(3)),
+    );
+    let other_res = VerifiableQueryResult::<InnerProductProof>::new(&other_ast, &accessor, &());
+    assert!(res != other_res);
+    assert_ne!(res.to_bytes(), other_res.to_bytes());
+}
+
+#[test]
+fn empty_results_are_equal_only_to_empty_results() {
+    let expr = EmptyTestQueryExpr {
+        columns: 1,
+        ..Default::default()
+    };
+    let accessor = UnimplementedTestAccessor::new_empty();
+    let res = VerifiableQueryResult::<InnerProductProof>::new(&expr, &accessor, &());
+    assert!(
+        res == VerifiableQueryResult {
+            provable_result: None,
+            proof: None,
+        }
+    );
+    let non_empty = VerifiableQueryResult::<InnerProductProof> {
+        provable_result: Some(ProvableQueryResult::default()),
+        proof: None,
+    };
+    assert!(res != non_empty);
+}
+
+#[test]
+fn we_can_verify_only_some_of_the_result_columns() {
+    let data = owned_table([
+        bigint("a", [1, 2, 3, 2]),
+        varchar("b", ["x", "y", "z", "w"]),
+        bigint("c", [5, 6, 7, 8]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast: DynProofPlan<RistrettoPoint> = filter(
+        cols_expr_plan(t, &["a", "b", "c"], &accessor),
+        tab(t),
+        equal(column(t, "a", &accessor), const_bigint(2)),
+    );
+    let res = VerifiableQueryResult::<InnerProductProof>::new(&ast, &accessor, &());
+    let full = res.verify(&ast, &accessor, &()).unwrap();
+
+    let QueryData {
+        table,
+        verification_hash,
+    } = res
+        .verify_columns(&ast, &accessor, &(), &["b".parse().unwrap()])
+        .unwrap();
+    assert_eq!(table, owned_table([varchar("b", ["y", "w"])]));
+    assert_eq!(verification_hash, full.verification_hash);
+
+    let table = res
+        .verify_columns(
+            &ast,
+            &accessor,
+            &(),
+            &[
+                "c".parse().unwrap(),
+                "a".parse().unwrap(),
+                "c".parse().unwrap(),
+            ],
+        )
+        .unwrap()
+        .table;
+    assert_eq!(
+        table,
+        owned_table([bigint("c", [6, 8]), bigint("a", [2, 2])])
+    );
+}
+
+#[test]
+fn we_cannot_verify_a_column_that_is_not_in_the_result() {
+    let data = owned_table([bigint("a", [1, 2, 3]), bigint("d", [4, 5, 6])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast: DynProofPlan<RistrettoPoint> =
+        projection(cols_expr_plan(t, &["a"], &accessor), tab(t));
+    let res = VerifiableQueryResult::<InnerProductProof>::new(&ast, &accessor, &());
+    assert!(matches!(
+        res.verify_columns(&ast, &accessor, &(), &["d".parse().unwrap()]),
+        Err(QueryError::MissingResultColumn { column }) if column.name() == "d"
+    ));
+}
+
+#[test]
+fn we_cannot_prove_a_result_with_more_rows_than_the_cap() {
+    let data = owned_table([bigint("a", [1, 2, 2, 2, 3])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast: DynProofPlan<RistrettoPoint> = filter(
+        cols_expr_plan(t, &["a"], &accessor),
+        tab(t),
+        equal(column(t, "a", &accessor), const_bigint(2)),
+    );
+    assert!(matches!(
+        VerifiableQueryResult::<InnerProductProof>::try_new_with_max_output_rows(
+            &ast,
+            &accessor,
+            &(),
+            Some(2)
+        ),
+        Err(ProofError::ResultTooLarge {
+            num_rows: 3,
+            max_output_rows: 2
+        })
+    ));
+}
+
+#[test]
+fn we_can_prove_a_result_within_the_cap() {
+    let data = owned_table([bigint("a", [1, 2, 2, 2, 3])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast: DynProofPlan<RistrettoPoint> = filter(
+        cols_expr_plan(t, &["a"], &accessor),
+        tab(t),
+        equal(column(t, "a", &accessor), const_bigint(2)),
+    );
+    let uncapped = VerifiableQueryResult::<InnerProductProof>::new(&ast, &accessor, &());
+    for max_output_rows in [Some(3), Some(5), None] {
+        let res = VerifiableQueryResult::<InnerProductProof>::try_new_with_max_output_rows(
+            &ast,
+            &accessor,
+            &(),
+            max_output_rows,
+        )
+        .unwrap();
+        assert!(res == uncapped);
+        assert_eq!(
+            res.verify(&ast, &accessor, &()).unwrap().table,
+            owned_table([bigint("a", [2, 2, 2])])
+        );
+    }
+}
+
+#[test]
+fn the_result_cap_applies_before_limit_postprocessing() {
+    let data = owned_table([bigint("a", [1, 2, 2, 2, 3])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast: DynProofPlan<RistrettoPoint> =
+        projection(cols_expr_plan(t, &["a"], &accessor), tab(t));
+    let limit = [slice(Some(2), None)];
+
+    // LIMIT does not shrink the proven result, so it can not bring it under the cap
+    assert!(matches!(
+        VerifiableQueryResult::<InnerProductProof>::try_new_with_max_output_rows(
+            &ast,
+            &accessor,
+            &(),
+            Some(2)
+        ),
+        Err(ProofError::ResultTooLarge { num_rows: 5, .. })
+    ));
+
+    // and a LIMIT below the cap applies to the verified result as usual
+    let res = VerifiableQueryResult::<InnerProductProof>::try_new_with_max_output_rows(
+        &ast,
+        &accessor,
+        &(),
+        Some(5),
+    )
+    .unwrap();
+    let table = res.verify(&ast, &accessor, &()).unwrap().table;
+    assert_eq!(
+        apply_postprocessing_steps(table, &limit).unwrap(),
+        owned_table([bigint("a", [1, 2])])
+    );
+}
+
+#[test]
+fn we_can_count_the_group_operations_of_the_verifier() {
+    let data = owned_table([
+        bigint("a", [1, 2, 3, 2]),
+        bigint("b", [5, 6, 7, 8]),
+        bigint("c", [0, 1, 0, 1]),
+        bigint("d", [9, 9, 9, 9]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let stats_of = |columns: &[&str]| {
+        let ast: DynProofPlan<RistrettoPoint> =
+            projection(cols_expr_plan(t, columns, &accessor), tab(t));
+        let res = VerifiableQueryResult::<InnerProductProof>::new(&ast, &accessor, &());
+        let (data, stats) = res.verify_with_stats(&ast, &accessor, &()).unwrap();
+        let verified = res.verify(&ast, &accessor, &()).unwrap();
+        assert_eq!(data.table, verified.table);
+        assert_eq!(data.verification_hash, verified.verification_hash);
+        assert_eq!(
+            stats,
+            res.verify_with_stats(&ast, &accessor, &()).unwrap().1
+        );
+        stats
+    };
+
+    let small = stats_of(&["a", "b"]);
+    assert!(small.msm_size > 0);
+    assert_eq!(small.sumcheck_rounds, 2);
+
+    let large = stats_of(&["a", "b", "c", "d"]);
+    assert_eq!(large.msm_size, 2 * small.msm_size);
+    assert_eq!(large.sumcheck_rounds, small.sumcheck_rounds);
+}
+
+#[test]
+fn verifying_a_query_on_an_empty_table_reports_no_group_operations() {
+    let data = owned_table([bigint("a", [0; 0])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast: DynProofPlan<RistrettoPoint> =
+        projection(cols_expr_plan(t, &["a"], &accessor), tab(t));
+    let res = VerifiableQueryResult::<InnerProductProof>::new(&ast, &accessor, &());
+    let (data, stats) = res.verify_with_stats(&ast, &accessor, &()).unwrap();
+    assert_eq!(data.table, owned_table([bigint("a", [0; 0])]));
+    assert_eq!(stats, VerifierStats::default());
+}
@@ -4,7 +4,9 @@ use super::{
 };
 use crate::base::{
     commitment::{Commitment, CommittableColumn},
-    database::{Column, CommitmentAccessor, OwnedTableTestAccessor, TableRef, TestAccessor},
+    database::{
+        Column, CommitmentAccessor, OwnedTableTestAccessor, SchemaAccessor, TableRef, TestAccessor,
+    },
     scalar::Curve25519Scalar,
 };
 use blitzar::proof::InnerProductProof;
@@ -88,7 +90,7 @@ pub fn exercise_verification(
 fn tamper_no_result(
     res: &VerifiableQueryResult<InnerProductProof>,
     expr: &(impl ProofPlan<RistrettoPoint> + Serialize),
-    accessor: &impl CommitmentAccessor<RistrettoPoint>,
+    accessor: &(impl CommitmentAccessor<RistrettoPoint> + SchemaAccessor),
 ) {
     // add a result
     let mut res_p = res.clone();
@@ -111,7 +113,7 @@ fn tamper_no_result(
 fn tamper_empty_result(
     res: &VerifiableQueryResult<InnerProductProof>,
     expr: &(impl ProofPlan<RistrettoPoint> + Serialize),
-    accessor: &impl CommitmentAccessor<RistrettoPoint>,
+    accessor: &(impl CommitmentAccessor<RistrettoPoint> + SchemaAccessor),
 ) {
     // try to add a result
     let mut res_p = res.clone();
@@ -130,7 +132,7 @@ fn tamper_empty_result(
 fn tamper_result(
     res: &VerifiableQueryResult<InnerProductProof>,
     expr: &(impl ProofPlan<RistrettoPoint> + Serialize),
-    accessor: &impl CommitmentAccessor<RistrettoPoint>,
+    accessor: &(impl CommitmentAccessor<RistrettoPoint> + SchemaAccessor),
 ) {
     if res.provable_result.is_none() {
         tamper_no_result(res, expr, accessor);
@@ -95,9 +95,17 @@ impl<'a, C: Commitment> VerificationBuilder<'a, C> {
     ///
     /// An interemdiate MLE is one where the verifier doesn't have access to its commitment
     pub fn consume_intermediate_mle(&mut self) -> C::Scalar {
+        self.consume_intermediate_mle_with_commitment().1
+    }
+
+    /// Consume the evaluation of an intermediate MLE used in sumcheck along with the commitment the prover sent for it
+    ///
+    /// This lets the commitment be reused for a column whose data is public, see [`Self::consume_anchored_mle`].
+    pub fn consume_intermediate_mle_with_commitment(&mut self) -> (C, C::Scalar) {
         let commitment = self.intermediate_commitments[self.consumed_intermediate_mles].clone();
         self.consumed_intermediate_mles += 1;
-        self.consume_anchored_mle(commitment)
+        let evaluation = self.consume_anchored_mle(commitment.clone());
+        (commitment, evaluation)
     }
 
     /// Produce the evaluation of a subpolynomial used in sumcheck
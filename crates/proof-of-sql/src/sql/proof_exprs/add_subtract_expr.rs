@@ -1,4 +1,6 @@
-use super::{add_subtract_columns, scale_and_add_subtract_eval, DynProofExpr, ProofExpr};
+use super::{
+    add_subtract_columns, column_of_type, scale_and_add_subtract_eval, DynProofExpr, ProofExpr,
+};
 use crate::{
     base::{
         commitment::Commitment,
@@ -33,6 +35,16 @@ impl<C: Commitment> AddSubtractExpr<C> {
             is_subtract,
         }
     }
+
+    /// The left and right operands of the expression
+    pub(crate) fn operands(&self) -> [&DynProofExpr<C>; 2] {
+        [&self.lhs, &self.rhs]
+    }
+
+    /// Whether the expression is a subtraction rather than an addition
+    pub(crate) fn is_subtract(&self) -> bool {
+        self.is_subtract
+    }
 }
 
 impl<C: Commitment> ProofExpr<C> for AddSubtractExpr<C> {
@@ -62,14 +74,15 @@ impl<C: Commitment> ProofExpr<C> for AddSubtractExpr<C> {
             self.lhs.result_evaluate(table_length, alloc, accessor);
         let rhs_column: Column<'a, C::Scalar> =
             self.rhs.result_evaluate(table_length, alloc, accessor);
-        Column::Scalar(add_subtract_columns(
+        let scalars = add_subtract_columns(
             lhs_column,
             rhs_column,
             self.lhs.data_type().scale().unwrap_or(0),
             self.rhs.data_type().scale().unwrap_or(0),
             alloc,
             self.is_subtract,
-        ))
+        );
+        column_of_type(scalars, self.data_type())
     }
 
     #[tracing::instrument(
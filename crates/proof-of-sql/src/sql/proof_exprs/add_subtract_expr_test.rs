@@ -7,7 +7,7 @@ use crate::{
     sql::{
         parse::ConversionError,
         proof::{exercise_verification, QueryError, VerifiableQueryResult},
-        proof_exprs::{test_utility::*, DynProofExpr, ProofExpr},
+        proof_exprs::{test_utility::*, DynProofExpr, ProofExpr, DEFAULT_MAX_EXPR_DEPTH},
         proof_plans::{test_utility::*, DynProofPlan},
     },
 };
@@ -56,6 +56,45 @@ fn we_can_prove_a_typical_add_subtract_query() {
     assert_eq!(res, expected_res);
 }
 
+// select a + b as total, a - c as diff from sxt.t where a - 100 >= b
+// with a of type decimal(5, -2), i.e. multiples of 100
+#[test]
+fn we_can_prove_an_add_subtract_query_with_a_negative_scale_decimal() {
+    let data = owned_table([
+        decimal75("a", 5, -2, [7, -3, 0, 12]),
+        bigint("b", [1_i64, 2, 3, -4]),
+        decimal75("c", 3, 1, [15, 0, -5, 999]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = filter(
+        vec![
+            aliased_plan(
+                add(column(t, "a", &accessor), column(t, "b", &accessor)),
+                "total",
+            ),
+            aliased_plan(
+                subtract(column(t, "a", &accessor), column(t, "c", &accessor)),
+                "diff",
+            ),
+        ],
+        tab(t),
+        gte(
+            subtract(column(t, "a", &accessor), const_bigint(100)),
+            column(t, "b", &accessor),
+        ),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    // 700 + 1 and 1200 - 4 at scale 0; 700 - 1.5 and 1200 - 99.9 at scale 1
+    let expected_res = owned_table([
+        decimal75("total", 20, 0, [701, 1196]),
+        decimal75("diff", 9, 1, [6985, 11001]),
+    ]);
+    assert_eq!(res, expected_res);
+}
+
 // select a, a + b + c + 0.4 as c, d from sxt.t where a - b = 0.5
 #[test]
 fn we_can_prove_a_typical_add_subtract_query_with_decimals() {
@@ -325,3 +364,42 @@ fn we_can_compute_the_correct_output_of_an_add_subtract_expr_using_result_evalua
     let expected_res = Column::Scalar(&expected_res_scalar);
     assert_eq!(res, expected_res);
 }
+
+#[test]
+fn we_can_evaluate_a_deeply_nested_add_expr_within_the_maximum_depth() {
+    let data = owned_table([bigint("a", [1_i64, 2, 3])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let sum: DynProofExpr<RistrettoPoint> = (0..DEFAULT_MAX_EXPR_DEPTH - 1)
+        .fold(column(t, "a", &accessor), |sum, _| {
+            add(sum, const_bigint(1))
+        });
+    assert_eq!(sum.depth(), DEFAULT_MAX_EXPR_DEPTH);
+    assert!(sum.check_depth(DEFAULT_MAX_EXPR_DEPTH).is_ok());
+    let alloc = Bump::new();
+    let res = sum.result_evaluate(3, &alloc, &accessor);
+    let offset = i64::try_from(DEFAULT_MAX_EXPR_DEPTH).unwrap() - 1;
+    let expected_res_scalar = [1 + offset, 2 + offset, 3 + offset]
+        .iter()
+        .map(|v| Curve25519Scalar::from(*v))
+        .collect::<Vec<_>>();
+    assert_eq!(res, Column::Scalar(&expected_res_scalar));
+}
+
+#[test]
+fn we_cannot_accept_an_add_expr_nested_deeper_than_the_maximum_depth() {
+    let data = owned_table([bigint("a", [1_i64, 2, 3])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let sum: DynProofExpr<RistrettoPoint> = (0..DEFAULT_MAX_EXPR_DEPTH)
+        .fold(column(t, "a", &accessor), |sum, _| {
+            add(sum, const_bigint(1))
+        });
+    assert_eq!(
+        sum.check_depth(DEFAULT_MAX_EXPR_DEPTH),
+        Err(ConversionError::PlanTooDeep {
+            depth: DEFAULT_MAX_EXPR_DEPTH + 1,
+            max_depth: DEFAULT_MAX_EXPR_DEPTH,
+        })
+    );
+}
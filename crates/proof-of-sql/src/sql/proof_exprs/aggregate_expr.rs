@@ -27,6 +27,16 @@ impl<C: Commitment> AggregateExpr<C> {
     pub fn new(op: AggregationOperator, expr: Box<DynProofExpr<C>>) -> Self {
         Self { op, expr }
     }
+
+    /// The operand of the expression
+    pub(crate) fn input(&self) -> &DynProofExpr<C> {
+        &self.expr
+    }
+
+    /// The aggregation operator of the expression
+    pub(crate) fn op(&self) -> AggregationOperator {
+        self.op
+    }
 }
 
 impl<C: Commitment> ProofExpr<C> for AggregateExpr<C> {
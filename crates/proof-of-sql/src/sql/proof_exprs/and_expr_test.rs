@@ -164,3 +164,43 @@ fn we_can_compute_the_correct_output_of_an_and_expr_using_result_evaluate() {
     let expected_res = Column::Boolean(&[false, true, false, false]);
     assert_eq!(res, expected_res);
 }
+
+#[test]
+fn we_fold_and_exprs_with_boolean_literals() {
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        t,
+        owned_table([boolean("p", [true, false])]),
+        0,
+        (),
+    );
+    let p: DynProofExpr<RistrettoPoint> = column(t, "p", &accessor);
+    assert_eq!(and(const_bool(true), p.clone()), p);
+    assert_eq!(and(p.clone(), const_bool(true)), p);
+    assert_eq!(and(const_bool(false), p.clone()), const_bool(false));
+    assert_eq!(and(p, const_bool(false)), const_bool(false));
+    assert_eq!(
+        and(const_bool(true), const_bool(true)),
+        const_bool::<RistrettoPoint>(true)
+    );
+}
+
+#[test]
+fn we_can_prove_an_and_query_that_is_folded_by_a_boolean_literal() {
+    let data = owned_table([bigint("a", [1, 2, 3, 4]), bigint("b", [0, 1, 0, 1])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = filter(
+        cols_expr_plan(t, &["a"], &accessor),
+        tab(t),
+        and(
+            const_bool(true),
+            equal(column(t, "b", &accessor), const_bigint(1)),
+        ),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([bigint("a", [2, 4])]);
+    assert_eq!(res, expected_res);
+}
@@ -8,6 +8,7 @@ use crate::{
     },
     sql::proof::{CountBuilder, FinalRoundBuilder, VerificationBuilder},
 };
+use alloc::boxed::Box;
 use bumpalo::Bump;
 use core::marker::PhantomData;
 use proof_of_sql_parser::Identifier;
@@ -17,22 +18,22 @@ use serde::{Deserialize, Serialize};
 /// Note: this is currently limited to named column expressions.
 #[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
 pub struct ColumnExpr<C: Commitment> {
-    column_ref: ColumnRef,
+    column_ref: Box<ColumnRef>,
     _phantom_data: PhantomData<C>,
 }
 
 impl<C: Commitment> ColumnExpr<C> {
     /// Create a new column expression
-    pub fn new(column_ref: ColumnRef) -> Self {
+    pub fn new(column_ref: &ColumnRef) -> Self {
         Self {
-            column_ref,
+            column_ref: Box::new(*column_ref),
             _phantom_data: PhantomData,
         }
     }
 
     /// Return the column referenced by this [`ColumnExpr`]
     pub fn get_column_reference(&self) -> ColumnRef {
-        self.column_ref
+        *self.column_ref
     }
 
     /// Wrap the column output name and its type within the [`ColumnField`]
@@ -66,7 +67,7 @@ impl<C: Commitment> ProofExpr<C> for ColumnExpr<C> {
         _alloc: &'a Bump,
         accessor: &'a dyn DataAccessor<C::Scalar>,
     ) -> Column<'a, C::Scalar> {
-        let column = accessor.get_column(self.column_ref);
+        let column = accessor.get_column(*self.column_ref);
         assert_eq!(column.len(), table_length);
         column
     }
@@ -79,7 +80,7 @@ impl<C: Commitment> ProofExpr<C> for ColumnExpr<C> {
         _alloc: &'a Bump,
         accessor: &'a dyn DataAccessor<C::Scalar>,
     ) -> Column<'a, C::Scalar> {
-        let column = accessor.get_column(self.column_ref);
+        let column = accessor.get_column(*self.column_ref);
         builder.produce_anchored_mle(column);
         column
     }
@@ -91,7 +92,7 @@ impl<C: Commitment> ProofExpr<C> for ColumnExpr<C> {
         builder: &mut VerificationBuilder<C>,
         accessor: &dyn CommitmentAccessor<C>,
     ) -> Result<C::Scalar, ProofError> {
-        let col_commit = accessor.get_commitment(self.column_ref);
+        let col_commit = accessor.get_commitment(*self.column_ref);
         Ok(builder.consume_anchored_mle(col_commit))
     }
 
@@ -99,6 +100,6 @@ impl<C: Commitment> ProofExpr<C> for ColumnExpr<C> {
     /// references in the `BoolExpr` or forwards the call to some
     /// subsequent `bool_expr`
     fn get_column_references(&self, columns: &mut IndexSet<ColumnRef>) {
-        columns.insert(self.column_ref);
+        columns.insert(*self.column_ref);
     }
 }
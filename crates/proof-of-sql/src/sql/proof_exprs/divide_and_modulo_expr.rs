@@ -0,0 +1,390 @@
+use super::{count_sign, prover_evaluate_sign, verifier_evaluate_sign, DynProofExpr, ProofExpr};
+use crate::{
+    base::{
+        commitment::Commitment,
+        database::{Column, ColumnRef, ColumnType, CommitmentAccessor, DataAccessor},
+        map::IndexSet,
+        proof::ProofError,
+        scalar::Scalar,
+    },
+    sql::{
+        parse::{ConversionError, ConversionResult},
+        proof::{CountBuilder, FinalRoundBuilder, SumcheckSubpolynomialType, VerificationBuilder},
+    },
+};
+use alloc::{boxed::Box, string::ToString, vec};
+use bumpalo::Bump;
+use num_traits::{One, Zero};
+use serde::{Deserialize, Serialize};
+
+/// Provable integer division expression producing both the quotient and the remainder
+/// of `lhs / rhs` from a single shared witness `lhs = quotient * rhs + remainder`.
+///
+/// Division truncates toward zero, matching postgres and Rust's `/` and `%`:
+/// - the quotient is rounded toward zero, e.g. `-7 / 2 = -3`;
+/// - the remainder is either zero or has the same sign as the dividend, e.g. `-7 % 2 = -1`;
+/// - `|remainder| < |rhs|`.
+///
+/// Division by zero cannot satisfy the last constraint, so any proof over a column
+/// containing a zero divisor fails to verify.
+///
+/// Only `TinyInt`, `SmallInt`, `Int` and `BigInt` operands are supported. The quotient has the
+/// type of `lhs` and the remainder the type of `rhs`. The quotient is proven to lie within the
+/// range of the `lhs` type, so `MIN / -1`, which overflows it, fails to verify.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DivideAndModuloExpr<C: Commitment> {
+    lhs: Box<DynProofExpr<C>>,
+    rhs: Box<DynProofExpr<C>>,
+}
+
+impl<C: Commitment> DivideAndModuloExpr<C> {
+    /// Create a new divide-and-modulo expression, checking that both operands are supported
+    /// integer types
+    pub fn try_new(lhs: Box<DynProofExpr<C>>, rhs: Box<DynProofExpr<C>>) -> ConversionResult<Self> {
+        let lhs_datatype = lhs.data_type();
+        let rhs_datatype = rhs.data_type();
+        if is_supported_type(lhs_datatype) && is_supported_type(rhs_datatype) {
+            Ok(Self { lhs, rhs })
+        } else {
+            Err(ConversionError::DataTypeMismatch {
+                left_type: lhs_datatype.to_string(),
+                right_type: rhs_datatype.to_string(),
+            })
+        }
+    }
+
+    /// The dividend and the divisor
+    pub(crate) fn operands(&self) -> [&DynProofExpr<C>; 2] {
+        [&self.lhs, &self.rhs]
+    }
+
+    /// Count terms used within the proof of both the quotient and the remainder
+    pub fn count(&self, builder: &mut CountBuilder) -> Result<(), ProofError> {
+        self.lhs.count(builder)?;
+        self.rhs.count(builder)?;
+        // quotient, remainder, |rhs| and |remainder|
+        builder.count_intermediate_mles(4);
+        builder.count_subpolynomials(3);
+        builder.count_degree(3);
+        // sign(lhs), sign(rhs), both bounds of the quotient, |remainder| >= 0
+        // and |rhs| > |remainder|
+        for _ in 0..6 {
+            count_sign(builder)?;
+        }
+        Ok(())
+    }
+
+    /// The data types of the quotient and the remainder
+    #[must_use]
+    pub fn data_type(&self) -> (ColumnType, ColumnType) {
+        (self.lhs.data_type(), self.rhs.data_type())
+    }
+
+    /// Compute the quotient and remainder columns
+    pub fn result_evaluate<'a>(
+        &self,
+        table_length: usize,
+        alloc: &'a Bump,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> (Column<'a, C::Scalar>, Column<'a, C::Scalar>) {
+        let lhs_column = self.lhs.result_evaluate(table_length, alloc, accessor);
+        let rhs_column = self.rhs.result_evaluate(table_length, alloc, accessor);
+        divide_and_modulo_columns(&lhs_column, &rhs_column, self.data_type(), alloc)
+    }
+
+    /// Compute the quotient and remainder columns and prove that they are correct
+    #[tracing::instrument(
+        name = "proofs.sql.ast.divide_and_modulo_expr.prover_evaluate",
+        level = "info",
+        skip_all
+    )]
+    pub fn prover_evaluate<'a>(
+        &self,
+        builder: &mut FinalRoundBuilder<'a, C::Scalar>,
+        alloc: &'a Bump,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> (Column<'a, C::Scalar>, Column<'a, C::Scalar>) {
+        let lhs_column = self.lhs.prover_evaluate(builder, alloc, accessor);
+        let rhs_column = self.rhs.prover_evaluate(builder, alloc, accessor);
+        let (quotient_column, remainder_column) =
+            divide_and_modulo_columns(&lhs_column, &rhs_column, self.data_type(), alloc);
+        let quotients = quotient_column.as_scalar(alloc);
+        let remainders = remainder_column.as_scalar(alloc);
+        let (min_quotient, max_quotient) = integer_bounds::<C::Scalar>(self.lhs.data_type());
+        let lhs = lhs_column.as_scalar(alloc);
+        let rhs = rhs_column.as_scalar(alloc);
+        let table_length = lhs.len();
+        builder.produce_intermediate_mle(quotients);
+        builder.produce_intermediate_mle(remainders);
+
+        // subpolynomial: lhs - quotient * rhs - remainder
+        builder.produce_sumcheck_subpolynomial(
+            SumcheckSubpolynomialType::Identity,
+            vec![
+                (C::Scalar::one(), vec![Box::new(lhs)]),
+                (-C::Scalar::one(), vec![Box::new(quotients), Box::new(rhs)]),
+                (-C::Scalar::one(), vec![Box::new(remainders)]),
+            ],
+        );
+
+        let lhs_sign = prover_evaluate_sign(
+            builder,
+            alloc,
+            lhs,
+            #[cfg(test)]
+            false,
+        );
+        let rhs_sign = prover_evaluate_sign(
+            builder,
+            alloc,
+            rhs,
+            #[cfg(test)]
+            false,
+        );
+        // The quotient lies within the range of the `lhs` type. This rules out both overflow
+        // and field elements such as `1/2` that would otherwise satisfy the identity above.
+        let above_min = alloc.alloc_slice_fill_with(table_length, |i| quotients[i] - min_quotient);
+        prover_evaluate_sign(
+            builder,
+            alloc,
+            above_min,
+            #[cfg(test)]
+            false,
+        );
+        let below_max = alloc.alloc_slice_fill_with(table_length, |i| max_quotient - quotients[i]);
+        prover_evaluate_sign(
+            builder,
+            alloc,
+            below_max,
+            #[cfg(test)]
+            false,
+        );
+
+        // |rhs|
+        let rhs_abs = alloc_abs(alloc, rhs, rhs_sign);
+        builder.produce_intermediate_mle(rhs_abs);
+        prove_abs(builder, rhs_abs, rhs, rhs_sign);
+
+        // |remainder|, given that the remainder has the sign of lhs
+        let remainder_abs = alloc_abs(alloc, remainders, lhs_sign);
+        builder.produce_intermediate_mle(remainder_abs);
+        prove_abs(builder, remainder_abs, remainders, lhs_sign);
+
+        // |remainder| >= 0
+        prover_evaluate_sign(
+            builder,
+            alloc,
+            remainder_abs,
+            #[cfg(test)]
+            false,
+        );
+        // |rhs| - |remainder| - 1 >= 0
+        let slack = alloc.alloc_slice_fill_with(table_length, |i| {
+            rhs_abs[i] - remainder_abs[i] - C::Scalar::one()
+        });
+        prover_evaluate_sign(
+            builder,
+            alloc,
+            slack,
+            #[cfg(test)]
+            false,
+        );
+
+        (quotient_column, remainder_column)
+    }
+
+    /// Compute the evaluations of the quotient and remainder columns
+    pub fn verifier_evaluate(
+        &self,
+        builder: &mut VerificationBuilder<C>,
+        accessor: &dyn CommitmentAccessor<C>,
+    ) -> Result<(C::Scalar, C::Scalar), ProofError> {
+        let one_eval = builder.mle_evaluations.input_one_evaluation;
+        let lhs = self.lhs.verifier_evaluate(builder, accessor)?;
+        let rhs = self.rhs.verifier_evaluate(builder, accessor)?;
+        let quotient = builder.consume_intermediate_mle();
+        let remainder = builder.consume_intermediate_mle();
+
+        // subpolynomial: lhs - quotient * rhs - remainder
+        builder.produce_sumcheck_subpolynomial_evaluation(
+            &SumcheckSubpolynomialType::Identity,
+            lhs - quotient * rhs - remainder,
+        );
+
+        let lhs_sign = verifier_evaluate_sign(builder, lhs, one_eval)?;
+        let rhs_sign = verifier_evaluate_sign(builder, rhs, one_eval)?;
+        let (min_quotient, max_quotient) = integer_bounds::<C::Scalar>(self.lhs.data_type());
+        let above_min_sign =
+            verifier_evaluate_sign(builder, quotient - min_quotient * one_eval, one_eval)?;
+        let below_max_sign =
+            verifier_evaluate_sign(builder, max_quotient * one_eval - quotient, one_eval)?;
+        if !above_min_sign.is_zero() || !below_max_sign.is_zero() {
+            return Err(ProofError::VerificationError {
+                error: "quotient overflows the type of the dividend",
+            });
+        }
+
+        // |rhs|
+        let rhs_abs = builder.consume_intermediate_mle();
+        verify_abs(builder, rhs_abs, rhs, rhs_sign);
+
+        // |remainder|
+        let remainder_abs = builder.consume_intermediate_mle();
+        verify_abs(builder, remainder_abs, remainder, lhs_sign);
+
+        // |remainder| >= 0
+        let remainder_abs_sign = verifier_evaluate_sign(builder, remainder_abs, one_eval)?;
+        // |rhs| - |remainder| - 1 >= 0
+        let slack_sign =
+            verifier_evaluate_sign(builder, rhs_abs - remainder_abs - one_eval, one_eval)?;
+        if !remainder_abs_sign.is_zero() || !slack_sign.is_zero() {
+            return Err(ProofError::VerificationError {
+                error: "remainder is out of range or divisor is zero",
+            });
+        }
+
+        Ok((quotient, remainder))
+    }
+
+    /// Insert the columns referenced by both operands into `columns`
+    pub fn get_column_references(&self, columns: &mut IndexSet<ColumnRef>) {
+        self.lhs.get_column_references(columns);
+        self.rhs.get_column_references(columns);
+    }
+}
+
+fn is_supported_type(column_type: ColumnType) -> bool {
+    matches!(
+        column_type,
+        ColumnType::TinyInt | ColumnType::SmallInt | ColumnType::Int | ColumnType::BigInt
+    )
+}
+
+/// Returns the minimum and maximum values of a supported integer type.
+///
+/// # Panics
+/// Panics if the type is not `TinyInt`, `SmallInt`, `Int` or `BigInt`.
+fn integer_bounds<S: Scalar>(column_type: ColumnType) -> (S, S) {
+    match column_type {
+        ColumnType::TinyInt => (i8::MIN.into(), i8::MAX.into()),
+        ColumnType::SmallInt => (i16::MIN.into(), i16::MAX.into()),
+        ColumnType::Int => (i32::MIN.into(), i32::MAX.into()),
+        ColumnType::BigInt => (i64::MIN.into(), i64::MAX.into()),
+        _ => panic!("divide and modulo is only supported for integer columns up to 64 bits"),
+    }
+}
+
+/// Allocates an integer column of `column_type` whose value at `index` is `value(index)`,
+/// wrapped to the range of the type.
+///
+/// # Panics
+/// Panics if the type is not `TinyInt`, `SmallInt`, `Int` or `BigInt`.
+#[allow(clippy::cast_possible_truncation)]
+fn alloc_integer_column<S: Scalar>(
+    alloc: &Bump,
+    column_type: ColumnType,
+    length: usize,
+    value: impl Fn(usize) -> i128,
+) -> Column<'_, S> {
+    match column_type {
+        ColumnType::TinyInt => {
+            Column::TinyInt(alloc.alloc_slice_fill_with(length, |i| value(i) as i8))
+        }
+        ColumnType::SmallInt => {
+            Column::SmallInt(alloc.alloc_slice_fill_with(length, |i| value(i) as i16))
+        }
+        ColumnType::Int => Column::Int(alloc.alloc_slice_fill_with(length, |i| value(i) as i32)),
+        ColumnType::BigInt => {
+            Column::BigInt(alloc.alloc_slice_fill_with(length, |i| value(i) as i64))
+        }
+        _ => panic!("divide and modulo is only supported for integer columns up to 64 bits"),
+    }
+}
+
+/// Returns the value of an integer column at `index`.
+///
+/// A computed operand, such as `a + 1`, is a `Scalar` column. A scalar that does not fit
+/// in an `i128` is read as zero, which breaks `lhs = quotient * rhs + remainder`, so the proof
+/// fails to verify.
+///
+/// # Panics
+/// Panics if the column is not a `TinyInt`, `SmallInt`, `Int`, `BigInt` or `Scalar` column.
+fn integer_at<S: Scalar>(column: &Column<'_, S>, index: usize) -> i128 {
+    match column {
+        Column::TinyInt(col) => col[index].into(),
+        Column::SmallInt(col) => col[index].into(),
+        Column::Int(col) => col[index].into(),
+        Column::BigInt(col) => col[index].into(),
+        Column::Scalar(col) => col[index].try_into().unwrap_or(0),
+        _ => panic!("divide and modulo is only supported for integer columns up to 64 bits"),
+    }
+}
+
+/// Divide two integer columns, truncating toward zero.
+///
+/// The quotient has the type of `lhs` and the remainder the type of `rhs`, as given by
+/// `column_types`, since a computed operand is a `Scalar` column.
+/// Where the divisor is zero the quotient is zero and the remainder is the dividend. This keeps
+/// `lhs = quotient * rhs + remainder` true so that only the range check on the remainder fails.
+/// The quotient of `MIN / -1` wraps to `MIN`, so the identity fails for it.
+///
+/// # Panics
+/// Panics if the columns have different lengths or are not supported integer columns.
+pub(crate) fn divide_and_modulo_columns<'a, S: Scalar>(
+    lhs: &Column<'a, S>,
+    rhs: &Column<'a, S>,
+    column_types: (ColumnType, ColumnType),
+    alloc: &'a Bump,
+) -> (Column<'a, S>, Column<'a, S>) {
+    let lhs_len = lhs.len();
+    assert!(
+        lhs_len == rhs.len(),
+        "lhs and rhs should have the same length"
+    );
+    let quotients = alloc_integer_column(alloc, column_types.0, lhs_len, |i| {
+        let (a, b) = (integer_at(lhs, i), integer_at(rhs, i));
+        a.checked_div(b).unwrap_or(0)
+    });
+    let remainders = alloc_integer_column(alloc, column_types.1, lhs_len, |i| {
+        let (a, b) = (integer_at(lhs, i), integer_at(rhs, i));
+        a.checked_rem(b).unwrap_or(a)
+    });
+    (quotients, remainders)
+}
+
+/// Compute `x * (1 - 2 * sign)`, i.e. `|x|` when `sign` is the sign of `x`.
+fn alloc_abs<'a, S: Scalar>(alloc: &'a Bump, x: &[S], sign: &[bool]) -> &'a [S] {
+    alloc.alloc_slice_fill_with(x.len(), |i| if sign[i] { -x[i] } else { x[i] })
+}
+
+/// Prove that `abs = x * (1 - 2 * sign)`.
+fn prove_abs<'a, S: Scalar>(
+    builder: &mut FinalRoundBuilder<'a, S>,
+    abs: &'a [S],
+    x: &'a [S],
+    sign: &'a [bool],
+) {
+    // subpolynomial: abs - x + 2 * x * sign
+    builder.produce_sumcheck_subpolynomial(
+        SumcheckSubpolynomialType::Identity,
+        vec![
+            (S::one(), vec![Box::new(abs)]),
+            (-S::one(), vec![Box::new(x)]),
+            (S::TWO, vec![Box::new(x), Box::new(sign)]),
+        ],
+    );
+}
+
+/// Verify that `abs = x * (1 - 2 * sign)`.
+fn verify_abs<C: Commitment>(
+    builder: &mut VerificationBuilder<C>,
+    abs: C::Scalar,
+    x: C::Scalar,
+    sign: C::Scalar,
+) {
+    // subpolynomial: abs - x + 2 * x * sign
+    builder.produce_sumcheck_subpolynomial_evaluation(
+        &SumcheckSubpolynomialType::Identity,
+        abs - x + C::Scalar::TWO * x * sign,
+    );
+}
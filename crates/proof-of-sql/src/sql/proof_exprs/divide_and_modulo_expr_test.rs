@@ -0,0 +1,233 @@
+use super::{test_utility::*, DivideAndModuloExpr};
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{owned_table_utility::*, Column, OwnedTableTestAccessor, TableRef},
+        proof::ProofError,
+    },
+    sql::{
+        parse::ConversionError,
+        proof::{exercise_verification, QueryError, VerifiableQueryResult},
+        proof_plans::{
+            test_utility::{divide_and_modulo, projection},
+            DynProofPlan,
+        },
+    },
+};
+use bumpalo::Bump;
+use curve25519_dalek::ristretto::RistrettoPoint;
+use proof_of_sql_parser::Identifier;
+
+/// Plan of the form `SELECT a / b AS q, a % b AS r FROM <table>`
+fn divide_and_modulo_plan(
+    t: TableRef,
+    accessor: &OwnedTableTestAccessor<InnerProductProof>,
+) -> DynProofPlan<RistrettoPoint> {
+    divide_and_modulo(
+        column(t, "a", accessor),
+        column(t, "b", accessor),
+        "q",
+        "r",
+        tab(t),
+    )
+}
+
+#[test]
+fn we_can_prove_divide_and_modulo_of_bigint_columns_with_negatives() {
+    let dividends = [7_i64, -7, 7, -7, 0, 6, -6, 1, -1, i64::MAX, i64::MIN + 1];
+    let divisors = [2_i64, 2, -2, -2, 5, 3, -3, 10, 10, -1, i64::MAX];
+    let data = owned_table([bigint("a", dividends), bigint("b", divisors)]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let quotients = assert_expr_roundtrip(
+        &divide(column(t, "a", &accessor), column(t, "b", &accessor)),
+        &accessor,
+        t,
+    );
+    let remainders = assert_expr_roundtrip(
+        &modulo(column(t, "a", &accessor), column(t, "b", &accessor)),
+        &accessor,
+        t,
+    );
+    assert_eq!(
+        quotients,
+        owned_table([bigint(
+            "expr",
+            [3_i64, -3, -3, 3, 0, 2, 2, 0, 0, -i64::MAX, -1]
+        )])
+    );
+    assert_eq!(
+        remainders,
+        owned_table([bigint("expr", [1_i64, -1, 1, -1, 0, 0, 0, 1, -1, 0, 0])])
+    );
+
+    // The remainder is zero or has the sign of the dividend, and is smaller than the divisor.
+    let expr: Identifier = "expr".parse().unwrap();
+    let q_values = quotients.inner_table()[&expr].i64_iter();
+    let r_values = remainders.inner_table()[&expr].i64_iter();
+    for (((dividend, divisor), quotient), remainder) in dividends
+        .into_iter()
+        .zip(divisors)
+        .zip(q_values)
+        .zip(r_values)
+    {
+        assert_eq!(
+            i128::from(dividend),
+            i128::from(*quotient) * i128::from(divisor) + i128::from(*remainder)
+        );
+        assert!(*remainder == 0 || remainder.signum() == dividend.signum());
+        assert!(remainder.unsigned_abs() < divisor.unsigned_abs());
+    }
+}
+
+#[test]
+fn we_can_prove_divide_and_modulo_of_mixed_integer_columns() {
+    let data = owned_table([tinyint("a", [-128_i8, 127, -5]), int("b", [3_i32, -100, 7])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = divide_and_modulo_plan(t, &accessor);
+    let verifiable_res = VerifiableQueryResult::<InnerProductProof>::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([tinyint("q", [-42_i8, -1, 0]), int("r", [-2_i32, 27, -5])]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_commit_to_fewer_columns_when_proving_divide_and_modulo_together() {
+    let data = owned_table([bigint("a", [7_i64, -7, 3]), bigint("b", [2_i64, 3, -1])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let together = divide_and_modulo_plan(t, &accessor);
+    let separately = projection(
+        vec![
+            aliased_plan(
+                divide(column(t, "a", &accessor), column(t, "b", &accessor)),
+                "q",
+            ),
+            aliased_plan(
+                modulo(column(t, "a", &accessor), column(t, "b", &accessor)),
+                "r",
+            ),
+        ],
+        tab(t),
+    );
+    let together_res = VerifiableQueryResult::<InnerProductProof>::new(&together, &accessor, &());
+    let separately_res =
+        VerifiableQueryResult::<InnerProductProof>::new(&separately, &accessor, &());
+    assert_eq!(
+        together_res
+            .verify(&together, &accessor, &())
+            .unwrap()
+            .table,
+        separately_res
+            .verify(&separately, &accessor, &())
+            .unwrap()
+            .table
+    );
+    assert!(
+        together_res.proof.unwrap().commitments.len()
+            < separately_res.proof.unwrap().commitments.len()
+    );
+}
+
+#[test]
+fn we_can_prove_divide_and_modulo_of_an_empty_table() {
+    let data = owned_table([bigint("a", [0_i64; 0]), bigint("b", [0_i64; 0])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = divide_and_modulo_plan(t, &accessor);
+    let verifiable_res = VerifiableQueryResult::<InnerProductProof>::new(&ast, &accessor, &());
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([bigint("q", [0_i64; 0]), bigint("r", [0_i64; 0])]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_cannot_prove_divide_and_modulo_by_zero() {
+    let data = owned_table([bigint("a", [7_i64, -7, 3]), bigint("b", [2_i64, 0, 1])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = divide_and_modulo_plan(t, &accessor);
+    let verifiable_res = VerifiableQueryResult::<InnerProductProof>::new(&ast, &accessor, &());
+    assert!(matches!(
+        verifiable_res.verify(&ast, &accessor, &()),
+        Err(QueryError::ProofError {
+            source: ProofError::VerificationError { .. }
+        })
+    ));
+}
+
+#[test]
+fn we_cannot_prove_divide_and_modulo_by_a_column_of_zeros() {
+    let data = owned_table([bigint("a", [7_i64, -7, 3]), bigint("b", [0_i64, 0, 0])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = divide_and_modulo_plan(t, &accessor);
+    let verifiable_res = VerifiableQueryResult::<InnerProductProof>::new(&ast, &accessor, &());
+    assert!(matches!(
+        verifiable_res.verify(&ast, &accessor, &()),
+        Err(QueryError::ProofError {
+            source: ProofError::VerificationError { .. }
+        })
+    ));
+}
+
+#[test]
+fn we_cannot_prove_divide_and_modulo_of_min_by_negative_one() {
+    let data = owned_table([bigint("a", [4_i64, i64::MIN]), bigint("b", [2_i64, -1])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = divide_and_modulo_plan(t, &accessor);
+    let verifiable_res = VerifiableQueryResult::<InnerProductProof>::new(&ast, &accessor, &());
+    assert!(matches!(
+        verifiable_res.verify(&ast, &accessor, &()),
+        Err(QueryError::ProofError {
+            source: ProofError::VerificationError { .. }
+        })
+    ));
+}
+
+#[test]
+fn we_can_result_evaluate_divide_and_modulo_into_typed_columns() {
+    let data = owned_table([smallint("a", [-7_i16, 9]), tinyint("b", [2_i8, -4])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let expr = DivideAndModuloExpr::<RistrettoPoint>::try_new(
+        Box::new(column(t, "a", &accessor)),
+        Box::new(column(t, "b", &accessor)),
+    )
+    .unwrap();
+    let alloc = Bump::new();
+    let (quotient, remainder) = expr.result_evaluate(2, &alloc, &accessor);
+    assert_eq!(quotient, Column::SmallInt(&[-3, -2]));
+    assert_eq!(remainder, Column::TinyInt(&[-1, 1]));
+}
+
+#[test]
+fn we_cannot_divide_and_modulo_non_integer_columns() {
+    let data = owned_table([
+        bigint("a", [1_i64]),
+        int128("b", [1_i128]),
+        decimal75("c", 10, 2, [100_i64]),
+        varchar("d", ["1"]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    for other in ["b", "c", "d"] {
+        assert!(matches!(
+            DivideAndModuloExpr::<RistrettoPoint>::try_new(
+                Box::new(column(t, "a", &accessor)),
+                Box::new(column(t, other, &accessor)),
+            ),
+            Err(ConversionError::DataTypeMismatch { .. })
+        ));
+        assert!(matches!(
+            DivideAndModuloExpr::<RistrettoPoint>::try_new(
+                Box::new(column(t, other, &accessor)),
+                Box::new(column(t, "a", &accessor)),
+            ),
+            Err(ConversionError::DataTypeMismatch { .. })
+        ));
+    }
+}
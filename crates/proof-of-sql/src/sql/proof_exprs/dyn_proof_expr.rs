@@ -1,6 +1,7 @@
 use super::{
-    AddSubtractExpr, AggregateExpr, AndExpr, ColumnExpr, EqualsExpr, InequalityExpr, LiteralExpr,
-    MultiplyExpr, NotExpr, OrExpr, ProofExpr,
+    dyn_proof_expr_seed::DynProofExprSeed, AddSubtractExpr, AggregateExpr, AndExpr, ColumnExpr,
+    DivideAndModuloExpr, EqualsExpr, InequalityExpr, LiteralExpr, MultiplyExpr, NotExpr, OrExpr,
+    PowExpr, ProofExpr, RoundExpr,
 };
 use crate::{
     base::{
@@ -14,14 +15,19 @@ use crate::{
         proof::{CountBuilder, FinalRoundBuilder, VerificationBuilder},
     },
 };
-use alloc::{boxed::Box, string::ToString};
+use alloc::{boxed::Box, string::ToString, vec, vec::Vec};
 use bumpalo::Bump;
-use core::fmt::Debug;
+use core::fmt::{self, Debug, Display, Formatter};
 use proof_of_sql_parser::intermediate_ast::{AggregationOperator, BinaryOperator};
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeSeed, Deserialize, Deserializer, Serialize};
+
+/// The default maximum nesting depth of a [`DynProofExpr`] built from a query
+pub const DEFAULT_MAX_EXPR_DEPTH: usize = 512;
 
 /// Enum of AST column expression types that implement `ProofExpr`. Is itself a `ProofExpr`.
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+///
+/// Deserializing an expression fails if it is nested deeper than [`DEFAULT_MAX_EXPR_DEPTH`].
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub enum DynProofExpr<C: Commitment> {
     /// Column
     Column(ColumnExpr<C>),
@@ -43,28 +49,68 @@ pub enum DynProofExpr<C: Commitment> {
     Multiply(MultiplyExpr<C>),
     /// Provable aggregate expression
     Aggregate(AggregateExpr<C>),
+    /// Provable integer `/` expression
+    Divide(DivideAndModuloExpr<C>),
+    /// Provable integer `%` expression
+    Modulo(DivideAndModuloExpr<C>),
+    /// Provable numeric `POW` expression with a constant exponent
+    Pow(PowExpr<C>),
+    /// Provable decimal `ROUND` expression with a constant number of digits
+    Round(RoundExpr<C>),
+}
+// Custom deserializer since proving and verifying recurse through the expression,
+// and so would a derived deserializer, before the depth could be checked
+impl<'de, C: Commitment + Deserialize<'de>> Deserialize<'de> for DynProofExpr<C> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        DynProofExprSeed::new(DEFAULT_MAX_EXPR_DEPTH).deserialize(deserializer)
+    }
 }
+
 impl<C: Commitment> DynProofExpr<C> {
     /// Create column expression
-    pub fn new_column(column_ref: ColumnRef) -> Self {
+    pub fn new_column(column_ref: &ColumnRef) -> Self {
         Self::Column(ColumnExpr::new(column_ref))
     }
     /// Create logical AND expression
+    ///
+    /// If either side is a boolean literal, the expression is folded:
+    /// `true AND p` becomes `p` and `false AND p` becomes `false`.
     pub fn try_new_and(lhs: DynProofExpr<C>, rhs: DynProofExpr<C>) -> ConversionResult<Self> {
         lhs.check_data_type(ColumnType::Boolean)?;
         rhs.check_data_type(ColumnType::Boolean)?;
-        Ok(Self::And(AndExpr::new(Box::new(lhs), Box::new(rhs))))
+        Ok(match (lhs.as_boolean_literal(), rhs.as_boolean_literal()) {
+            (Some(true), _) => rhs,
+            (_, Some(true)) => lhs,
+            (Some(false), _) | (_, Some(false)) => Self::new_literal(LiteralValue::Boolean(false)),
+            (None, None) => Self::And(AndExpr::new(Box::new(lhs), Box::new(rhs))),
+        })
     }
     /// Create logical OR expression
+    ///
+    /// If either side is a boolean literal, the expression is folded:
+    /// `false OR p` becomes `p` and `true OR p` becomes `true`.
     pub fn try_new_or(lhs: DynProofExpr<C>, rhs: DynProofExpr<C>) -> ConversionResult<Self> {
         lhs.check_data_type(ColumnType::Boolean)?;
         rhs.check_data_type(ColumnType::Boolean)?;
-        Ok(Self::Or(OrExpr::new(Box::new(lhs), Box::new(rhs))))
+        Ok(match (lhs.as_boolean_literal(), rhs.as_boolean_literal()) {
+            (Some(false), _) => rhs,
+            (_, Some(false)) => lhs,
+            (Some(true), _) | (_, Some(true)) => Self::new_literal(LiteralValue::Boolean(true)),
+            (None, None) => Self::Or(OrExpr::new(Box::new(lhs), Box::new(rhs))),
+        })
     }
     /// Create logical NOT expression
+    ///
+    /// The negation of a boolean literal is folded into the negated literal.
     pub fn try_new_not(expr: DynProofExpr<C>) -> ConversionResult<Self> {
         expr.check_data_type(ColumnType::Boolean)?;
-        Ok(Self::Not(NotExpr::new(Box::new(expr))))
+        Ok(match expr.as_boolean_literal() {
+            Some(value) => Self::new_literal(LiteralValue::Boolean(!value)),
+            None => Self::Not(NotExpr::new(Box::new(expr))),
+        })
     }
     /// Create CONST expression
     pub fn new_literal(value: LiteralValue<C::Scalar>) -> Self {
@@ -83,6 +129,44 @@ impl<C: Commitment> DynProofExpr<C> {
             })
         }
     }
+    /// Create a new `IN` expression testing whether `expr` is equal to any of `values`
+    ///
+    /// The expression is proven as the `OR` of one equality per distinct value,
+    /// so both membership and non-membership are proven for every row.
+    /// Since its cost grows with the number of values, it is meant for short lists,
+    /// such as the verified result of a sub-query used for a semi-join.
+    /// An empty list is folded to `false`.
+    pub fn try_new_in_list(
+        expr: &DynProofExpr<C>,
+        values: &[LiteralValue<C::Scalar>],
+    ) -> ConversionResult<Self> {
+        // Two values of the same type are equal exactly when their scalars are
+        let mut seen = IndexSet::default();
+        let equalities = values
+            .iter()
+            .filter(|value| {
+                let limbs: [u64; 4] = value.to_scalar().into();
+                seen.insert((value.column_type(), limbs))
+            })
+            .map(|value| Self::try_new_equals(expr.clone(), Self::new_literal(value.clone())))
+            .collect::<ConversionResult<Vec<_>>>()?;
+        Self::try_new_balanced_or(equalities)
+    }
+    /// Combines `exprs` with `OR` as a balanced tree, so that the depth is logarithmic in their number
+    ///
+    /// An empty list is folded to `false`.
+    fn try_new_balanced_or(mut exprs: Vec<Self>) -> ConversionResult<Self> {
+        if exprs.len() <= 1 {
+            return Ok(exprs
+                .pop()
+                .unwrap_or_else(|| Self::new_literal(LiteralValue::Boolean(false))));
+        }
+        let rhs = exprs.split_off(exprs.len() / 2);
+        Self::try_new_or(
+            Self::try_new_balanced_or(exprs)?,
+            Self::try_new_balanced_or(rhs)?,
+        )
+    }
     /// Create a new inequality expression
     pub fn try_new_inequality(
         lhs: DynProofExpr<C>,
@@ -162,11 +246,85 @@ impl<C: Commitment> DynProofExpr<C> {
         }
     }
 
+    /// Create a new integer divide expression, truncating toward zero
+    pub fn try_new_divide(lhs: DynProofExpr<C>, rhs: DynProofExpr<C>) -> ConversionResult<Self> {
+        Ok(Self::Divide(DivideAndModuloExpr::try_new(
+            Box::new(lhs),
+            Box::new(rhs),
+        )?))
+    }
+
+    /// Create a new integer modulo expression, whose result has the sign of `lhs`
+    pub fn try_new_modulo(lhs: DynProofExpr<C>, rhs: DynProofExpr<C>) -> ConversionResult<Self> {
+        Ok(Self::Modulo(DivideAndModuloExpr::try_new(
+            Box::new(lhs),
+            Box::new(rhs),
+        )?))
+    }
+
+    /// Create a new `POW` expression with a constant exponent
+    pub fn try_new_pow(base: DynProofExpr<C>, exponent: u8) -> ConversionResult<Self> {
+        Ok(Self::Pow(PowExpr::try_new(Box::new(base), exponent)?))
+    }
+
+    /// Create a new `ROUND` expression rounding a decimal to a constant number of digits
+    pub fn try_new_round(input: DynProofExpr<C>, digits: i8) -> ConversionResult<Self> {
+        Ok(Self::Round(RoundExpr::try_new(Box::new(input), digits)?))
+    }
+
     /// Create a new aggregate expression
     pub fn new_aggregate(op: AggregationOperator, expr: DynProofExpr<C>) -> Self {
         Self::Aggregate(AggregateExpr::new(op, Box::new(expr)))
     }
 
+    /// The nesting depth of the expression, where a column or literal has depth 1
+    ///
+    /// The tree is walked with an explicit worklist, so this is safe to call on arbitrarily deep expressions.
+    pub fn depth(&self) -> usize {
+        let mut max_depth = 0;
+        let mut worklist = vec![(self, 1)];
+        while let Some((expr, depth)) = worklist.pop() {
+            max_depth = max_depth.max(depth);
+            match expr {
+                Self::Column(_) | Self::Literal(_) => {}
+                Self::And(expr) => worklist.extend(expr.operands().map(|e| (e, depth + 1))),
+                Self::Or(expr) => worklist.extend(expr.operands().map(|e| (e, depth + 1))),
+                Self::Equals(expr) => worklist.extend(expr.operands().map(|e| (e, depth + 1))),
+                Self::Inequality(expr) => {
+                    worklist.extend(expr.operands().map(|e| (e, depth + 1)));
+                }
+                Self::AddSubtract(expr) => {
+                    worklist.extend(expr.operands().map(|e| (e, depth + 1)));
+                }
+                Self::Multiply(expr) => worklist.extend(expr.operands().map(|e| (e, depth + 1))),
+                Self::Divide(expr) | Self::Modulo(expr) => {
+                    worklist.extend(expr.operands().map(|e| (e, depth + 1)));
+                }
+                Self::Not(expr) => worklist.push((expr.input(), depth + 1)),
+                Self::Aggregate(expr) => worklist.push((expr.input(), depth + 1)),
+                Self::Pow(expr) => worklist.push((expr.product(), depth + 1)),
+                Self::Round(expr) => worklist.push((expr.input_and_digits().0, depth + 1)),
+            }
+        }
+        max_depth
+    }
+
+    /// Check that the expression is not nested deeper than `max_depth`
+    ///
+    /// Proving and verifying recurse through the expression tree,
+    /// so this should be checked before handing an untrusted expression to the prover.
+    ///
+    /// # Errors
+    /// Returns [`ConversionError::PlanTooDeep`] if the depth of the expression exceeds `max_depth`.
+    pub fn check_depth(&self, max_depth: usize) -> ConversionResult<()> {
+        let depth = self.depth();
+        if depth > max_depth {
+            Err(ConversionError::PlanTooDeep { depth, max_depth })
+        } else {
+            Ok(())
+        }
+    }
+
     /// Check that the plan has the correct data type
     fn check_data_type(&self, data_type: ColumnType) -> ConversionResult<()> {
         if self.data_type() == data_type {
@@ -178,6 +336,86 @@ impl<C: Commitment> DynProofExpr<C> {
             })
         }
     }
+
+    /// The value of the expression if it is a boolean literal
+    fn as_boolean_literal(&self) -> Option<bool> {
+        match self {
+            Self::Literal(literal) => match literal.value() {
+                LiteralValue::Boolean(value) => Some(*value),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+/// Renders the expression as approximate SQL, for debugging.
+///
+/// Operands that are themselves operations are parenthesized, so the rendering is unambiguous,
+/// but it is not guaranteed to parse back into the same expression.
+impl<C: Commitment> Display for DynProofExpr<C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let binary = |f: &mut Formatter<'_>, [lhs, rhs]: [&Self; 2], op: &str| {
+            write!(f, "{} {op} {}", Operand(lhs), Operand(rhs))
+        };
+        match self {
+            Self::Column(expr) => {
+                let column_ref = expr.get_column_reference();
+                if let Some(table_alias) = column_ref.table_alias() {
+                    write!(f, "{table_alias}.")?;
+                }
+                write!(f, "{}", column_ref.column_id())
+            }
+            Self::Literal(expr) => write!(f, "{}", expr.value()),
+            Self::And(expr) => binary(f, expr.operands(), "AND"),
+            Self::Or(expr) => binary(f, expr.operands(), "OR"),
+            Self::Not(expr) => write!(f, "NOT {}", Operand(expr.input())),
+            Self::Equals(expr) => binary(f, expr.operands(), "="),
+            Self::Inequality(expr) => {
+                binary(f, expr.operands(), if expr.is_lte() { "<=" } else { ">=" })
+            }
+            Self::AddSubtract(expr) => binary(
+                f,
+                expr.operands(),
+                if expr.is_subtract() { "-" } else { "+" },
+            ),
+            Self::Multiply(expr) => binary(f, expr.operands(), "*"),
+            Self::Divide(expr) => binary(f, expr.operands(), "/"),
+            Self::Modulo(expr) => binary(f, expr.operands(), "%"),
+            Self::Pow(expr) => {
+                let (base, exponent) = expr.base_and_exponent();
+                write!(f, "POWER({base}, {exponent})")
+            }
+            Self::Round(expr) => {
+                let (input, digits) = expr.input_and_digits();
+                write!(f, "ROUND({input}, {digits})")
+            }
+            Self::Aggregate(expr) => {
+                write!(
+                    f,
+                    "{}({})",
+                    expr.op().to_string().to_uppercase(),
+                    expr.input()
+                )
+            }
+        }
+    }
+}
+
+/// An operand of a rendered expression, parenthesized unless it is a single term.
+struct Operand<'a, C: Commitment>(&'a DynProofExpr<C>);
+
+impl<C: Commitment> Display for Operand<'_, C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            DynProofExpr::Column(_)
+            | DynProofExpr::Literal(_)
+            | DynProofExpr::Pow(_)
+            | DynProofExpr::Round(_)
+            | DynProofExpr::Aggregate(_) => write!(f, "{}", self.0),
+            expr => write!(f, "({expr})"),
+        }
+    }
 }
 
 impl<C: Commitment> ProofExpr<C> for DynProofExpr<C> {
@@ -192,6 +430,9 @@ impl<C: Commitment> ProofExpr<C> for DynProofExpr<C> {
             DynProofExpr::Inequality(expr) => ProofExpr::<C>::count(expr, builder),
             DynProofExpr::AddSubtract(expr) => ProofExpr::<C>::count(expr, builder),
             DynProofExpr::Multiply(expr) => ProofExpr::<C>::count(expr, builder),
+            DynProofExpr::Divide(expr) | DynProofExpr::Modulo(expr) => expr.count(builder),
+            DynProofExpr::Pow(expr) => ProofExpr::<C>::count(expr, builder),
+            DynProofExpr::Round(expr) => ProofExpr::<C>::count(expr, builder),
             DynProofExpr::Aggregate(expr) => ProofExpr::<C>::count(expr, builder),
         }
     }
@@ -201,6 +442,10 @@ impl<C: Commitment> ProofExpr<C> for DynProofExpr<C> {
             DynProofExpr::Column(expr) => expr.data_type(),
             DynProofExpr::AddSubtract(expr) => expr.data_type(),
             DynProofExpr::Multiply(expr) => expr.data_type(),
+            DynProofExpr::Divide(expr) => expr.data_type().0,
+            DynProofExpr::Modulo(expr) => expr.data_type().1,
+            DynProofExpr::Pow(expr) => expr.data_type(),
+            DynProofExpr::Round(expr) => expr.data_type(),
             DynProofExpr::Aggregate(expr) => expr.data_type(),
             DynProofExpr::Literal(expr) => ProofExpr::<C>::data_type(expr),
             DynProofExpr::And(_)
@@ -245,6 +490,14 @@ impl<C: Commitment> ProofExpr<C> for DynProofExpr<C> {
             DynProofExpr::Multiply(expr) => {
                 ProofExpr::<C>::result_evaluate(expr, table_length, alloc, accessor)
             }
+            DynProofExpr::Divide(expr) => expr.result_evaluate(table_length, alloc, accessor).0,
+            DynProofExpr::Modulo(expr) => expr.result_evaluate(table_length, alloc, accessor).1,
+            DynProofExpr::Pow(expr) => {
+                ProofExpr::<C>::result_evaluate(expr, table_length, alloc, accessor)
+            }
+            DynProofExpr::Round(expr) => {
+                ProofExpr::<C>::result_evaluate(expr, table_length, alloc, accessor)
+            }
             DynProofExpr::Aggregate(expr) => {
                 ProofExpr::<C>::result_evaluate(expr, table_length, alloc, accessor)
             }
@@ -285,6 +538,14 @@ impl<C: Commitment> ProofExpr<C> for DynProofExpr<C> {
             DynProofExpr::Multiply(expr) => {
                 ProofExpr::<C>::prover_evaluate(expr, builder, alloc, accessor)
             }
+            DynProofExpr::Divide(expr) => expr.prover_evaluate(builder, alloc, accessor).0,
+            DynProofExpr::Modulo(expr) => expr.prover_evaluate(builder, alloc, accessor).1,
+            DynProofExpr::Pow(expr) => {
+                ProofExpr::<C>::prover_evaluate(expr, builder, alloc, accessor)
+            }
+            DynProofExpr::Round(expr) => {
+                ProofExpr::<C>::prover_evaluate(expr, builder, alloc, accessor)
+            }
             DynProofExpr::Aggregate(expr) => {
                 ProofExpr::<C>::prover_evaluate(expr, builder, alloc, accessor)
             }
@@ -308,6 +569,10 @@ impl<C: Commitment> ProofExpr<C> for DynProofExpr<C> {
             DynProofExpr::Inequality(expr) => expr.verifier_evaluate(builder, accessor),
             DynProofExpr::AddSubtract(expr) => expr.verifier_evaluate(builder, accessor),
             DynProofExpr::Multiply(expr) => expr.verifier_evaluate(builder, accessor),
+            DynProofExpr::Divide(expr) => Ok(expr.verifier_evaluate(builder, accessor)?.0),
+            DynProofExpr::Modulo(expr) => Ok(expr.verifier_evaluate(builder, accessor)?.1),
+            DynProofExpr::Pow(expr) => expr.verifier_evaluate(builder, accessor),
+            DynProofExpr::Round(expr) => expr.verifier_evaluate(builder, accessor),
             DynProofExpr::Aggregate(expr) => expr.verifier_evaluate(builder, accessor),
         }
     }
@@ -323,6 +588,11 @@ impl<C: Commitment> ProofExpr<C> for DynProofExpr<C> {
             DynProofExpr::Inequality(expr) => ProofExpr::<C>::get_column_references(expr, columns),
             DynProofExpr::AddSubtract(expr) => ProofExpr::<C>::get_column_references(expr, columns),
             DynProofExpr::Multiply(expr) => ProofExpr::<C>::get_column_references(expr, columns),
+            DynProofExpr::Divide(expr) | DynProofExpr::Modulo(expr) => {
+                expr.get_column_references(columns);
+            }
+            DynProofExpr::Pow(expr) => ProofExpr::<C>::get_column_references(expr, columns),
+            DynProofExpr::Round(expr) => ProofExpr::<C>::get_column_references(expr, columns),
             DynProofExpr::Aggregate(expr) => ProofExpr::<C>::get_column_references(expr, columns),
         }
     }
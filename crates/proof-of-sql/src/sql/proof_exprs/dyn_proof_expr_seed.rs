@@ -0,0 +1,387 @@
+use super::{
+    AddSubtractExpr, AggregateExpr, AndExpr, DivideAndModuloExpr, DynProofExpr, EqualsExpr,
+    InequalityExpr, MultiplyExpr, NotExpr, OrExpr, PowExpr, RoundExpr,
+};
+use crate::{
+    base::commitment::Commitment,
+    sql::parse::{ConversionError, ConversionResult},
+};
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::{
+    fmt::{self, Formatter},
+    marker::PhantomData,
+};
+use serde::{
+    de::{
+        value::UnitDeserializer, DeserializeSeed, EnumAccess, Error, IgnoredAny, MapAccess,
+        SeqAccess, VariantAccess, Visitor,
+    },
+    Deserialize, Deserializer,
+};
+
+/// Deserializes a [`DynProofExpr`] that is nested at most `max_depth` deep.
+///
+/// A derived deserializer recurses once per level of the expression, so a deeply nested
+/// untrusted plan could overflow the stack before its depth is ever checked.
+/// This seed counts the levels as it deserializes and fails with [`ConversionError::PlanTooDeep`]
+/// at the first level past `max_depth`, so the recursion is bounded by `max_depth`.
+///
+/// The wire format is the same as that of a derived deserializer.
+pub(crate) struct DynProofExprSeed<C> {
+    depth: usize,
+    max_depth: usize,
+    _commitment: PhantomData<C>,
+}
+
+impl<C> Clone for DynProofExprSeed<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<C> Copy for DynProofExprSeed<C> {}
+
+impl<C> DynProofExprSeed<C> {
+    /// Seed for a top-level expression
+    pub(crate) fn new(max_depth: usize) -> Self {
+        Self {
+            depth: 1,
+            max_depth,
+            _commitment: PhantomData,
+        }
+    }
+
+    /// Seed for the operands of an expression at this seed's level
+    fn operand(self) -> Self {
+        Self {
+            depth: self.depth + 1,
+            ..self
+        }
+    }
+}
+
+/// The variants of [`DynProofExpr`], in order
+#[derive(Deserialize)]
+#[serde(variant_identifier)]
+enum Variant {
+    Column,
+    And,
+    Or,
+    Not,
+    Literal,
+    Equals,
+    Inequality,
+    AddSubtract,
+    Multiply,
+    Aggregate,
+    Divide,
+    Modulo,
+    Pow,
+    Round,
+}
+
+const VARIANTS: &[&str] = &[
+    "Column",
+    "And",
+    "Or",
+    "Not",
+    "Literal",
+    "Equals",
+    "Inequality",
+    "AddSubtract",
+    "Multiply",
+    "Aggregate",
+    "Divide",
+    "Modulo",
+    "Pow",
+    "Round",
+];
+
+const BINARY_FIELDS: &[&str] = &["lhs", "rhs"];
+
+impl<'de, C: Commitment + Deserialize<'de>> DeserializeSeed<'de> for DynProofExprSeed<C> {
+    type Value = DynProofExpr<C>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if self.depth > self.max_depth {
+            return Err(D::Error::custom(ConversionError::PlanTooDeep {
+                depth: self.depth,
+                max_depth: self.max_depth,
+            }));
+        }
+        deserializer.deserialize_enum("DynProofExpr", VARIANTS, self)
+    }
+}
+
+impl<'de, C: Commitment + Deserialize<'de>> Visitor<'de> for DynProofExprSeed<C> {
+    type Value = DynProofExpr<C>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("enum DynProofExpr")
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let (variant, access) = data.variant::<Variant>()?;
+        match variant {
+            Variant::Column => Ok(DynProofExpr::Column(access.newtype_variant()?)),
+            Variant::Literal => Ok(DynProofExpr::Literal(access.newtype_variant()?)),
+            Variant::And => {
+                self.variant(access, "AndExpr", BINARY_FIELDS, None, |[lhs, rhs], ()| {
+                    Ok(DynProofExpr::And(AndExpr::new(lhs, rhs)))
+                })
+            }
+            Variant::Or => self.variant(access, "OrExpr", BINARY_FIELDS, None, |[lhs, rhs], ()| {
+                Ok(DynProofExpr::Or(OrExpr::new(lhs, rhs)))
+            }),
+            Variant::Not => self.variant(access, "NotExpr", &["expr"], None, |[expr], ()| {
+                Ok(DynProofExpr::Not(NotExpr::new(expr)))
+            }),
+            Variant::Equals => self.variant(
+                access,
+                "EqualsExpr",
+                BINARY_FIELDS,
+                None,
+                |[lhs, rhs], ()| Ok(DynProofExpr::Equals(EqualsExpr::new(lhs, rhs))),
+            ),
+            Variant::Inequality => self.variant(
+                access,
+                "InequalityExpr",
+                &["lhs", "rhs", "is_lte"],
+                Some(2),
+                |[lhs, rhs], is_lte| {
+                    Ok(DynProofExpr::Inequality(InequalityExpr::new(
+                        lhs, rhs, is_lte,
+                    )))
+                },
+            ),
+            Variant::AddSubtract => self.variant(
+                access,
+                "AddSubtractExpr",
+                &["lhs", "rhs", "is_subtract"],
+                Some(2),
+                |[lhs, rhs], is_subtract| {
+                    Ok(DynProofExpr::AddSubtract(AddSubtractExpr::new(
+                        lhs,
+                        rhs,
+                        is_subtract,
+                    )))
+                },
+            ),
+            Variant::Multiply => self.variant(
+                access,
+                "MultiplyExpr",
+                BINARY_FIELDS,
+                None,
+                |[lhs, rhs], ()| Ok(DynProofExpr::Multiply(MultiplyExpr::new(lhs, rhs))),
+            ),
+            Variant::Aggregate => self.variant(
+                access,
+                "AggregateExpr",
+                &["op", "expr"],
+                Some(0),
+                |[expr], op| Ok(DynProofExpr::Aggregate(AggregateExpr::new(op, expr))),
+            ),
+            Variant::Divide => self.variant(
+                access,
+                "DivideAndModuloExpr",
+                BINARY_FIELDS,
+                None,
+                |[lhs, rhs], ()| {
+                    Ok(DynProofExpr::Divide(DivideAndModuloExpr::try_new(
+                        lhs, rhs,
+                    )?))
+                },
+            ),
+            Variant::Modulo => self.variant(
+                access,
+                "DivideAndModuloExpr",
+                BINARY_FIELDS,
+                None,
+                |[lhs, rhs], ()| {
+                    Ok(DynProofExpr::Modulo(DivideAndModuloExpr::try_new(
+                        lhs, rhs,
+                    )?))
+                },
+            ),
+            Variant::Pow => self.variant(
+                access,
+                "PowExpr",
+                &["base", "exponent"],
+                Some(1),
+                |[base], exponent| Ok(DynProofExpr::Pow(PowExpr::try_new(base, exponent)?)),
+            ),
+            Variant::Round => self.variant(
+                access,
+                "RoundExpr",
+                &["input", "digits"],
+                Some(1),
+                |[input], digits| Ok(DynProofExpr::Round(RoundExpr::try_new(input, digits)?)),
+            ),
+        }
+    }
+}
+
+impl<'de, C: Commitment + Deserialize<'de>> DynProofExprSeed<C> {
+    /// Deserialize the expression struct of a variant with the given fields and `build` the expression from it.
+    ///
+    /// Every field except the one at `extra`, if any, is an operand.
+    ///
+    /// Each variant is built in its own function, rather than inline in [`Visitor::visit_enum`],
+    /// to keep the stack frame of each level of the expression small.
+    fn variant<A, E, const N: usize>(
+        self,
+        access: A,
+        name: &'static str,
+        fields: &'static [&'static str],
+        extra: Option<usize>,
+        build: impl FnOnce(Operands<C, N>, E) -> ConversionResult<DynProofExpr<C>>,
+    ) -> Result<DynProofExpr<C>, A::Error>
+    where
+        A: VariantAccess<'de>,
+        E: Deserialize<'de>,
+    {
+        let (operands, extra) = access.newtype_variant_seed(OperandsSeed {
+            name,
+            fields,
+            extra,
+            operand: self.operand(),
+            _extra: PhantomData,
+        })?;
+        build(operands, extra).map_err(A::Error::custom)
+    }
+}
+
+/// The operands of an expression struct
+type Operands<C, const N: usize> = [Box<DynProofExpr<C>>; N];
+
+/// Deserializes the `N` operands of an expression struct with a [`DynProofExprSeed`],
+/// along with its one field of type `E` that is not an operand, if it has one.
+struct OperandsSeed<C, E, const N: usize> {
+    name: &'static str,
+    fields: &'static [&'static str],
+    /// The position of the field that is not an operand
+    extra: Option<usize>,
+    operand: DynProofExprSeed<C>,
+    _extra: PhantomData<E>,
+}
+
+impl<C, E, const N: usize> OperandsSeed<C, E, N> {
+    /// The position of the field at `index` among the operands
+    fn operand_index(&self, index: usize) -> usize {
+        index - usize::from(self.extra.is_some_and(|extra| extra < index))
+    }
+}
+
+impl<'de, C, E, const N: usize> DeserializeSeed<'de> for OperandsSeed<C, E, N>
+where
+    C: Commitment + Deserialize<'de>,
+    E: Deserialize<'de>,
+{
+    type Value = (Operands<C, N>, E);
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(self.name, self.fields, self)
+    }
+}
+
+impl<'de, C, E, const N: usize> Visitor<'de> for OperandsSeed<C, E, N>
+where
+    C: Commitment + Deserialize<'de>,
+    E: Deserialize<'de>,
+{
+    type Value = (Operands<C, N>, E);
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "struct {}", self.name)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut operands = Vec::with_capacity(N);
+        let mut extra = None;
+        for index in 0..self.fields.len() {
+            if self.extra == Some(index) {
+                extra = Some(
+                    seq.next_element()?
+                        .ok_or_else(|| A::Error::invalid_length(index, &self))?,
+                );
+            } else {
+                operands.push(Some(Box::new(
+                    seq.next_element_seed(self.operand)?
+                        .ok_or_else(|| A::Error::invalid_length(index, &self))?,
+                )));
+            }
+        }
+        self.finish(operands, extra)
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut operands = Vec::new();
+        operands.resize_with(N, || None);
+        let mut extra = None;
+        while let Some(key) = map.next_key::<String>()? {
+            match self.fields.iter().position(|field| *field == key) {
+                Some(index) if self.extra == Some(index) => {
+                    if extra.is_some() {
+                        return Err(A::Error::duplicate_field(self.fields[index]));
+                    }
+                    extra = Some(map.next_value()?);
+                }
+                Some(index) => {
+                    let operand = &mut operands[self.operand_index(index)];
+                    if operand.is_some() {
+                        return Err(A::Error::duplicate_field(self.fields[index]));
+                    }
+                    *operand = Some(Box::new(map.next_value_seed(self.operand)?));
+                }
+                None => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        self.finish(operands, extra)
+    }
+}
+
+impl<'de, C, E, const N: usize> OperandsSeed<C, E, N>
+where
+    C: Commitment + Deserialize<'de>,
+    E: Deserialize<'de>,
+{
+    /// Check that no field is missing
+    fn finish<Err: Error>(
+        &self,
+        operands: Vec<Option<Box<DynProofExpr<C>>>>,
+        extra: Option<E>,
+    ) -> Result<(Operands<C, N>, E), Err> {
+        let extra = match (self.extra, extra) {
+            (_, Some(extra)) => extra,
+            (Some(index), None) => return Err(Err::missing_field(self.fields[index])),
+            // A struct without an extra field has `E = ()`
+            (None, None) => E::deserialize(UnitDeserializer::<Err>::new())?,
+        };
+        let operand_fields = (0..self.fields.len()).filter(|index| self.extra != Some(*index));
+        let operands = operands
+            .into_iter()
+            .zip(operand_fields)
+            .map(|(operand, index)| operand.ok_or_else(|| Err::missing_field(self.fields[index])))
+            .collect::<Result<Vec<_>, _>>()?;
+        let operands = operands
+            .try_into()
+            .map_err(|operands: Vec<_>| Err::invalid_length(operands.len(), self))?;
+        Ok((operands, extra))
+    }
+}
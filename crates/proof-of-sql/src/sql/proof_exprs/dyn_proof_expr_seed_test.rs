@@ -0,0 +1,78 @@
+use super::{test_utility::*, DynProofExpr, DEFAULT_MAX_EXPR_DEPTH};
+use crate::base::{
+    commitment::InnerProductProof,
+    database::{owned_table_utility::*, OwnedTableTestAccessor},
+};
+use curve25519_dalek::ristretto::RistrettoPoint;
+
+/// An expression with every kind of operand struct, i.e. with and without a non-operand field
+fn expr_of_every_kind(
+    accessor: &OwnedTableTestAccessor<InnerProductProof>,
+) -> DynProofExpr<RistrettoPoint> {
+    let t = "sxt.t".parse().unwrap();
+    let a = || column(t, "a", accessor);
+    let d = || column(t, "d", accessor);
+    and(
+        or(
+            not(equal(a(), const_bigint(1))),
+            gte(
+                subtract(multiply(a(), a()), divide(a(), const_bigint(2))),
+                modulo(add(a(), const_bigint(1)), a()),
+            ),
+        ),
+        equal(add(pow(a(), 2), sum_expr(a(), "s").expr), round(d(), 1)),
+    )
+}
+
+#[test]
+fn we_can_roundtrip_an_expression_of_every_kind_through_postcard_and_json() {
+    let data = owned_table([bigint("a", [1_i64]), decimal75("d", 10, 2, [100_i64])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let expr = expr_of_every_kind(&accessor);
+
+    let bytes = postcard::to_allocvec(&expr).unwrap();
+    let deserialized: DynProofExpr<RistrettoPoint> = postcard::from_bytes(&bytes).unwrap();
+    assert_eq!(deserialized, expr);
+
+    let json = serde_json::to_value(&expr).unwrap();
+    let deserialized: DynProofExpr<RistrettoPoint> = serde_json::from_value(json).unwrap();
+    assert_eq!(deserialized, expr);
+}
+
+#[test]
+fn we_cannot_deserialize_an_expression_with_a_missing_operand() {
+    let data = owned_table([bigint("a", [1_i64])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let lhs = serde_json::to_value(column::<RistrettoPoint>(t, "a", &accessor)).unwrap();
+    let json = serde_json::json!({ "AddSubtract": { "lhs": lhs, "is_subtract": false } });
+    let err = serde_json::from_value::<DynProofExpr<RistrettoPoint>>(json).unwrap_err();
+    assert!(err.to_string().contains("missing field `rhs`"));
+}
+
+#[test]
+fn we_cannot_deserialize_an_expression_nested_too_deep_without_recursing_through_it() {
+    // Postcard encodes `NOT` as its variant index followed by its operand, so this is a chain of
+    // a million `NOT`s. Recursing through all of it would overflow the stack.
+    let bytes = vec![3_u8; 1_000_000];
+    let err = postcard::from_bytes::<DynProofExpr<RistrettoPoint>>(&bytes).unwrap_err();
+    assert_eq!(err, postcard::Error::SerdeDeCustom);
+}
+
+#[test]
+fn we_can_deserialize_an_expression_up_to_the_maximum_depth() {
+    let data = owned_table([bigint("a", [1_i64])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let nested = |depth| {
+        (1..depth).fold(
+            equal(column(t, "a", &accessor), const_bigint(1)),
+            |expr: DynProofExpr<RistrettoPoint>, _| not(expr),
+        )
+    };
+    let bytes = postcard::to_allocvec(&nested(DEFAULT_MAX_EXPR_DEPTH - 1)).unwrap();
+    assert!(postcard::from_bytes::<DynProofExpr<RistrettoPoint>>(&bytes).is_ok());
+    let bytes = postcard::to_allocvec(&nested(DEFAULT_MAX_EXPR_DEPTH)).unwrap();
+    assert!(postcard::from_bytes::<DynProofExpr<RistrettoPoint>>(&bytes).is_err());
+}
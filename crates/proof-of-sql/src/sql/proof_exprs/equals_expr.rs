@@ -26,6 +26,11 @@ impl<C: Commitment> EqualsExpr<C> {
     pub fn new(lhs: Box<DynProofExpr<C>>, rhs: Box<DynProofExpr<C>>) -> Self {
         Self { lhs, rhs }
     }
+
+    /// The left and right operands of the expression
+    pub(crate) fn operands(&self) -> [&DynProofExpr<C>; 2] {
+        [&self.lhs, &self.rhs]
+    }
 }
 
 impl<C: Commitment> ProofExpr<C> for EqualsExpr<C> {
@@ -314,6 +314,65 @@ fn we_can_prove_an_equality_query_with_a_string_comparison() {
     assert_eq!(res, expected_res);
 }
 
+#[test]
+fn we_can_prove_an_equality_query_with_a_binary_comparison() {
+    let data: OwnedTable<Curve25519Scalar> = owned_table([
+        bigint("a", [1, 2, 3, 4, 5]),
+        varbinary(
+            "c",
+            [
+                &[0xff, 0x00, 0xfe][..],
+                b"",
+                b"abc",
+                &[0xff, 0x00, 0xfe],
+                &[0xff, 0x00],
+            ],
+        ),
+        varchar("d", ["abc", "", "abc", "t", "abc"]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = filter(
+        cols_expr_plan(t, &["a", "c"], &accessor),
+        tab(t),
+        equal(
+            column(t, "c", &accessor),
+            const_varbinary(&[0xff, 0x00, 0xfe]),
+        ),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([
+        bigint("a", [1, 4]),
+        varbinary("c", [[0xff, 0x00, 0xfe], [0xff, 0x00, 0xfe]]),
+    ]);
+    assert_eq!(res, expected_res);
+
+    // An empty binary literal only matches empty values.
+    let ast = filter(
+        cols_expr_plan(t, &["a"], &accessor),
+        tab(t),
+        equal(column(t, "c", &accessor), const_varbinary(b"")),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    assert_eq!(res, owned_table([bigint("a", [2])]));
+}
+
+#[test]
+fn we_cannot_compare_a_binary_column_to_a_string_literal() {
+    let data: OwnedTable<Curve25519Scalar> = owned_table([varbinary("c", [b"abc"])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    assert!(DynProofExpr::<RistrettoPoint>::try_new_equals(
+        column(t, "c", &accessor),
+        const_varchar("abc")
+    )
+    .is_err());
+}
+
 fn test_random_tables_with_given_offset(offset: usize) {
     let dist = Uniform::new(-3, 4);
     let mut rng = StdRng::from_seed([0u8; 32]);
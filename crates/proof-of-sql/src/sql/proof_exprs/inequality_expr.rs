@@ -24,6 +24,7 @@ pub struct InequalityExpr<C: Commitment> {
     rhs: Box<DynProofExpr<C>>,
     is_lte: bool,
     #[cfg(test)]
+    #[serde(skip)]
     pub(crate) treat_column_of_zeros_as_negative: bool,
 }
 
@@ -38,6 +39,16 @@ impl<C: Commitment> InequalityExpr<C> {
             treat_column_of_zeros_as_negative: false,
         }
     }
+
+    /// The left and right operands of the expression
+    pub(crate) fn operands(&self) -> [&DynProofExpr<C>; 2] {
+        [&self.lhs, &self.rhs]
+    }
+
+    /// Whether the expression is `lhs <= rhs` rather than `lhs >= rhs`
+    pub(crate) fn is_lte(&self) -> bool {
+        self.is_lte
+    }
 }
 
 impl<C: Commitment> ProofExpr<C> for InequalityExpr<C> {
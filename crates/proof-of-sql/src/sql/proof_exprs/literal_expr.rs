@@ -33,6 +33,11 @@ impl<S: Scalar> LiteralExpr<S> {
     pub fn new(value: LiteralValue<S>) -> Self {
         Self { value }
     }
+
+    /// The value of the literal
+    pub(crate) fn value(&self) -> &LiteralValue<S> {
+        &self.value
+    }
 }
 
 impl<C: Commitment> ProofExpr<C> for LiteralExpr<C::Scalar> {
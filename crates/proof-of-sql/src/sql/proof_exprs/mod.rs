@@ -20,6 +20,21 @@ use multiply_expr::MultiplyExpr;
 #[cfg(all(test, feature = "blitzar"))]
 mod multiply_expr_test;
 
+mod pow_expr;
+use pow_expr::PowExpr;
+#[cfg(all(test, feature = "blitzar"))]
+mod pow_expr_test;
+
+mod round_expr;
+use round_expr::RoundExpr;
+#[cfg(all(test, feature = "blitzar"))]
+mod round_expr_test;
+
+mod divide_and_modulo_expr;
+pub use divide_and_modulo_expr::DivideAndModuloExpr;
+#[cfg(all(test, feature = "blitzar"))]
+mod divide_and_modulo_expr_test;
+
 mod bitwise_verification;
 use bitwise_verification::{
     is_within_acceptable_range, verify_constant_abs_decomposition,
@@ -29,7 +44,11 @@ use bitwise_verification::{
 mod bitwise_verification_test;
 
 mod dyn_proof_expr;
+mod dyn_proof_expr_seed;
+#[cfg(all(test, feature = "blitzar"))]
+mod dyn_proof_expr_seed_test;
 pub(crate) use dyn_proof_expr::DynProofExpr;
+pub use dyn_proof_expr::DEFAULT_MAX_EXPR_DEPTH;
 
 mod literal_expr;
 pub(crate) use literal_expr::LiteralExpr;
@@ -61,7 +80,7 @@ pub(crate) use comparison_util::scale_and_subtract;
 
 mod numerical_util;
 pub(crate) use numerical_util::{
-    add_subtract_columns, multiply_columns, scale_and_add_subtract_eval,
+    add_subtract_columns, column_of_type, multiply_columns, scale_and_add_subtract_eval,
 };
 
 mod equals_expr;
@@ -82,6 +101,8 @@ pub(crate) use table_expr::TableExpr;
 
 #[cfg(test)]
 pub(crate) mod test_utility;
+#[cfg(all(test, feature = "blitzar"))]
+mod test_utility_test;
 
 mod column_expr;
 pub(crate) use column_expr::ColumnExpr;
@@ -11,7 +11,7 @@ use crate::{
     },
     sql::{
         proof::{CountBuilder, FinalRoundBuilder, SumcheckSubpolynomialType, VerificationBuilder},
-        proof_exprs::multiply_columns,
+        proof_exprs::{column_of_type, multiply_columns},
     },
 };
 use alloc::{boxed::Box, vec};
@@ -31,6 +31,11 @@ impl<C: Commitment> MultiplyExpr<C> {
     pub fn new(lhs: Box<DynProofExpr<C>>, rhs: Box<DynProofExpr<C>>) -> Self {
         Self { lhs, rhs }
     }
+
+    /// The left and right operands of the expression
+    pub(crate) fn operands(&self) -> [&DynProofExpr<C>; 2] {
+        [&self.lhs, &self.rhs]
+    }
 }
 
 impl<C: Commitment> ProofExpr<C> for MultiplyExpr<C> {
@@ -59,7 +64,7 @@ impl<C: Commitment> ProofExpr<C> for MultiplyExpr<C> {
         let rhs_column: Column<'a, C::Scalar> =
             self.rhs.result_evaluate(table_length, alloc, accessor);
         let scalars = multiply_columns(&lhs_column, &rhs_column, alloc);
-        Column::Scalar(scalars)
+        column_of_type(scalars, self.data_type())
     }
 
     #[tracing::instrument(
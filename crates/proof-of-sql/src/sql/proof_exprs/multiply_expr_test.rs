@@ -2,6 +2,7 @@ use crate::{
     base::{
         commitment::InnerProductProof,
         database::{owned_table_utility::*, Column, OwnedTableTestAccessor},
+        math::decimal::Precision,
         scalar::Curve25519Scalar,
     },
     sql::{
@@ -68,6 +69,31 @@ fn we_can_prove_a_typical_multiply_query() {
     assert_eq!(res, expected_res);
 }
 
+// select a * b as product from sxt.t
+// with a of type decimal(5, -2), i.e. multiples of 100
+#[test]
+fn we_can_prove_a_multiply_query_with_a_negative_scale_decimal() {
+    let data = owned_table([
+        decimal75("a", 5, -2, [7, -3, 0]),
+        decimal75("b", 3, 1, [12, 5, 999]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = projection(
+        vec![aliased_plan(
+            multiply(column(t, "a", &accessor), column(t, "b", &accessor)),
+            "product",
+        )],
+        tab(t),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    // 700 * 1.2 = 840, -300 * 0.5 = -150 and 0 * 99.9 = 0, at scale -1
+    let expected_res = owned_table([decimal75("product", 9, -1, [84, -15, 0])]);
+    assert_eq!(res, expected_res);
+}
+
 // Column type issue tests
 #[test]
 fn decimal_column_type_issues_error_out_when_producing_provable_ast() {
@@ -351,6 +377,6 @@ fn we_can_compute_the_correct_output_of_a_multiply_expr_using_result_evaluate()
         .iter()
         .map(|v| Curve25519Scalar::from(*v))
         .collect::<Vec<_>>();
-    let expected_res = Column::Scalar(&expected_res_scalar);
+    let expected_res = Column::Decimal75(Precision::new(18).unwrap(), 1, &expected_res_scalar);
     assert_eq!(res, expected_res);
 }
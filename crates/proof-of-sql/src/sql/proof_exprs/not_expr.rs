@@ -23,6 +23,11 @@ impl<C: Commitment> NotExpr<C> {
     pub fn new(expr: Box<DynProofExpr<C>>) -> Self {
         Self { expr }
     }
+
+    /// The operand of the expression
+    pub(crate) fn input(&self) -> &DynProofExpr<C> {
+        &self.expr
+    }
 }
 
 impl<C: Commitment> ProofExpr<C> for NotExpr<C> {
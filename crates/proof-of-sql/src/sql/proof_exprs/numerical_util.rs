@@ -1,5 +1,5 @@
 use crate::base::{
-    database::Column,
+    database::{Column, ColumnType},
     scalar::{Scalar, ScalarExt},
 };
 use bumpalo::Bump;
@@ -36,6 +36,17 @@ pub(crate) fn add_subtract_columns<'a, S: Scalar>(
     result
 }
 
+/// Wrap the scalars computed for an expression of type `column_type` in a column.
+///
+/// Decimal results keep their precision and scale, so the column carries the same type as the
+/// expression. Every other result stays a [`Column::Scalar`], which is encoded the same way.
+pub(crate) fn column_of_type<S: Scalar>(scalars: &[S], column_type: ColumnType) -> Column<'_, S> {
+    match column_type {
+        ColumnType::Decimal75(precision, scale) => Column::Decimal75(precision, scale, scalars),
+        _ => Column::Scalar(scalars),
+    }
+}
+
 /// Multiply two columns together.
 /// # Panics
 /// Panics if: The lengths of `lhs` and `rhs` are not equal.`lhs.scalar_at(i)` or `rhs.scalar_at(i)` returns `None`, which occurs if the column does not have, a scalar at the given index `i`.
@@ -68,8 +79,8 @@ pub(crate) fn scale_and_add_subtract_eval<S: Scalar>(
     is_subtract: bool,
 ) -> S {
     let max_scale = lhs_scale.max(rhs_scale);
-    let left_scaled_eval = lhs_eval * S::pow10(max_scale.abs_diff(lhs_scale));
-    let right_scaled_eval = rhs_eval * S::pow10(max_scale.abs_diff(rhs_scale));
+    let left_scaled_eval = lhs_eval * S::pow10(max_scale.abs_diff(lhs_scale).into());
+    let right_scaled_eval = rhs_eval * S::pow10(max_scale.abs_diff(rhs_scale).into());
     if is_subtract {
         left_scaled_eval - right_scaled_eval
     } else {
@@ -188,3 +188,43 @@ fn we_can_compute_the_correct_output_of_an_or_expr_using_result_evaluate() {
     let expected_res = Column::Boolean(&[false, true, true, true]);
     assert_eq!(res, expected_res);
 }
+
+#[test]
+fn we_fold_or_exprs_with_boolean_literals() {
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    let t = "sxt.t".parse().unwrap();
+    accessor.add_table(t, owned_table([boolean("p", [true, false])]), 0);
+    let p: DynProofExpr<RistrettoPoint> = column(t, "p", &accessor);
+    assert_eq!(or(const_bool(false), p.clone()), p);
+    assert_eq!(or(p.clone(), const_bool(false)), p);
+    assert_eq!(or(const_bool(true), p.clone()), const_bool(true));
+    assert_eq!(or(p.clone(), const_bool(true)), const_bool(true));
+    assert_eq!(not(const_bool(true)), const_bool::<RistrettoPoint>(false));
+    assert_eq!(not(or(p, const_bool(true))), const_bool(false));
+}
+
+#[test]
+fn we_can_prove_an_or_query_that_is_folded_to_a_boolean_literal() {
+    let data = owned_table([bigint("a", [1_i64, 2, 3]), bigint("b", [0_i64, 1, 0])]);
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    let t = "sxt.t".parse().unwrap();
+    accessor.add_table(t, data, 0);
+    let ast = projection(
+        vec![
+            aliased_plan(
+                or(
+                    const_bool(true),
+                    equal(column(t, "b", &accessor), const_bigint(1)),
+                ),
+                "c",
+            ),
+            col_expr_plan(t, "a", &accessor),
+        ],
+        tab(t),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([boolean("c", [true; 3]), bigint("a", [1_i64, 2, 3])]);
+    assert_eq!(res, expected_res);
+}
@@ -0,0 +1,157 @@
+use super::{DynProofExpr, ProofExpr};
+use crate::{
+    base::{
+        commitment::Commitment,
+        database::{Column, ColumnRef, ColumnType, CommitmentAccessor, DataAccessor, LiteralValue},
+        map::IndexSet,
+        proof::ProofError,
+        scalar::{Scalar, ScalarExt},
+    },
+    sql::{
+        parse::{ConversionError, ConversionResult},
+        proof::{CountBuilder, FinalRoundBuilder, VerificationBuilder},
+    },
+};
+use alloc::{boxed::Box, format};
+use bumpalo::Bump;
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// Provable numerical expression raising an expression to a constant power, i.e. `POW(base, exponent)`
+///
+/// The power is proven as the repeated multiplication `base * base * ... * base`,
+/// so the output type follows the widening rules of multiplication.
+/// Only the base and the exponent are serialized; the product is rebuilt when deserializing.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PowExpr<C: Commitment> {
+    base: Box<DynProofExpr<C>>,
+    exponent: u8,
+    #[serde(skip_serializing)]
+    product: Box<DynProofExpr<C>>,
+}
+
+/// The serialized fields of a [`PowExpr`]
+#[derive(Deserialize)]
+struct PowExprParts<C: Commitment> {
+    base: Box<DynProofExpr<C>>,
+    exponent: u8,
+}
+
+// Custom deserializer since the product is derived from, and must agree with, the base and the exponent
+impl<'de, C: Commitment + Deserialize<'de>> Deserialize<'de> for PowExpr<C> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let PowExprParts { base, exponent } = PowExprParts::deserialize(deserializer)?;
+        Self::try_new(base, exponent).map_err(serde::de::Error::custom)
+    }
+}
+
+impl<C: Commitment> PowExpr<C> {
+    /// Create numerical `POW` expression
+    ///
+    /// An exponent of 0 results in a column of ones of the base type, and an exponent of 1 in the base itself.
+    ///
+    /// # Errors
+    /// Returns an error if the base is not numeric, or if the result type is not representable,
+    /// e.g. a decimal whose precision would exceed 75.
+    pub fn try_new(base: Box<DynProofExpr<C>>, exponent: u8) -> ConversionResult<Self> {
+        let base_type = base.data_type();
+        if !base_type.is_numeric() {
+            return Err(ConversionError::InvalidExpression {
+                expression: format!("cannot raise a value of type {base_type} to a power"),
+            });
+        }
+        let product = if exponent == 0 {
+            DynProofExpr::new_literal(one_of_type(base_type)?)
+        } else {
+            (1..exponent).try_fold((*base).clone(), |product, _| {
+                DynProofExpr::try_new_multiply(product, (*base).clone())
+            })?
+        };
+        Ok(Self {
+            base,
+            exponent,
+            product: Box::new(product),
+        })
+    }
+
+    /// The repeated multiplication that the power is proven as
+    pub(crate) fn product(&self) -> &DynProofExpr<C> {
+        &self.product
+    }
+
+    /// The base and the exponent of the power
+    pub(crate) fn base_and_exponent(&self) -> (&DynProofExpr<C>, u8) {
+        (&self.base, self.exponent)
+    }
+}
+
+/// The literal one of the given numeric type
+fn one_of_type<S: Scalar>(column_type: ColumnType) -> ConversionResult<LiteralValue<S>> {
+    match column_type {
+        ColumnType::TinyInt => Ok(LiteralValue::TinyInt(1)),
+        ColumnType::SmallInt => Ok(LiteralValue::SmallInt(1)),
+        ColumnType::Int => Ok(LiteralValue::Int(1)),
+        ColumnType::BigInt => Ok(LiteralValue::BigInt(1)),
+        ColumnType::Int128 => Ok(LiteralValue::Int128(1)),
+        ColumnType::Scalar => Ok(LiteralValue::Scalar(S::ONE)),
+        ColumnType::Decimal75(precision, scale) => u8::try_from(scale)
+            .ok()
+            .filter(|&unsigned_scale| precision.value() > unsigned_scale)
+            .map(|unsigned_scale| {
+                LiteralValue::Decimal75(precision, scale, S::pow10(unsigned_scale.into()))
+            })
+            .ok_or_else(|| ConversionError::InvalidExpression {
+                expression: format!("one is not representable as {column_type}"),
+            }),
+        _ => Err(ConversionError::InvalidExpression {
+            expression: format!("cannot raise a value of type {column_type} to a power"),
+        }),
+    }
+}
+
+impl<C: Commitment> ProofExpr<C> for PowExpr<C> {
+    fn count(&self, builder: &mut CountBuilder) -> Result<(), ProofError> {
+        self.product.count(builder)
+    }
+
+    fn data_type(&self) -> ColumnType {
+        self.product.data_type()
+    }
+
+    fn result_evaluate<'a>(
+        &self,
+        table_length: usize,
+        alloc: &'a Bump,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> Column<'a, C::Scalar> {
+        self.product.result_evaluate(table_length, alloc, accessor)
+    }
+
+    #[tracing::instrument(
+        name = "proofs.sql.ast.pow_expr.prover_evaluate",
+        level = "info",
+        skip_all
+    )]
+    fn prover_evaluate<'a>(
+        &self,
+        builder: &mut FinalRoundBuilder<'a, C::Scalar>,
+        alloc: &'a Bump,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> Column<'a, C::Scalar> {
+        self.product.prover_evaluate(builder, alloc, accessor)
+    }
+
+    fn verifier_evaluate(
+        &self,
+        builder: &mut VerificationBuilder<C>,
+        accessor: &dyn CommitmentAccessor<C>,
+    ) -> Result<C::Scalar, ProofError> {
+        self.product.verifier_evaluate(builder, accessor)
+    }
+
+    fn get_column_references(&self, columns: &mut IndexSet<ColumnRef>) {
+        self.product.get_column_references(columns);
+    }
+}
@@ -0,0 +1,161 @@
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{owned_table_utility::*, ColumnType, OwnedTableTestAccessor},
+        math::decimal::Precision,
+    },
+    sql::{
+        parse::ConversionError,
+        proof::{exercise_verification, QueryError, VerifiableQueryResult},
+        proof_exprs::{test_utility::*, DynProofExpr, ProofExpr},
+        proof_plans::{test_utility::*, DynProofPlan},
+    },
+};
+use curve25519_dalek::ristretto::RistrettoPoint;
+
+#[test]
+fn we_can_prove_the_square_and_cube_of_a_bigint_column() {
+    let data = owned_table([bigint("a", [0_i64, 1, -2, 3, -1000])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let res = assert_expr_roundtrip(&pow(column(t, "a", &accessor), 2), &accessor, t);
+    assert_eq!(
+        res,
+        owned_table([bigint("expr", [0_i64, 1, 4, 9, 1_000_000])])
+    );
+    let res = assert_expr_roundtrip(&pow(column(t, "a", &accessor), 3), &accessor, t);
+    assert_eq!(
+        res,
+        owned_table([bigint("expr", [0_i64, 1, -8, 27, -1_000_000_000])])
+    );
+}
+
+#[test]
+fn we_can_prove_the_zeroth_and_first_powers_of_a_column() {
+    let data = owned_table([
+        bigint("a", [0_i64, 1, -2, 3]),
+        decimal75("b", 10, 2, [0_i64, 150, -275, 1]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    // The zeroth power is a constant, so we prove it alongside a column that the proof does depend on.
+    let ast: DynProofPlan<RistrettoPoint> = projection(
+        vec![
+            col_expr_plan(t, "a", &accessor),
+            aliased_plan(pow(column(t, "a", &accessor), 0), "c"),
+            aliased_plan(pow(column(t, "b", &accessor), 0), "d"),
+        ],
+        tab(t),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    assert_eq!(
+        res,
+        owned_table([
+            bigint("a", [0_i64, 1, -2, 3]),
+            bigint("c", [1_i64, 1, 1, 1]),
+            decimal75("d", 10, 2, [100_i64; 4]),
+        ])
+    );
+    let res = assert_expr_roundtrip(&pow(column(t, "a", &accessor), 1), &accessor, t);
+    assert_eq!(res, owned_table([bigint("expr", [0_i64, 1, -2, 3])]));
+}
+
+#[test]
+fn we_can_prove_the_square_of_a_decimal_column() {
+    let data = owned_table([decimal75("a", 10, 2, [0_i64, 150, -275])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let expr = pow(column::<RistrettoPoint>(t, "a", &accessor), 2);
+    assert_eq!(
+        expr.data_type(),
+        ColumnType::Decimal75(Precision::new(21).unwrap(), 4)
+    );
+    let res = assert_expr_roundtrip(&expr, &accessor, t);
+    assert_eq!(
+        res,
+        owned_table([decimal75("expr", 21, 4, [0_i64, 22500, 75625])])
+    );
+}
+
+#[test]
+fn we_cannot_raise_a_decimal_column_past_precision_75() {
+    let data = owned_table([decimal75("a", 25, 2, [1_i64])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    assert!(DynProofExpr::<RistrettoPoint>::try_new_pow(column(t, "a", &accessor), 2).is_ok());
+    assert!(matches!(
+        DynProofExpr::<RistrettoPoint>::try_new_pow(column(t, "a", &accessor), 3),
+        Err(ConversionError::DataTypeMismatch { .. })
+    ));
+}
+
+#[test]
+fn we_cannot_raise_a_non_numeric_column_to_a_power() {
+    let data = owned_table([varchar("a", ["1"]), boolean("b", [true])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    for name in ["a", "b"] {
+        for exponent in [0, 1, 2] {
+            assert!(matches!(
+                DynProofExpr::<RistrettoPoint>::try_new_pow(column(t, name, &accessor), exponent),
+                Err(ConversionError::InvalidExpression { .. })
+            ));
+        }
+    }
+}
+
+// select pow(a, 3) as c from sxt.t
+#[test]
+fn result_expr_can_overflow() {
+    let data = owned_table([bigint("a", [2_i64, 3_000_000])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast: DynProofPlan<RistrettoPoint> = projection(
+        vec![aliased_plan(pow(column(t, "a", &accessor), 3), "c")],
+        tab(t),
+    );
+    let verifiable_res: VerifiableQueryResult<InnerProductProof> =
+        VerifiableQueryResult::new(&ast, &accessor, &());
+    assert!(matches!(
+        verifiable_res.verify(&ast, &accessor, &()),
+        Err(QueryError::Overflow)
+    ));
+}
+
+#[test]
+fn we_can_serialize_a_pow_expr_without_its_product() {
+    let data = owned_table([bigint("a", [1_i64, 2])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let expr: DynProofExpr<RistrettoPoint> = pow(column(t, "a", &accessor), 5);
+    let serialized = serde_json::to_value(&expr).unwrap();
+    assert_eq!(serialized["Pow"].as_object().unwrap().len(), 2);
+    assert_eq!(serialized["Pow"]["exponent"], 5);
+    let deserialized: DynProofExpr<RistrettoPoint> = serde_json::from_value(serialized).unwrap();
+    assert_eq!(deserialized, expr);
+}
+
+#[test]
+fn we_cannot_deserialize_a_pow_expr_with_an_invalid_base() {
+    let data = owned_table([varchar("a", ["x"])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let base: DynProofExpr<RistrettoPoint> = column(t, "a", &accessor);
+    let serialized = serde_json::json!({ "Pow": { "base": base, "exponent": 2 } });
+    assert!(serde_json::from_value::<DynProofExpr<RistrettoPoint>>(serialized).is_err());
+}
+
+#[test]
+fn we_serialize_pow_after_the_existing_expression_variants() {
+    let data = owned_table([bigint("a", [1_i64])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    // The first byte of a postcard-serialized enum is its variant index.
+    let variant_index =
+        |expr: &DynProofExpr<RistrettoPoint>| postcard::to_allocvec(expr).unwrap()[0];
+    let aggregate = sum_expr(column(t, "a", &accessor), "s").expr;
+    assert_eq!(variant_index(&aggregate), 9);
+    assert_eq!(variant_index(&pow(column(t, "a", &accessor), 2)), 12);
+}
@@ -0,0 +1,287 @@
+use super::{
+    column_of_type, count_sign, prover_evaluate_sign, verifier_evaluate_sign, DynProofExpr,
+    ProofExpr,
+};
+use crate::{
+    base::{
+        commitment::Commitment,
+        database::{Column, ColumnRef, ColumnType, CommitmentAccessor, DataAccessor},
+        map::IndexSet,
+        math::decimal::Precision,
+        proof::ProofError,
+        scalar::{Scalar, ScalarExt},
+    },
+    sql::{
+        parse::{ConversionError, ConversionResult},
+        proof::{CountBuilder, FinalRoundBuilder, SumcheckSubpolynomialType, VerificationBuilder},
+    },
+};
+use alloc::{boxed::Box, format, vec, vec::Vec};
+use bumpalo::Bump;
+use num_bigint::BigInt;
+use num_traits::{One, Signed, Zero};
+use serde::{Deserialize, Serialize};
+
+/// Provable `ROUND(expr, digits)` of a decimal expression to `digits` fractional digits
+///
+/// Values are rounded half away from zero, as in postgres, e.g. `ROUND(2.345, 2) = 2.35` and
+/// `ROUND(-2.345, 2) = -2.35`. Rounding to at least as many digits as the scale of the input is
+/// the identity. Otherwise, with `d = 10^(scale - digits)`, the rounded value `y` of `x` is proven
+/// through a witness `t` such that `x = d * y + (1 - 2 * sign(x)) * (t - d / 2)` and `0 <= t < d`.
+///
+/// The result has scale `digits` and one more integer digit than the input, for the carry,
+/// e.g. `ROUND(9.99, 1) = 10.0`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RoundExpr<C: Commitment> {
+    input: Box<DynProofExpr<C>>,
+    digits: i8,
+}
+
+impl<C: Commitment> RoundExpr<C> {
+    /// Create a new `ROUND` expression
+    ///
+    /// # Errors
+    /// Returns an error if the input is not a decimal, or if `digits` is more than 75 digits below
+    /// the scale of the input.
+    pub fn try_new(input: Box<DynProofExpr<C>>, digits: i8) -> ConversionResult<Self> {
+        let input_type = input.data_type();
+        let ColumnType::Decimal75(_, scale) = input_type else {
+            return Err(ConversionError::InvalidExpression {
+                expression: format!("cannot round a value of type {input_type}"),
+            });
+        };
+        if i16::from(scale) - i16::from(digits) > 75 {
+            return Err(ConversionError::InvalidExpression {
+                expression: format!("cannot round a value of type {input_type} to {digits} digits"),
+            });
+        }
+        Ok(Self { input, digits })
+    }
+
+    /// The rounded expression and the number of fractional digits it is rounded to
+    pub(crate) fn input_and_digits(&self) -> (&DynProofExpr<C>, i8) {
+        (&self.input, self.digits)
+    }
+
+    /// The number of digits that are rounded away, or `None` if the rounding is the identity
+    ///
+    /// # Panics
+    /// Panics if the input is not a decimal, which `try_new` rules out.
+    fn dropped_digits(&self) -> Option<u8> {
+        let scale = self
+            .input
+            .data_type()
+            .scale()
+            .expect("the input is a decimal");
+        u8::try_from(i16::from(scale) - i16::from(self.digits))
+            .ok()
+            .filter(|&dropped| dropped > 0)
+    }
+}
+
+/// Round every value of `x` half away from zero to a multiple of `divisor`.
+///
+/// Returns the rounded values, divided by `divisor`, and the witnesses `t` in `[0, divisor)` with
+/// `x = divisor * y + (1 - 2 * sign(x)) * (t - divisor / 2)`.
+///
+/// # Panics
+/// Panics if `divisor` does not fit in a scalar.
+fn round_column<'a, S: Scalar>(x: &[S], divisor: &BigInt, alloc: &'a Bump) -> (&'a [S], &'a [S]) {
+    let half = divisor / 2;
+    let (rounded, witnesses): (Vec<_>, Vec<_>) = x
+        .iter()
+        .map(|&value| {
+            let value: BigInt = value.into();
+            let shifted: BigInt = value.abs() + &half;
+            let (magnitude, witness) = (&shifted / divisor, &shifted % divisor);
+            let rounded = if value.is_negative() {
+                -magnitude
+            } else {
+                magnitude
+            };
+            (
+                S::try_from(rounded).expect("the rounded value is smaller than the input"),
+                S::try_from(witness).expect("the witness is smaller than the divisor"),
+            )
+        })
+        .unzip();
+    (
+        alloc.alloc_slice_copy(&rounded),
+        alloc.alloc_slice_copy(&witnesses),
+    )
+}
+
+impl<C: Commitment> ProofExpr<C> for RoundExpr<C> {
+    fn count(&self, builder: &mut CountBuilder) -> Result<(), ProofError> {
+        self.input.count(builder)?;
+        if self.dropped_digits().is_some() {
+            // sign(x)
+            count_sign(builder)?;
+            // y and t
+            builder.count_intermediate_mles(2);
+            builder.count_subpolynomials(1);
+            builder.count_degree(3);
+            // range of y, t >= 0 and d - 1 - t >= 0
+            for _ in 0..3 {
+                count_sign(builder)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn data_type(&self) -> ColumnType {
+        let input_type = self.input.data_type();
+        if self.dropped_digits().is_none() {
+            return input_type;
+        }
+        let precision = i16::from(
+            input_type
+                .precision_value()
+                .expect("the input is a decimal"),
+        );
+        let scale = i16::from(input_type.scale().expect("the input is a decimal"));
+        let digits = i16::from(self.digits);
+        let precision = (precision - scale + digits + 1).max(digits).clamp(1, 75);
+        ColumnType::Decimal75(
+            Precision::new(u8::try_from(precision).expect("precision is between 1 and 75"))
+                .expect("precision is between 1 and 75"),
+            self.digits,
+        )
+    }
+
+    fn result_evaluate<'a>(
+        &self,
+        table_length: usize,
+        alloc: &'a Bump,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> Column<'a, C::Scalar> {
+        let input = self.input.result_evaluate(table_length, alloc, accessor);
+        let Some(dropped_digits) = self.dropped_digits() else {
+            return input;
+        };
+        let divisor = BigInt::from(10).pow(u32::from(dropped_digits));
+        let (rounded, _) = round_column(input.as_scalar(alloc), &divisor, alloc);
+        column_of_type(rounded, self.data_type())
+    }
+
+    #[tracing::instrument(
+        name = "proofs.sql.ast.round_expr.prover_evaluate",
+        level = "info",
+        skip_all
+    )]
+    fn prover_evaluate<'a>(
+        &self,
+        builder: &mut FinalRoundBuilder<'a, C::Scalar>,
+        alloc: &'a Bump,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> Column<'a, C::Scalar> {
+        let input = self.input.prover_evaluate(builder, alloc, accessor);
+        let Some(dropped_digits) = self.dropped_digits() else {
+            return input;
+        };
+        let x = input.as_scalar(alloc);
+        let divisor = BigInt::from(10).pow(u32::from(dropped_digits));
+        let (rounded, witnesses) = round_column(x, &divisor, alloc);
+        let d = C::Scalar::pow10(dropped_digits.into());
+        let half = C::Scalar::pow10(u32::from(dropped_digits) - 1) * C::Scalar::from(5);
+
+        let sign = prover_evaluate_sign(
+            builder,
+            alloc,
+            x,
+            #[cfg(test)]
+            false,
+        );
+        builder.produce_intermediate_mle(rounded);
+        builder.produce_intermediate_mle(witnesses);
+
+        // subpolynomial: x - d * y - t + d / 2 + 2 * sign * t - d * sign
+        builder.produce_sumcheck_subpolynomial(
+            SumcheckSubpolynomialType::Identity,
+            vec![
+                (C::Scalar::one(), vec![Box::new(x)]),
+                (-d, vec![Box::new(rounded)]),
+                (-C::Scalar::one(), vec![Box::new(witnesses)]),
+                (half, vec![]),
+                (C::Scalar::TWO, vec![Box::new(sign), Box::new(witnesses)]),
+                (-d, vec![Box::new(sign)]),
+            ],
+        );
+
+        // The rounded value is only range checked. This rules out field elements such as `1/2`
+        // that would otherwise satisfy the identity above.
+        prover_evaluate_sign(
+            builder,
+            alloc,
+            rounded,
+            #[cfg(test)]
+            false,
+        );
+        // t >= 0
+        prover_evaluate_sign(
+            builder,
+            alloc,
+            witnesses,
+            #[cfg(test)]
+            false,
+        );
+        // d - 1 - t >= 0
+        let slack =
+            alloc.alloc_slice_fill_with(witnesses.len(), |i| d - C::Scalar::one() - witnesses[i]);
+        prover_evaluate_sign(
+            builder,
+            alloc,
+            slack,
+            #[cfg(test)]
+            false,
+        );
+
+        column_of_type(rounded, self.data_type())
+    }
+
+    fn verifier_evaluate(
+        &self,
+        builder: &mut VerificationBuilder<C>,
+        accessor: &dyn CommitmentAccessor<C>,
+    ) -> Result<C::Scalar, ProofError> {
+        let x = self.input.verifier_evaluate(builder, accessor)?;
+        let Some(dropped_digits) = self.dropped_digits() else {
+            return Ok(x);
+        };
+        let one_eval = builder.mle_evaluations.input_one_evaluation;
+        let d = C::Scalar::pow10(dropped_digits.into());
+        let half = C::Scalar::pow10(u32::from(dropped_digits) - 1) * C::Scalar::from(5);
+
+        let sign = verifier_evaluate_sign(builder, x, one_eval)?;
+        let rounded = builder.consume_intermediate_mle();
+        let witness = builder.consume_intermediate_mle();
+
+        // subpolynomial: x - d * y - t + d / 2 + 2 * sign * t - d * sign
+        builder.produce_sumcheck_subpolynomial_evaluation(
+            &SumcheckSubpolynomialType::Identity,
+            x - d * rounded - witness + half * one_eval + C::Scalar::TWO * sign * witness
+                - d * sign,
+        );
+
+        verifier_evaluate_sign(builder, rounded, one_eval)?;
+        // t >= 0
+        let witness_sign = verifier_evaluate_sign(builder, witness, one_eval)?;
+        // d - 1 - t >= 0
+        let slack_sign = verifier_evaluate_sign(
+            builder,
+            (d - C::Scalar::one()) * one_eval - witness,
+            one_eval,
+        )?;
+        if !witness_sign.is_zero() || !slack_sign.is_zero() {
+            return Err(ProofError::VerificationError {
+                error: "rounding remainder is out of range",
+            });
+        }
+
+        Ok(rounded)
+    }
+
+    fn get_column_references(&self, columns: &mut IndexSet<ColumnRef>) {
+        self.input.get_column_references(columns);
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{owned_table_utility::*, ColumnType, OwnedTableTestAccessor},
+        math::decimal::Precision,
+    },
+    sql::{
+        parse::ConversionError,
+        proof::{exercise_verification, VerifiableQueryResult},
+        proof_exprs::{test_utility::*, DynProofExpr, ProofExpr},
+        proof_plans::{test_utility::*, DynProofPlan},
+    },
+};
+use alloc::string::ToString;
+use curve25519_dalek::ristretto::RistrettoPoint;
+
+#[test]
+fn we_can_round_a_decimal_column_half_away_from_zero() {
+    // 1.2349, 1.2351, 1.2350, -1.2350, -1.2349, 0, 9.9950, -0.0049
+    let data = owned_table([decimal75(
+        "a",
+        10,
+        4,
+        [12349_i64, 12351, 12350, -12350, -12349, 0, 99950, -49],
+    )]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let expr = round(column::<RistrettoPoint>(t, "a", &accessor), 2);
+    assert_eq!(
+        expr.data_type(),
+        ColumnType::Decimal75(Precision::new(9).unwrap(), 2)
+    );
+    assert_eq!(expr.to_string(), "ROUND(a, 2)");
+    let res = assert_expr_roundtrip(&expr, &accessor, t);
+    assert_eq!(
+        res,
+        owned_table([decimal75(
+            "expr",
+            9,
+            2,
+            [123_i64, 124, 124, -124, -123, 0, 1000, 0]
+        )])
+    );
+}
+
+#[test]
+fn we_can_round_a_decimal_column_to_zero_or_negative_digits() {
+    let data = owned_table([decimal75(
+        "a",
+        6,
+        2,
+        [14_949_i64, 15_000, -15_000, -250, 49],
+    )]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let res = assert_expr_roundtrip(&round(column(t, "a", &accessor), 0), &accessor, t);
+    assert_eq!(
+        res,
+        owned_table([decimal75("expr", 5, 0, [149_i64, 150, -150, -3, 0])])
+    );
+    let res = assert_expr_roundtrip(&round(column(t, "a", &accessor), -1), &accessor, t);
+    assert_eq!(
+        res,
+        owned_table([decimal75("expr", 4, -1, [15_i64, 15, -15, 0, 0])])
+    );
+}
+
+#[test]
+fn rounding_to_at_least_the_scale_is_the_identity() {
+    let data = owned_table([decimal75("a", 10, 4, [12349_i64, -12350, 0])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    for digits in [4, 6] {
+        let expr = round(column::<RistrettoPoint>(t, "a", &accessor), digits);
+        assert_eq!(
+            expr.data_type(),
+            ColumnType::Decimal75(Precision::new(10).unwrap(), 4)
+        );
+        let res = assert_expr_roundtrip(&expr, &accessor, t);
+        assert_eq!(
+            res,
+            owned_table([decimal75("expr", 10, 4, [12349_i64, -12350, 0])])
+        );
+    }
+}
+
+#[test]
+fn we_can_prove_a_projection_of_rounded_values_of_an_empty_table() {
+    let data = owned_table([decimal75("a", 10, 4, [0_i64; 0])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast: DynProofPlan<RistrettoPoint> = projection(
+        vec![aliased_plan(round(column(t, "a", &accessor), 2), "r")],
+        tab(t),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    assert_eq!(res, owned_table([decimal75("r", 9, 2, [0_i64; 0])]));
+}
+
+#[test]
+fn we_cannot_round_a_non_decimal_column_or_to_too_few_digits() {
+    let data = owned_table([
+        bigint("a", [1_i64]),
+        varchar("b", ["1"]),
+        decimal75("c", 10, 4, [1_i64]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    for name in ["a", "b"] {
+        assert!(matches!(
+            DynProofExpr::<RistrettoPoint>::try_new_round(column(t, name, &accessor), 0),
+            Err(ConversionError::InvalidExpression { .. })
+        ));
+    }
+    assert!(DynProofExpr::<RistrettoPoint>::try_new_round(column(t, "c", &accessor), -71).is_ok());
+    assert!(matches!(
+        DynProofExpr::<RistrettoPoint>::try_new_round(column(t, "c", &accessor), -72),
+        Err(ConversionError::InvalidExpression { .. })
+    ));
+}
+
+#[test]
+fn we_serialize_round_as_the_last_expression_variant() {
+    let data = owned_table([decimal75("a", 10, 2, [100_i64])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    // The first byte of a postcard-serialized enum is its variant index.
+    let variant_index =
+        |expr: &DynProofExpr<RistrettoPoint>| postcard::to_allocvec(expr).unwrap()[0];
+    assert_eq!(variant_index(&pow(column(t, "a", &accessor), 2)), 12);
+    assert_eq!(variant_index(&round(column(t, "a", &accessor), 1)), 13);
+}
@@ -1,8 +1,58 @@
-use crate::base::database::TableRef;
+use crate::{
+    base::{
+        database::{ColumnRef, ColumnType, TableRef},
+        map::IndexSet,
+    },
+    sql::parse::{ConversionError, ConversionResult},
+};
+use alloc::boxed::Box;
+use proof_of_sql_parser::Identifier;
 use serde::{Deserialize, Serialize};
 
 /// Expression for an SQL table
+///
+/// The same table can be referenced more than once, e.g. in a self-join, by giving each reference a different alias.
 #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TableExpr {
     pub table_ref: TableRef,
+    /// The alias of the table, e.g. `l` in `sxt.t AS l`
+    pub alias: Option<Identifier>,
+}
+
+impl TableExpr {
+    /// Returns a reference to a column of this table, qualified by the alias of the table if it has one
+    #[must_use]
+    pub fn column_ref(&self, column_id: Identifier, column_type: ColumnType) -> ColumnRef {
+        let column_ref = ColumnRef::new(self.table_ref, column_id, column_type);
+        match self.alias {
+            Some(alias) => column_ref.with_table_alias(alias),
+            None => column_ref,
+        }
+    }
+
+    /// Returns this table together with the tables of all the `columns`, each table once
+    #[must_use]
+    pub fn table_references(&self, columns: &IndexSet<ColumnRef>) -> IndexSet<TableRef> {
+        core::iter::once(self.table_ref)
+            .chain(columns.iter().map(ColumnRef::table_ref))
+            .collect()
+    }
+
+    /// Checks that every column in `columns` is a column of this table, through any alias
+    ///
+    /// # Errors
+    /// Returns [`ConversionError::ColumnFromOtherTable`] for the first column of another table.
+    pub fn check_column_references(&self, columns: &IndexSet<ColumnRef>) -> ConversionResult<()> {
+        match columns
+            .iter()
+            .find(|column| column.table_ref() != self.table_ref)
+        {
+            Some(column) => Err(ConversionError::ColumnFromOtherTable {
+                identifier: Box::new(column.column_id()),
+                column_table: Box::new(column.table_ref().resource_id()),
+                table: Box::new(self.table_ref.resource_id()),
+            }),
+            None => Ok(()),
+        }
+    }
 }
@@ -3,6 +3,7 @@ use crate::base::{
     commitment::Commitment,
     database::{ColumnRef, LiteralValue, SchemaAccessor, TableRef},
     math::decimal::Precision,
+    scalar::ScalarExt,
 };
 use proof_of_sql_parser::intermediate_ast::AggregationOperator;
 
@@ -26,7 +27,7 @@ pub fn column<C: Commitment>(
 ) -> DynProofExpr<C> {
     let name = name.parse().unwrap();
     let type_col = accessor.lookup_column(tab, name).unwrap();
-    DynProofExpr::Column(ColumnExpr::new(ColumnRef::new(tab, name, type_col)))
+    DynProofExpr::Column(ColumnExpr::new(&ColumnRef::new(tab, name, type_col)))
 }
 
 /// # Panics
@@ -92,6 +93,31 @@ pub fn multiply<C: Commitment>(left: DynProofExpr<C>, right: DynProofExpr<C>) ->
     DynProofExpr::try_new_multiply(left, right).unwrap()
 }
 
+/// # Panics
+/// Panics if:
+/// - `DynProofExpr::try_new_divide()` returns an error.
+pub fn divide<C: Commitment>(left: DynProofExpr<C>, right: DynProofExpr<C>) -> DynProofExpr<C> {
+    DynProofExpr::try_new_divide(left, right).unwrap()
+}
+
+/// # Panics
+/// Panics if:
+/// - `DynProofExpr::try_new_modulo()` returns an error.
+pub fn modulo<C: Commitment>(left: DynProofExpr<C>, right: DynProofExpr<C>) -> DynProofExpr<C> {
+    DynProofExpr::try_new_modulo(left, right).unwrap()
+}
+
+/// # Panics
+/// Panics if:
+/// - `DynProofExpr::try_new_pow()` returns an error.
+pub fn pow<C: Commitment>(base: DynProofExpr<C>, exponent: u8) -> DynProofExpr<C> {
+    DynProofExpr::try_new_pow(base, exponent).unwrap()
+}
+
+pub fn round<C: Commitment>(input: DynProofExpr<C>, digits: i8) -> DynProofExpr<C> {
+    DynProofExpr::try_new_round(input, digits).unwrap()
+}
+
 pub fn const_bool<C: Commitment>(val: bool) -> DynProofExpr<C> {
     DynProofExpr::new_literal(LiteralValue::Boolean(val))
 }
@@ -119,6 +145,13 @@ pub fn const_varchar<C: Commitment>(val: &str) -> DynProofExpr<C> {
     )))
 }
 
+pub fn const_varbinary<C: Commitment>(val: &[u8]) -> DynProofExpr<C> {
+    DynProofExpr::new_literal(LiteralValue::VarBinary((
+        val.to_vec(),
+        C::Scalar::from_byte_slice_via_hash(val),
+    )))
+}
+
 /// Create a constant scalar value. Used if we don't want to specify column types.
 #[allow(dead_code)]
 pub fn const_scalar<C: Commitment, T: Into<C::Scalar>>(val: T) -> DynProofExpr<C> {
@@ -141,7 +174,34 @@ pub fn const_decimal75<C: Commitment, T: Into<C::Scalar>>(
 }
 
 pub fn tab(tab: TableRef) -> TableExpr {
-    TableExpr { table_ref: tab }
+    TableExpr {
+        table_ref: tab,
+        alias: None,
+    }
+}
+
+/// # Panics
+/// Panics if:
+/// - `alias.parse()` fails to parse the provided alias string.
+pub fn aliased_tab(tab: TableRef, alias: &str) -> TableExpr {
+    TableExpr {
+        table_ref: tab,
+        alias: Some(alias.parse().unwrap()),
+    }
+}
+
+/// # Panics
+/// Panics if:
+/// - `name.parse()` fails to parse the column name.
+/// - `accessor.lookup_column()` returns `None`, indicating the column is not found.
+pub fn tab_column<C: Commitment>(
+    table: &TableExpr,
+    name: &str,
+    accessor: &impl SchemaAccessor,
+) -> DynProofExpr<C> {
+    let name = name.parse().unwrap();
+    let type_col = accessor.lookup_column(table.table_ref, name).unwrap();
+    DynProofExpr::Column(ColumnExpr::new(&table.column_ref(name, type_col)))
 }
 
 /// # Panics
@@ -165,7 +225,7 @@ pub fn aliased_col_expr_plan<C: Commitment>(
     accessor: &impl SchemaAccessor,
 ) -> AliasedDynProofExpr<C> {
     AliasedDynProofExpr {
-        expr: DynProofExpr::Column(ColumnExpr::<C>::new(col_ref(tab, old_name, accessor))),
+        expr: DynProofExpr::Column(ColumnExpr::<C>::new(&col_ref(tab, old_name, accessor))),
         alias: new_name.parse().unwrap(),
     }
 }
@@ -180,7 +240,7 @@ pub fn col_expr_plan<C: Commitment>(
     accessor: &impl SchemaAccessor,
 ) -> AliasedDynProofExpr<C> {
     AliasedDynProofExpr {
-        expr: DynProofExpr::Column(ColumnExpr::<C>::new(col_ref(tab, name, accessor))),
+        expr: DynProofExpr::Column(ColumnExpr::<C>::new(&col_ref(tab, name, accessor))),
         alias: name.parse().unwrap(),
     }
 }
@@ -212,7 +272,7 @@ pub fn col_expr<C: Commitment>(
     name: &str,
     accessor: &impl SchemaAccessor,
 ) -> ColumnExpr<C> {
-    ColumnExpr::<C>::new(col_ref(tab, name, accessor))
+    ColumnExpr::<C>::new(&col_ref(tab, name, accessor))
 }
 
 pub fn cols_expr<C: Commitment>(
@@ -235,3 +295,151 @@ pub fn sum_expr<C: Commitment>(expr: DynProofExpr<C>, alias: &str) -> AliasedDyn
         alias: alias.parse().unwrap(),
     }
 }
+
+#[cfg(feature = "blitzar")]
+pub use expr_roundtrip::assert_expr_roundtrip;
+
+#[cfg(feature = "blitzar")]
+mod expr_roundtrip {
+    use super::{super::ProofExpr, TableExpr};
+    use crate::{
+        base::{
+            commitment::InnerProductProof,
+            database::{
+                Column, ColumnField, ColumnRef, CommitmentAccessor, DataAccessor, MetadataAccessor,
+                OwnedTable, TableRef, TestAccessor,
+            },
+            map::IndexSet,
+            math::log2_up,
+            proof::ProofError,
+            scalar::Curve25519Scalar,
+        },
+        sql::proof::{
+            exercise_verification, CountBuilder, FinalRoundBuilder, FirstRoundBuilder, ProofPlan,
+            ProverEvaluate, VerifiableQueryResult, VerificationBuilder,
+        },
+    };
+    use bumpalo::Bump;
+    use curve25519_dalek::ristretto::RistrettoPoint;
+    use serde::Serialize;
+
+    /// Plan of the form `SELECT <expr> AS expr FROM <table>`, for any single [`ProofExpr`].
+    #[derive(Debug, Serialize)]
+    struct ExprExec<'e, E> {
+        expr: &'e E,
+        table: TableExpr,
+    }
+
+    impl<E: ProofExpr<RistrettoPoint> + Serialize> ProofPlan<RistrettoPoint> for ExprExec<'_, E> {
+        fn count(
+            &self,
+            builder: &mut CountBuilder,
+            _accessor: &dyn MetadataAccessor,
+        ) -> Result<(), ProofError> {
+            self.expr.count(builder)
+        }
+
+        fn get_length(&self, accessor: &dyn MetadataAccessor) -> usize {
+            accessor.get_length(self.table.table_ref)
+        }
+
+        fn get_offset(&self, accessor: &dyn MetadataAccessor) -> usize {
+            accessor.get_offset(self.table.table_ref)
+        }
+
+        fn verifier_evaluate(
+            &self,
+            builder: &mut VerificationBuilder<RistrettoPoint>,
+            accessor: &dyn CommitmentAccessor<RistrettoPoint>,
+            _result: Option<&OwnedTable<Curve25519Scalar>>,
+        ) -> Result<Vec<Curve25519Scalar>, ProofError> {
+            Ok(vec![self.expr.verifier_evaluate(builder, accessor)?])
+        }
+
+        fn get_column_result_fields(&self) -> Vec<ColumnField> {
+            vec![ColumnField::new(
+                "expr".parse().unwrap(),
+                self.expr.data_type(),
+            )]
+        }
+
+        fn get_column_references(&self) -> IndexSet<ColumnRef> {
+            let mut columns = IndexSet::default();
+            self.expr.get_column_references(&mut columns);
+            columns
+        }
+
+        fn get_table_references(&self) -> IndexSet<TableRef> {
+            IndexSet::from_iter([self.table.table_ref])
+        }
+    }
+
+    impl<E: ProofExpr<RistrettoPoint>> ProverEvaluate<Curve25519Scalar> for ExprExec<'_, E> {
+        fn result_evaluate<'a>(
+            &self,
+            input_length: usize,
+            alloc: &'a Bump,
+            accessor: &'a dyn DataAccessor<Curve25519Scalar>,
+        ) -> Vec<Column<'a, Curve25519Scalar>> {
+            vec![self.expr.result_evaluate(input_length, alloc, accessor)]
+        }
+
+        fn first_round_evaluate(&self, _builder: &mut FirstRoundBuilder) {}
+
+        fn final_round_evaluate<'a>(
+            &self,
+            builder: &mut FinalRoundBuilder<'a, Curve25519Scalar>,
+            alloc: &'a Bump,
+            accessor: &'a dyn DataAccessor<Curve25519Scalar>,
+        ) -> Vec<Column<'a, Curve25519Scalar>> {
+            vec![self.expr.prover_evaluate(builder, alloc, accessor)]
+        }
+    }
+
+    /// Evaluates, proves and verifies `expr` over the table `table_ref`, returning the verified
+    /// result as a table with a single column named `expr`.
+    ///
+    /// This checks that the output of [`ProofExpr::prover_evaluate`] is the same as the output of
+    /// [`ProofExpr::result_evaluate`], that verification succeeds (see [`exercise_verification`]),
+    /// and that the verified result matches the result-evaluated output.
+    ///
+    /// # Panics
+    /// Panics if any of the checks above fail.
+    pub fn assert_expr_roundtrip<E: ProofExpr<RistrettoPoint> + Serialize>(
+        expr: &E,
+        accessor: &impl TestAccessor<RistrettoPoint>,
+        table_ref: TableRef,
+    ) -> OwnedTable<Curve25519Scalar> {
+        let table_length = accessor.get_length(table_ref);
+        let alloc = Bump::new();
+        let result_column = expr.result_evaluate(table_length, &alloc, accessor);
+        let mut builder = FinalRoundBuilder::new(table_length, log2_up(table_length), Vec::new());
+        let prover_column = expr.prover_evaluate(&mut builder, &alloc, accessor);
+        assert_eq!(
+            prover_column.as_scalar(&alloc),
+            result_column.as_scalar(&alloc),
+            "prover_evaluate and result_evaluate disagree on {expr:?}"
+        );
+
+        let plan = ExprExec {
+            expr,
+            table: TableExpr {
+                table_ref,
+                alias: None,
+            },
+        };
+        let verifiable_res = VerifiableQueryResult::<InnerProductProof>::new(&plan, accessor, &());
+        exercise_verification(&verifiable_res, &plan, accessor, table_ref);
+        let res = verifiable_res
+            .verify(&plan, accessor, &())
+            .unwrap_or_else(|err| panic!("verification of {expr:?} failed: {err}"))
+            .table;
+        let verified_column = Column::from_owned_column(&res.inner_table()[0], &alloc);
+        assert_eq!(
+            verified_column.as_scalar(&alloc),
+            result_column.as_scalar(&alloc),
+            "the verified result of {expr:?} differs from result_evaluate"
+        );
+        res
+    }
+}
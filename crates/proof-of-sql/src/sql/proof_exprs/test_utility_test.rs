@@ -0,0 +1,122 @@
+use super::{test_utility::*, DynProofExpr, ProofExpr};
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{
+            owned_table_utility::*, Column, ColumnRef, ColumnType, CommitmentAccessor,
+            DataAccessor, OwnedTableTestAccessor,
+        },
+        map::IndexSet,
+        proof::ProofError,
+        scalar::Curve25519Scalar,
+    },
+    sql::proof::{CountBuilder, FinalRoundBuilder, VerificationBuilder},
+};
+use bumpalo::Bump;
+use curve25519_dalek::ristretto::RistrettoPoint;
+use serde::Serialize;
+
+#[test]
+fn we_can_roundtrip_an_equals_expr() {
+    let data = owned_table([
+        bigint("a", [1_i64, 2, 3, 2]),
+        varchar("b", ["x", "y", "x", "z"]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let res = assert_expr_roundtrip(
+        &equal(column(t, "a", &accessor), const_bigint(2)),
+        &accessor,
+        t,
+    );
+    assert_eq!(
+        res,
+        owned_table([boolean("expr", [false, true, false, true])])
+    );
+    let res = assert_expr_roundtrip(
+        &equal(column(t, "b", &accessor), const_varchar("x")),
+        &accessor,
+        t,
+    );
+    assert_eq!(
+        res,
+        owned_table([boolean("expr", [true, false, true, false])])
+    );
+}
+
+#[test]
+fn we_can_roundtrip_an_arithmetic_expr() {
+    let data = owned_table([bigint("a", [1_i64, -2, 3]), int("b", [4_i32, 5, -6])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 10, ());
+    let res = assert_expr_roundtrip(
+        &add(
+            multiply(column(t, "a", &accessor), column(t, "b", &accessor)),
+            const_bigint(1),
+        ),
+        &accessor,
+        t,
+    );
+    assert_eq!(res, owned_table([bigint("expr", [5_i64, -9, -17])]));
+}
+
+/// An expression whose `result_evaluate` disagrees with its `prover_evaluate`.
+#[derive(Debug, Serialize)]
+struct DivergentExpr {
+    expr: DynProofExpr<RistrettoPoint>,
+}
+
+impl ProofExpr<RistrettoPoint> for DivergentExpr {
+    fn count(&self, builder: &mut CountBuilder) -> Result<(), ProofError> {
+        self.expr.count(builder)
+    }
+
+    fn data_type(&self) -> ColumnType {
+        self.expr.data_type()
+    }
+
+    fn result_evaluate<'a>(
+        &self,
+        table_length: usize,
+        alloc: &'a Bump,
+        _accessor: &'a dyn DataAccessor<Curve25519Scalar>,
+    ) -> Column<'a, Curve25519Scalar> {
+        Column::BigInt(alloc.alloc_slice_fill_copy(table_length, 0))
+    }
+
+    fn prover_evaluate<'a>(
+        &self,
+        builder: &mut FinalRoundBuilder<'a, Curve25519Scalar>,
+        alloc: &'a Bump,
+        accessor: &'a dyn DataAccessor<Curve25519Scalar>,
+    ) -> Column<'a, Curve25519Scalar> {
+        self.expr.prover_evaluate(builder, alloc, accessor)
+    }
+
+    fn verifier_evaluate(
+        &self,
+        builder: &mut VerificationBuilder<RistrettoPoint>,
+        accessor: &dyn CommitmentAccessor<RistrettoPoint>,
+    ) -> Result<Curve25519Scalar, ProofError> {
+        self.expr.verifier_evaluate(builder, accessor)
+    }
+
+    fn get_column_references(&self, columns: &mut IndexSet<ColumnRef>) {
+        self.expr.get_column_references(columns);
+    }
+}
+
+#[test]
+#[should_panic(expected = "prover_evaluate and result_evaluate disagree")]
+fn we_cannot_roundtrip_an_expr_whose_prover_and_result_evaluations_diverge() {
+    let data = owned_table([bigint("a", [1_i64, 2, 3])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    assert_expr_roundtrip(
+        &DivergentExpr {
+            expr: column(t, "a", &accessor),
+        },
+        &accessor,
+        t,
+    );
+}
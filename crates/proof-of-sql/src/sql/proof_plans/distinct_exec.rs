@@ -0,0 +1,311 @@
+use super::{
+    dyn_proof_plan::check_result_aliases,
+    group_by_exec::{prove_group_by, verify_group_by},
+};
+use crate::{
+    base::{
+        commitment::Commitment,
+        database::{
+            group_by_util::{
+                aggregate_columns, compare_indexes_by_owned_columns, AggregatedColumns,
+            },
+            Column, ColumnField, ColumnRef, CommitmentAccessor, DataAccessor, MetadataAccessor,
+            OwnedTable, TableRef,
+        },
+        map::IndexSet,
+        proof::ProofError,
+        scalar::Scalar,
+        slice_ops,
+    },
+    sql::{
+        parse::ConversionResult,
+        proof::{
+            CountBuilder, FinalRoundBuilder, FirstRoundBuilder, ProofPlan, ProverEvaluate,
+            SumcheckSubpolynomialType, VerificationBuilder,
+        },
+        proof_exprs::{ColumnExpr, DynProofExpr, ProofExpr, TableExpr},
+    },
+};
+use alloc::{boxed::Box, vec, vec::Vec};
+use bumpalo::Bump;
+use core::iter::repeat_with;
+use serde::{Deserialize, Serialize};
+
+/// Provable expressions for queries of the form
+/// ```ignore
+///     SELECT DISTINCT <distinct_expr1>, ..., <distinct_exprM>
+///     FROM <table>
+///     WHERE <where_clause>
+/// ```
+///
+/// The result is returned in ascending order of the distinct columns,
+/// not in the order in which each row first occurs in the input.
+/// The verifier relies on this order to establish that every returned row is unique,
+/// so callers that need first-occurrence order have to reorder the verified result themselves.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct DistinctExec<C: Commitment> {
+    pub(super) distinct_exprs: Vec<ColumnExpr<C>>,
+    pub(super) table: TableExpr,
+    pub(super) where_clause: DynProofExpr<C>,
+}
+
+impl<C: Commitment> DistinctExec<C> {
+    /// Creates a new `distinct` expression.
+    ///
+    /// # Errors
+    /// Returns [`ConversionError::ColumnFromOtherTable`](crate::sql::parse::ConversionError::ColumnFromOtherTable)
+    /// if an expression references a column of another table, and
+    /// [`ConversionError::DuplicateResultAlias`](crate::sql::parse::ConversionError::DuplicateResultAlias)
+    /// if two result columns have the same name.
+    pub fn try_new(
+        distinct_exprs: Vec<ColumnExpr<C>>,
+        table: TableExpr,
+        where_clause: DynProofExpr<C>,
+    ) -> ConversionResult<Self> {
+        let plan = Self {
+            distinct_exprs,
+            table,
+            where_clause,
+        };
+        plan.table
+            .check_column_references(&plan.get_column_references())?;
+        check_result_aliases(
+            plan.get_column_result_fields()
+                .iter()
+                .map(ColumnField::name),
+        )?;
+        Ok(plan)
+    }
+}
+
+impl<C: Commitment> ProofPlan<C> for DistinctExec<C> {
+    fn count(
+        &self,
+        builder: &mut CountBuilder,
+        _accessor: &dyn MetadataAccessor,
+    ) -> Result<(), ProofError> {
+        self.where_clause.count(builder)?;
+        for expr in &self.distinct_exprs {
+            expr.count(builder)?;
+            builder.count_intermediate_mles(1);
+        }
+        // For the count col and its inverse
+        builder.count_intermediate_mles(2);
+        // For the group by argument
+        builder.count_intermediate_mles(2);
+        builder.count_subpolynomials(4);
+        builder.count_degree(3);
+        builder.count_post_result_challenges(2);
+        Ok(())
+    }
+
+    fn get_length(&self, accessor: &dyn MetadataAccessor) -> usize {
+        accessor.get_length(self.table.table_ref)
+    }
+
+    fn get_offset(&self, accessor: &dyn MetadataAccessor) -> usize {
+        accessor.get_offset(self.table.table_ref)
+    }
+
+    fn verifier_evaluate(
+        &self,
+        builder: &mut VerificationBuilder<C>,
+        accessor: &dyn CommitmentAccessor<C>,
+        result: Option<&OwnedTable<C::Scalar>>,
+    ) -> Result<Vec<C::Scalar>, ProofError> {
+        // 1. selection
+        let where_eval = self.where_clause.verifier_evaluate(builder, accessor)?;
+        // 2. columns
+        let distinct_evals = self
+            .distinct_exprs
+            .iter()
+            .map(|expr| expr.verifier_evaluate(builder, accessor))
+            .collect::<Result<Vec<_>, _>>()?;
+        // 3. filtered_columns
+        let distinct_result_columns_evals: Vec<_> =
+            repeat_with(|| builder.consume_intermediate_mle())
+                .take(self.distinct_exprs.len())
+                .collect();
+        let count_column_eval = builder.consume_intermediate_mle();
+        let count_star_eval = builder.consume_intermediate_mle();
+
+        let alpha = builder.consume_post_result_challenge();
+        let beta = builder.consume_post_result_challenge();
+
+        verify_group_by(
+            builder,
+            alpha,
+            beta,
+            (distinct_evals, Vec::new(), where_eval),
+            (
+                distinct_result_columns_evals.clone(),
+                Vec::new(),
+                count_column_eval,
+            ),
+        )?;
+
+        // count_out * count_star - output_one = 0,
+        // i.e. every returned row represents at least one selected input row
+        builder.produce_sumcheck_subpolynomial_evaluation(
+            &SumcheckSubpolynomialType::Identity,
+            count_column_eval * count_star_eval - builder.mle_evaluations.output_one_evaluation,
+        );
+
+        match result {
+            Some(table) => {
+                let cols = self
+                    .distinct_exprs
+                    .iter()
+                    .map(|col| table.inner_table().get(&col.column_id()))
+                    .collect::<Option<Vec<_>>>()
+                    .ok_or(ProofError::VerificationError {
+                        error: "Result does not contain all the distinct columns.",
+                    })?;
+                if (1..table.num_rows())
+                    .any(|i| compare_indexes_by_owned_columns(&cols, i - 1, i).is_ge())
+                {
+                    Err(ProofError::VerificationError {
+                        error: "Result of distinct not ordered as expected.",
+                    })?;
+                }
+            }
+            None => Err(ProofError::VerificationError {
+                error: "DistinctExec is only supported at the top level of a query plan.",
+            })?,
+        }
+
+        Ok(distinct_result_columns_evals)
+    }
+
+    fn get_column_result_fields(&self) -> Vec<ColumnField> {
+        self.distinct_exprs
+            .iter()
+            .map(ColumnExpr::get_column_field)
+            .collect()
+    }
+
+    fn get_column_references(&self) -> IndexSet<ColumnRef> {
+        let mut columns = IndexSet::default();
+
+        for col in &self.distinct_exprs {
+            columns.insert(col.get_column_reference());
+        }
+
+        self.where_clause.get_column_references(&mut columns);
+
+        columns
+    }
+
+    fn get_table_references(&self) -> IndexSet<TableRef> {
+        self.table.table_references(&self.get_column_references())
+    }
+}
+
+impl<C: Commitment> ProverEvaluate<C::Scalar> for DistinctExec<C> {
+    #[tracing::instrument(name = "DistinctExec::result_evaluate", level = "debug", skip_all)]
+    fn result_evaluate<'a>(
+        &self,
+        input_length: usize,
+        alloc: &'a Bump,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> Vec<Column<'a, C::Scalar>> {
+        // 1. selection
+        let selection_column: Column<'a, C::Scalar> =
+            self.where_clause
+                .result_evaluate(input_length, alloc, accessor);
+        let selection = selection_column
+            .as_boolean()
+            .expect("selection is not boolean");
+
+        // 2. columns
+        let distinct_columns = self
+            .distinct_exprs
+            .iter()
+            .map(|expr| expr.result_evaluate(input_length, alloc, accessor))
+            .collect::<Vec<_>>();
+        // Compute filtered_columns
+        let AggregatedColumns {
+            group_by_columns: distinct_result_columns,
+            ..
+        } = aggregate_columns(alloc, &distinct_columns, &[], &[], &[], selection)
+            .expect("columns should be aggregatable");
+        distinct_result_columns
+    }
+
+    fn first_round_evaluate(&self, builder: &mut FirstRoundBuilder) {
+        builder.request_post_result_challenges(2);
+    }
+
+    #[tracing::instrument(name = "DistinctExec::final_round_evaluate", level = "debug", skip_all)]
+    fn final_round_evaluate<'a>(
+        &self,
+        builder: &mut FinalRoundBuilder<'a, C::Scalar>,
+        alloc: &'a Bump,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> Vec<Column<'a, C::Scalar>> {
+        // 1. selection
+        let selection_column: Column<'a, C::Scalar> =
+            self.where_clause.prover_evaluate(builder, alloc, accessor);
+        let selection = selection_column
+            .as_boolean()
+            .expect("selection is not boolean");
+
+        // 2. columns
+        let distinct_columns = self
+            .distinct_exprs
+            .iter()
+            .map(|expr| expr.prover_evaluate(builder, alloc, accessor))
+            .collect::<Vec<_>>();
+        // 3. Compute filtered_columns
+        let AggregatedColumns {
+            group_by_columns: distinct_result_columns,
+            count_column,
+            ..
+        } = aggregate_columns(alloc, &distinct_columns, &[], &[], &[], selection)
+            .expect("columns should be aggregatable");
+
+        let alpha = builder.consume_post_result_challenge();
+        let beta = builder.consume_post_result_challenge();
+
+        // 4. Produce MLEs
+        distinct_result_columns.iter().copied().for_each(|column| {
+            builder.produce_intermediate_mle(column);
+        });
+        builder.produce_intermediate_mle(count_column);
+        // count_star = count_out^(-1)
+        let count_star = alloc.alloc_slice_fill_default(count_column.len());
+        slice_ops::slice_cast_mut(count_column, count_star);
+        slice_ops::batch_inversion(count_star);
+        builder.produce_intermediate_mle(count_star as &[_]);
+
+        // 5. Prove distinct
+        prove_group_by(
+            builder,
+            alloc,
+            alpha,
+            beta,
+            (&distinct_columns, &[], selection),
+            (&distinct_result_columns, &[], count_column),
+        );
+        prove_counts_are_nonzero(builder, alloc, count_column, count_star);
+        distinct_result_columns
+    }
+}
+
+/// Proves that `count_out * count_star - output_one = 0`,
+/// so that no returned row can be made up without a matching input row.
+fn prove_counts_are_nonzero<'a, S: Scalar>(
+    builder: &mut FinalRoundBuilder<'a, S>,
+    alloc: &'a Bump,
+    count_out: &'a [i64],
+    count_star: &'a [S],
+) {
+    let output_one = alloc.alloc_slice_fill_copy(count_out.len(), true);
+    builder.produce_sumcheck_subpolynomial(
+        SumcheckSubpolynomialType::Identity,
+        vec![
+            (S::one(), vec![Box::new(count_out), Box::new(count_star)]),
+            (-S::one(), vec![Box::new(output_one as &[_])]),
+        ],
+    );
+}
@@ -0,0 +1,125 @@
+use super::{test_utility::*, DynProofPlan};
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{owned_table_utility::*, OwnedTableTestAccessor, TestAccessor},
+    },
+    sql::{
+        proof::{exercise_verification, VerifiableQueryResult},
+        proof_exprs::test_utility::*,
+    },
+};
+use curve25519_dalek::RistrettoPoint;
+
+/// `select distinct a, b, c from sxt.t`
+#[test]
+fn we_can_prove_a_distinct_over_fully_duplicate_rows() {
+    let data = owned_table([
+        bigint("a", [2, 1, 2, 1, 2]),
+        varchar("b", ["x", "y", "x", "y", "x"]),
+        int128("c", [5, 6, 5, 6, 5]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = distinct(
+        cols_expr(t, &["a", "b", "c"], &accessor),
+        tab(t),
+        const_bool(true),
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    // Rows come back sorted by the distinct columns, not in first-occurrence order.
+    let expected = owned_table([
+        bigint("a", [1, 2]),
+        varchar("b", ["y", "x"]),
+        int128("c", [6, 5]),
+    ]);
+    assert_eq!(res, expected);
+}
+
+/// `select distinct a, b, c from sxt.t`
+#[test]
+fn we_can_prove_a_distinct_over_rows_differing_in_only_one_column() {
+    let data = owned_table([
+        bigint("a", [1, 1, 1, 1]),
+        varchar("b", ["x", "x", "x", "x"]),
+        int128("c", [7, 5, 7, 6]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = distinct(
+        cols_expr(t, &["a", "b", "c"], &accessor),
+        tab(t),
+        const_bool(true),
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    let expected = owned_table([
+        bigint("a", [1, 1, 1]),
+        varchar("b", ["x", "x", "x"]),
+        int128("c", [5, 6, 7]),
+    ]);
+    assert_eq!(res, expected);
+}
+
+/// `select distinct a, b from sxt.t where c = 1`
+#[test]
+fn we_can_prove_a_distinct_over_all_unique_rows_with_a_where_clause() {
+    let data = owned_table([
+        bigint("a", [3, 1, 2, 4]),
+        varchar("b", ["w", "x", "y", "z"]),
+        bigint("c", [1, 1, 1, 0]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = distinct(
+        cols_expr(t, &["a", "b"], &accessor),
+        tab(t),
+        equal(column(t, "c", &accessor), const_bigint(1)),
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    let expected = owned_table([bigint("a", [1, 2, 3]), varchar("b", ["x", "y", "w"])]);
+    assert_eq!(res, expected);
+}
+
+/// `select distinct a from sxt.t where a = 0`
+#[test]
+fn we_can_prove_a_distinct_with_an_empty_result() {
+    let data = owned_table([bigint("a", [3, 1, 2, 1])]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = distinct(
+        cols_expr(t, &["a"], &accessor),
+        tab(t),
+        equal(column(t, "a", &accessor), const_bigint(0)),
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    let expected = owned_table([bigint("a", [0; 0])]);
+    assert_eq!(res, expected);
+}
+
+#[test]
+fn we_can_render_a_distinct_as_sql() {
+    let data = owned_table([bigint("a", [1, 2]), varchar("b", ["x", "y"])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let expr: DynProofPlan<RistrettoPoint> = distinct(
+        cols_expr(t, &["a", "b"], &accessor),
+        tab(t),
+        gte(column(t, "a", &accessor), const_bigint(2)),
+    );
+    assert_eq!(
+        expr.to_sql_string(),
+        "SELECT DISTINCT a, b FROM sxt.t WHERE a >= 2"
+    );
+}
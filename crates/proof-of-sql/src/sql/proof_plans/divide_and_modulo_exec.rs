@@ -0,0 +1,149 @@
+use super::dyn_proof_plan::check_result_aliases;
+use crate::{
+    base::{
+        commitment::Commitment,
+        database::{
+            Column, ColumnField, ColumnRef, CommitmentAccessor, DataAccessor, MetadataAccessor,
+            OwnedTable, TableRef,
+        },
+        map::IndexSet,
+        proof::ProofError,
+    },
+    sql::{
+        parse::ConversionResult,
+        proof::{
+            CountBuilder, FinalRoundBuilder, FirstRoundBuilder, ProofPlan, ProverEvaluate,
+            VerificationBuilder,
+        },
+        proof_exprs::{DivideAndModuloExpr, TableExpr},
+    },
+};
+use alloc::{vec, vec::Vec};
+use bumpalo::Bump;
+use proof_of_sql_parser::Identifier;
+use serde::{Deserialize, Serialize};
+
+/// Provable expressions for queries of the form
+/// ```ignore
+///     SELECT <lhs> / <rhs> AS <quotient_alias>, <lhs> % <rhs> AS <remainder_alias> FROM <table>
+/// ```
+///
+/// Both result columns come from the single witness `lhs = quotient * rhs + remainder`
+/// of [`DivideAndModuloExpr`], so the witness is proven once rather than once per column,
+/// as it would be for a projection of a [`DynProofExpr::Divide`](crate::sql::proof_exprs::DynProofExpr::Divide)
+/// and a [`DynProofExpr::Modulo`](crate::sql::proof_exprs::DynProofExpr::Modulo) of the same operands.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct DivideAndModuloExec<C: Commitment> {
+    pub(super) expr: DivideAndModuloExpr<C>,
+    pub(super) quotient_alias: Identifier,
+    pub(super) remainder_alias: Identifier,
+    pub(super) table: TableExpr,
+}
+
+impl<C: Commitment> DivideAndModuloExec<C> {
+    /// Creates a new divide-and-modulo expression.
+    ///
+    /// # Errors
+    /// Returns [`ConversionError::ColumnFromOtherTable`](crate::sql::parse::ConversionError::ColumnFromOtherTable)
+    /// if an operand references a column of another table,
+    /// and [`ConversionError::DuplicateResultAlias`](crate::sql::parse::ConversionError::DuplicateResultAlias)
+    /// if the quotient and the remainder have the same name.
+    pub fn try_new(
+        expr: DivideAndModuloExpr<C>,
+        quotient_alias: Identifier,
+        remainder_alias: Identifier,
+        table: TableExpr,
+    ) -> ConversionResult<Self> {
+        let plan = Self {
+            expr,
+            quotient_alias,
+            remainder_alias,
+            table,
+        };
+        plan.table
+            .check_column_references(&plan.get_column_references())?;
+        check_result_aliases([plan.quotient_alias, plan.remainder_alias])?;
+        Ok(plan)
+    }
+}
+
+impl<C: Commitment> ProofPlan<C> for DivideAndModuloExec<C> {
+    fn count(
+        &self,
+        builder: &mut CountBuilder,
+        _accessor: &dyn MetadataAccessor,
+    ) -> Result<(), ProofError> {
+        // The quotient and the remainder are already intermediate MLEs of the expression
+        self.expr.count(builder)
+    }
+
+    fn get_length(&self, accessor: &dyn MetadataAccessor) -> usize {
+        accessor.get_length(self.table.table_ref)
+    }
+
+    fn get_offset(&self, accessor: &dyn MetadataAccessor) -> usize {
+        accessor.get_offset(self.table.table_ref)
+    }
+
+    fn verifier_evaluate(
+        &self,
+        builder: &mut VerificationBuilder<C>,
+        accessor: &dyn CommitmentAccessor<C>,
+        _result: Option<&OwnedTable<C::Scalar>>,
+    ) -> Result<Vec<C::Scalar>, ProofError> {
+        let (quotient, remainder) = self.expr.verifier_evaluate(builder, accessor)?;
+        Ok(vec![quotient, remainder])
+    }
+
+    fn get_column_result_fields(&self) -> Vec<ColumnField> {
+        let (quotient_type, remainder_type) = self.expr.data_type();
+        vec![
+            ColumnField::new(self.quotient_alias, quotient_type),
+            ColumnField::new(self.remainder_alias, remainder_type),
+        ]
+    }
+
+    fn get_column_references(&self) -> IndexSet<ColumnRef> {
+        let mut columns = IndexSet::default();
+        self.expr.get_column_references(&mut columns);
+        columns
+    }
+
+    fn get_table_references(&self) -> IndexSet<TableRef> {
+        self.table.table_references(&self.get_column_references())
+    }
+}
+
+impl<C: Commitment> ProverEvaluate<C::Scalar> for DivideAndModuloExec<C> {
+    #[tracing::instrument(
+        name = "DivideAndModuloExec::result_evaluate",
+        level = "debug",
+        skip_all
+    )]
+    fn result_evaluate<'a>(
+        &self,
+        input_length: usize,
+        alloc: &'a Bump,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> Vec<Column<'a, C::Scalar>> {
+        let (quotient, remainder) = self.expr.result_evaluate(input_length, alloc, accessor);
+        vec![quotient, remainder]
+    }
+
+    fn first_round_evaluate(&self, _builder: &mut FirstRoundBuilder) {}
+
+    #[tracing::instrument(
+        name = "DivideAndModuloExec::final_round_evaluate",
+        level = "debug",
+        skip_all
+    )]
+    fn final_round_evaluate<'a>(
+        &self,
+        builder: &mut FinalRoundBuilder<'a, C::Scalar>,
+        alloc: &'a Bump,
+        accessor: &'a dyn DataAccessor<C::Scalar>,
+    ) -> Vec<Column<'a, C::Scalar>> {
+        let (quotient, remainder) = self.expr.prover_evaluate(builder, alloc, accessor);
+        vec![quotient, remainder]
+    }
+}
@@ -0,0 +1,66 @@
+use super::{test_utility::*, DivideAndModuloExec, DynProofPlan};
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{owned_table_utility::*, OwnedTableTestAccessor},
+    },
+    sql::{
+        parse::ConversionError,
+        proof::{exercise_verification, VerifiableQueryResult},
+        proof_exprs::{test_utility::*, DivideAndModuloExpr},
+    },
+};
+use curve25519_dalek::RistrettoPoint;
+
+#[test]
+fn we_can_prove_a_divide_and_modulo_of_expressions() {
+    let data = owned_table([bigint("a", [7_i64, -9, 10]), bigint("b", [2_i64, 4, -3])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast: DynProofPlan<RistrettoPoint> = divide_and_modulo(
+        add(column(t, "a", &accessor), const_bigint(1)),
+        column(t, "b", &accessor),
+        "q",
+        "r",
+        tab(t),
+    );
+    let verifiable_res = VerifiableQueryResult::<InnerProductProof>::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([bigint("q", [4_i64, -2, -3]), bigint("r", [0_i64, 0, 2])]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_can_render_a_divide_and_modulo_as_sql() {
+    let data = owned_table([bigint("a", [1_i64]), bigint("b", [1_i64])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast: DynProofPlan<RistrettoPoint> = divide_and_modulo(
+        column(t, "a", &accessor),
+        column(t, "b", &accessor),
+        "q",
+        "r",
+        tab(t),
+    );
+    assert_eq!(
+        ast.to_sql_string(),
+        "SELECT a / b AS q, a % b AS r FROM sxt.t"
+    );
+}
+
+#[test]
+fn we_cannot_create_a_divide_and_modulo_with_the_same_name_for_both_results() {
+    let data = owned_table([bigint("a", [1_i64]), bigint("b", [1_i64])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let expr = DivideAndModuloExpr::<RistrettoPoint>::try_new(
+        Box::new(column(t, "a", &accessor)),
+        Box::new(column(t, "b", &accessor)),
+    )
+    .unwrap();
+    assert!(matches!(
+        DivideAndModuloExec::try_new(expr, "q".parse().unwrap(), "q".parse().unwrap(), tab(t)),
+        Err(ConversionError::DuplicateResultAlias { .. })
+    ));
+}
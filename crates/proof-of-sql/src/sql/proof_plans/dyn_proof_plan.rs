@@ -1,25 +1,39 @@
-use super::{FilterExec, GroupByExec, ProjectionExec};
+use super::{DistinctExec, DivideAndModuloExec, FilterExec, GroupByExec, ProjectionExec};
 use crate::{
     base::{
         commitment::Commitment,
         database::{
-            Column, ColumnField, ColumnRef, CommitmentAccessor, DataAccessor, MetadataAccessor,
-            OwnedTable, TableRef,
+            Column, ColumnField, ColumnRef, CommitmentAccessor, DataAccessor, LiteralValue,
+            MetadataAccessor, OwnedTable, TableRef,
         },
         map::IndexSet,
         proof::ProofError,
     },
-    sql::proof::{
-        CountBuilder, FinalRoundBuilder, FirstRoundBuilder, ProofPlan, ProverEvaluate,
-        VerificationBuilder,
+    sql::{
+        parse::{ConversionError, ConversionResult},
+        proof::{
+            CountBuilder, FinalRoundBuilder, FirstRoundBuilder, ProofPlan, ProverEvaluate,
+            VerificationBuilder,
+        },
+        proof_exprs::{
+            AliasedDynProofExpr, ColumnExpr, DynProofExpr, TableExpr, DEFAULT_MAX_EXPR_DEPTH,
+        },
     },
 };
-use alloc::vec::Vec;
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
 use bumpalo::Bump;
-use serde::{Deserialize, Serialize};
+use proof_of_sql_parser::Identifier;
+use serde::{Deserialize, Deserializer, Serialize};
 
 /// The query plan for proving a query
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
+///
+/// Deserializing a plan fails if any of its expressions is nested deeper than [`DEFAULT_MAX_EXPR_DEPTH`].
+#[derive(Debug, PartialEq, Serialize)]
 pub enum DynProofPlan<C: Commitment> {
     /// Provable expressions for queries of the form
     /// ```ignore
@@ -41,6 +55,230 @@ pub enum DynProofPlan<C: Commitment> {
     ///     SELECT <result_expr1>, ..., <result_exprN> FROM <table> WHERE <where_clause>
     /// ```
     Filter(FilterExec<C>),
+    /// Provable expressions for queries of the form, where the result is sorted by the distinct columns
+    /// ```ignore
+    ///     SELECT DISTINCT <distinct_expr1>, ..., <distinct_exprM> FROM <table> WHERE <where_clause>
+    /// ```
+    Distinct(DistinctExec<C>),
+    /// Provable expressions for queries of the form, where both columns are proven from one witness
+    /// ```ignore
+    ///     SELECT <lhs> / <rhs> AS <quotient_alias>, <lhs> % <rhs> AS <remainder_alias> FROM <table>
+    /// ```
+    DivideAndModulo(DivideAndModuloExec<C>),
+}
+
+/// The serialized form of a [`DynProofPlan`], whose depth is not checked yet
+#[derive(Deserialize)]
+#[serde(rename = "DynProofPlan")]
+enum DynProofPlanParts<C: Commitment> {
+    Projection(ProjectionExec<C>),
+    GroupBy(GroupByExec<C>),
+    Filter(FilterExec<C>),
+    Distinct(DistinctExec<C>),
+    DivideAndModulo(DivideAndModuloExec<C>),
+}
+
+// Custom deserializer since proving and verifying recurse through the expressions of the plan,
+// and since the checks of the plan constructors have to hold for deserialized plans as well
+impl<'de, C: Commitment + Deserialize<'de>> Deserialize<'de> for DynProofPlan<C> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let plan = match DynProofPlanParts::deserialize(deserializer)? {
+            DynProofPlanParts::Projection(plan) => Self::Projection(plan),
+            DynProofPlanParts::GroupBy(plan) => Self::GroupBy(plan),
+            DynProofPlanParts::Filter(plan) => Self::Filter(plan),
+            DynProofPlanParts::Distinct(plan) => Self::Distinct(plan),
+            DynProofPlanParts::DivideAndModulo(plan) => Self::DivideAndModulo(plan),
+        };
+        plan.check_depth(DEFAULT_MAX_EXPR_DEPTH)
+            .map_err(serde::de::Error::custom)?;
+        plan.table()
+            .check_column_references(&plan.get_column_references())
+            .map_err(serde::de::Error::custom)?;
+        check_result_aliases(
+            plan.get_column_result_fields()
+                .iter()
+                .map(ColumnField::name),
+        )
+        .map_err(serde::de::Error::custom)?;
+        Ok(plan)
+    }
+}
+
+impl<C: Commitment> DynProofPlan<C> {
+    /// The table the plan reads from
+    fn table(&self) -> &TableExpr {
+        match self {
+            DynProofPlan::Projection(plan) => &plan.table,
+            DynProofPlan::Filter(plan) => &plan.table,
+            DynProofPlan::GroupBy(plan) => &plan.table,
+            DynProofPlan::Distinct(plan) => &plan.table,
+            DynProofPlan::DivideAndModulo(plan) => &plan.table,
+        }
+    }
+
+    /// The expressions of the plan, i.e. its result expressions and `WHERE` clauses
+    fn exprs(&self) -> Vec<&DynProofExpr<C>> {
+        match self {
+            DynProofPlan::Projection(plan) => plan
+                .aliased_results
+                .iter()
+                .map(|aliased| &aliased.expr)
+                .collect(),
+            DynProofPlan::Filter(plan) => plan
+                .aliased_results
+                .iter()
+                .map(|aliased| &aliased.expr)
+                .chain([&plan.where_clause])
+                .collect(),
+            DynProofPlan::GroupBy(plan) => plan
+                .sum_expr
+                .iter()
+                .map(|aliased| &aliased.expr)
+                .chain([&plan.where_clause])
+                .collect(),
+            DynProofPlan::Distinct(plan) => vec![&plan.where_clause],
+            DynProofPlan::DivideAndModulo(plan) => plan.expr.operands().to_vec(),
+        }
+    }
+
+    /// Check that no expression of the plan is nested deeper than `max_depth`
+    ///
+    /// See [`DynProofExpr::check_depth`].
+    ///
+    /// # Errors
+    /// Returns [`ConversionError::PlanTooDeep`] for the first expression whose depth exceeds `max_depth`.
+    pub fn check_depth(&self, max_depth: usize) -> ConversionResult<()> {
+        self.exprs()
+            .into_iter()
+            .try_for_each(|expr| expr.check_depth(max_depth))
+    }
+
+    /// Render the plan as approximate SQL, e.g. `SELECT b + 1 AS b, a * b AS prod FROM sxt.t`
+    ///
+    /// This is a best-effort rendering for logs and error messages.
+    /// It is meant to be readable and is not guaranteed to parse back into the same plan.
+    #[must_use]
+    pub fn to_sql_string(&self) -> String {
+        match self {
+            DynProofPlan::Projection(plan) => format!(
+                "SELECT {} FROM {}",
+                aliased_results_sql(&plan.aliased_results),
+                table_sql(&plan.table)
+            ),
+            DynProofPlan::Filter(plan) => format!(
+                "SELECT {} FROM {}{}",
+                aliased_results_sql(&plan.aliased_results),
+                table_sql(&plan.table),
+                where_sql(&plan.where_clause)
+            ),
+            DynProofPlan::GroupBy(plan) => {
+                let group_by = columns_sql(&plan.group_by_exprs);
+                let results = plan
+                    .group_by_exprs
+                    .iter()
+                    .map(|expr| DynProofExpr::Column(expr.clone()).to_string())
+                    .chain(
+                        plan.sum_expr
+                            .iter()
+                            .map(|aliased| format!("{} AS {}", aliased.expr, aliased.alias)),
+                    )
+                    .chain([format!("COUNT(*) AS {}", plan.count_alias)])
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let group_by_clause = if group_by.is_empty() {
+                    String::new()
+                } else {
+                    format!(" GROUP BY {group_by}")
+                };
+                format!(
+                    "SELECT {results} FROM {}{}{group_by_clause}",
+                    table_sql(&plan.table),
+                    where_sql(&plan.where_clause)
+                )
+            }
+            DynProofPlan::Distinct(plan) => format!(
+                "SELECT DISTINCT {} FROM {}{}",
+                columns_sql(&plan.distinct_exprs),
+                table_sql(&plan.table),
+                where_sql(&plan.where_clause)
+            ),
+            DynProofPlan::DivideAndModulo(plan) => {
+                let [lhs, rhs] = plan.expr.operands();
+                format!(
+                    "SELECT {lhs} / {rhs} AS {}, {lhs} % {rhs} AS {} FROM {}",
+                    plan.quotient_alias,
+                    plan.remainder_alias,
+                    table_sql(&plan.table)
+                )
+            }
+        }
+    }
+}
+
+/// Check that no two result columns share a name
+///
+/// Plans check this when they are built, since a result with duplicate column names
+/// can not be turned into an [`OwnedTable`].
+///
+/// # Errors
+/// Returns [`ConversionError::DuplicateResultAlias`] with the first name that is used more than once.
+pub(super) fn check_result_aliases(
+    names: impl IntoIterator<Item = Identifier>,
+) -> ConversionResult<()> {
+    let mut seen = IndexSet::default();
+    match names.into_iter().find(|name| !seen.insert(*name)) {
+        Some(name) => Err(ConversionError::DuplicateResultAlias {
+            alias: name.to_string(),
+        }),
+        None => Ok(()),
+    }
+}
+
+/// The SQL for a list of result expressions, omitting the alias of a column that keeps its name
+fn aliased_results_sql<C: Commitment>(aliased_results: &[AliasedDynProofExpr<C>]) -> String {
+    aliased_results
+        .iter()
+        .map(|AliasedDynProofExpr { expr, alias }| match expr {
+            DynProofExpr::Column(column)
+                if column.column_id() == *alias
+                    && column.get_column_reference().table_alias().is_none() =>
+            {
+                alias.to_string()
+            }
+            _ => format!("{expr} AS {alias}"),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// The SQL for a list of columns
+fn columns_sql<C: Commitment>(columns: &[ColumnExpr<C>]) -> String {
+    columns
+        .iter()
+        .map(|column| DynProofExpr::Column(column.clone()).to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// The SQL for a table, with its alias if it has one
+fn table_sql(table: &TableExpr) -> String {
+    match table.alias {
+        Some(alias) => format!("{} AS {alias}", table.table_ref),
+        None => table.table_ref.to_string(),
+    }
+}
+
+/// The SQL for a `WHERE` clause, which is omitted if it is always true
+fn where_sql<C: Commitment>(where_clause: &DynProofExpr<C>) -> String {
+    match where_clause {
+        DynProofExpr::Literal(literal) if *literal.value() == LiteralValue::Boolean(true) => {
+            String::new()
+        }
+        _ => format!(" WHERE {where_clause}"),
+    }
 }
 
 impl<C: Commitment> ProofPlan<C> for DynProofPlan<C> {
@@ -53,6 +291,8 @@ impl<C: Commitment> ProofPlan<C> for DynProofPlan<C> {
             DynProofPlan::Projection(expr) => expr.count(builder, accessor),
             DynProofPlan::GroupBy(expr) => expr.count(builder, accessor),
             DynProofPlan::Filter(expr) => expr.count(builder, accessor),
+            DynProofPlan::Distinct(expr) => expr.count(builder, accessor),
+            DynProofPlan::DivideAndModulo(expr) => expr.count(builder, accessor),
         }
     }
 
@@ -61,6 +301,8 @@ impl<C: Commitment> ProofPlan<C> for DynProofPlan<C> {
             DynProofPlan::Projection(expr) => expr.get_length(accessor),
             DynProofPlan::GroupBy(expr) => expr.get_length(accessor),
             DynProofPlan::Filter(expr) => expr.get_length(accessor),
+            DynProofPlan::Distinct(expr) => expr.get_length(accessor),
+            DynProofPlan::DivideAndModulo(expr) => expr.get_length(accessor),
         }
     }
 
@@ -69,6 +311,8 @@ impl<C: Commitment> ProofPlan<C> for DynProofPlan<C> {
             DynProofPlan::Projection(expr) => expr.get_offset(accessor),
             DynProofPlan::GroupBy(expr) => expr.get_offset(accessor),
             DynProofPlan::Filter(expr) => expr.get_offset(accessor),
+            DynProofPlan::Distinct(expr) => expr.get_offset(accessor),
+            DynProofPlan::DivideAndModulo(expr) => expr.get_offset(accessor),
         }
     }
 
@@ -83,6 +327,10 @@ impl<C: Commitment> ProofPlan<C> for DynProofPlan<C> {
             DynProofPlan::Projection(expr) => expr.verifier_evaluate(builder, accessor, result),
             DynProofPlan::GroupBy(expr) => expr.verifier_evaluate(builder, accessor, result),
             DynProofPlan::Filter(expr) => expr.verifier_evaluate(builder, accessor, result),
+            DynProofPlan::Distinct(expr) => expr.verifier_evaluate(builder, accessor, result),
+            DynProofPlan::DivideAndModulo(expr) => {
+                expr.verifier_evaluate(builder, accessor, result)
+            }
         }
     }
 
@@ -91,6 +339,8 @@ impl<C: Commitment> ProofPlan<C> for DynProofPlan<C> {
             DynProofPlan::Projection(expr) => expr.get_column_result_fields(),
             DynProofPlan::GroupBy(expr) => expr.get_column_result_fields(),
             DynProofPlan::Filter(expr) => expr.get_column_result_fields(),
+            DynProofPlan::Distinct(expr) => expr.get_column_result_fields(),
+            DynProofPlan::DivideAndModulo(expr) => expr.get_column_result_fields(),
         }
     }
 
@@ -99,6 +349,8 @@ impl<C: Commitment> ProofPlan<C> for DynProofPlan<C> {
             DynProofPlan::Projection(expr) => expr.get_column_references(),
             DynProofPlan::GroupBy(expr) => expr.get_column_references(),
             DynProofPlan::Filter(expr) => expr.get_column_references(),
+            DynProofPlan::Distinct(expr) => expr.get_column_references(),
+            DynProofPlan::DivideAndModulo(expr) => expr.get_column_references(),
         }
     }
 
@@ -107,6 +359,8 @@ impl<C: Commitment> ProofPlan<C> for DynProofPlan<C> {
             DynProofPlan::Projection(expr) => expr.get_table_references(),
             DynProofPlan::GroupBy(expr) => expr.get_table_references(),
             DynProofPlan::Filter(expr) => expr.get_table_references(),
+            DynProofPlan::Distinct(expr) => expr.get_table_references(),
+            DynProofPlan::DivideAndModulo(expr) => expr.get_table_references(),
         }
     }
 }
@@ -123,6 +377,10 @@ impl<C: Commitment> ProverEvaluate<C::Scalar> for DynProofPlan<C> {
             DynProofPlan::Projection(expr) => expr.result_evaluate(input_length, alloc, accessor),
             DynProofPlan::GroupBy(expr) => expr.result_evaluate(input_length, alloc, accessor),
             DynProofPlan::Filter(expr) => expr.result_evaluate(input_length, alloc, accessor),
+            DynProofPlan::Distinct(expr) => expr.result_evaluate(input_length, alloc, accessor),
+            DynProofPlan::DivideAndModulo(expr) => {
+                expr.result_evaluate(input_length, alloc, accessor)
+            }
         }
     }
 
@@ -131,6 +389,8 @@ impl<C: Commitment> ProverEvaluate<C::Scalar> for DynProofPlan<C> {
             DynProofPlan::Projection(expr) => expr.first_round_evaluate(builder),
             DynProofPlan::GroupBy(expr) => expr.first_round_evaluate(builder),
             DynProofPlan::Filter(expr) => expr.first_round_evaluate(builder),
+            DynProofPlan::Distinct(expr) => expr.first_round_evaluate(builder),
+            DynProofPlan::DivideAndModulo(expr) => expr.first_round_evaluate(builder),
         }
     }
 
@@ -145,6 +405,10 @@ impl<C: Commitment> ProverEvaluate<C::Scalar> for DynProofPlan<C> {
             DynProofPlan::Projection(expr) => expr.final_round_evaluate(builder, alloc, accessor),
             DynProofPlan::GroupBy(expr) => expr.final_round_evaluate(builder, alloc, accessor),
             DynProofPlan::Filter(expr) => expr.final_round_evaluate(builder, alloc, accessor),
+            DynProofPlan::Distinct(expr) => expr.final_round_evaluate(builder, alloc, accessor),
+            DynProofPlan::DivideAndModulo(expr) => {
+                expr.final_round_evaluate(builder, alloc, accessor)
+            }
         }
     }
 }
@@ -1,4 +1,4 @@
-use super::{fold_columns, fold_vals};
+use super::{dyn_proof_plan::check_result_aliases, fold_columns, fold_vals};
 use crate::{
     base::{
         commitment::Commitment,
@@ -12,6 +12,7 @@ use crate::{
         slice_ops,
     },
     sql::{
+        parse::ConversionResult,
         proof::{
             CountBuilder, FinalRoundBuilder, FirstRoundBuilder, HonestProver, ProofPlan,
             ProverEvaluate, ProverHonestyMarker, SumcheckSubpolynomialType, VerificationBuilder,
@@ -23,6 +24,7 @@ use alloc::{boxed::Box, vec, vec::Vec};
 use bumpalo::Bump;
 use core::{iter::repeat_with, marker::PhantomData};
 use num_traits::{One, Zero};
+use proof_of_sql_parser::Identifier;
 use serde::{Deserialize, Serialize};
 
 /// Provable expressions for queries of the form
@@ -42,6 +44,8 @@ pub struct OstensibleFilterExec<C: Commitment, H: ProverHonestyMarker> {
 
 impl<C: Commitment, H: ProverHonestyMarker> OstensibleFilterExec<C, H> {
     /// Creates a new filter expression.
+    ///
+    /// Unlike [`Self::try_new`], this does not check the column references or the result aliases.
     pub fn new(
         aliased_results: Vec<AliasedDynProofExpr<C>>,
         table: TableExpr,
@@ -54,6 +58,54 @@ impl<C: Commitment, H: ProverHonestyMarker> OstensibleFilterExec<C, H> {
             phantom: PhantomData,
         }
     }
+
+    /// Creates a new filter expression, checking its column references and result aliases.
+    ///
+    /// # Errors
+    /// Returns [`ConversionError::ColumnFromOtherTable`](crate::sql::parse::ConversionError::ColumnFromOtherTable)
+    /// if an expression references a column of another table, and
+    /// [`ConversionError::DuplicateResultAlias`](crate::sql::parse::ConversionError::DuplicateResultAlias)
+    /// if two result columns have the same name.
+    pub fn try_new(
+        aliased_results: Vec<AliasedDynProofExpr<C>>,
+        table: TableExpr,
+        where_clause: DynProofExpr<C>,
+    ) -> ConversionResult<Self> {
+        let mut columns = IndexSet::default();
+        for aliased_expr in &aliased_results {
+            aliased_expr.expr.get_column_references(&mut columns);
+        }
+        where_clause.get_column_references(&mut columns);
+        table.check_column_references(&columns)?;
+        check_result_aliases(
+            aliased_results
+                .iter()
+                .map(|aliased_expr| aliased_expr.alias),
+        )?;
+        Ok(Self::new(aliased_results, table, where_clause))
+    }
+
+    /// Adds the predicate of the `WHERE` clause as an extra boolean result column named `alias`.
+    ///
+    /// This is the same as selecting the `WHERE` clause as a result expression,
+    /// so the column is proven like any other and is `true` on every retained row.
+    /// Other boolean expressions can be returned by adding them to the results as usual.
+    ///
+    /// # Errors
+    /// Returns [`ConversionError::DuplicateResultAlias`](crate::sql::parse::ConversionError::DuplicateResultAlias)
+    /// if there already is a result column named `alias`.
+    pub fn try_with_predicate_column(mut self, alias: Identifier) -> ConversionResult<Self> {
+        self.aliased_results.push(AliasedDynProofExpr {
+            expr: self.where_clause.clone(),
+            alias,
+        });
+        check_result_aliases(
+            self.aliased_results
+                .iter()
+                .map(|aliased_expr| aliased_expr.alias),
+        )?;
+        Ok(self)
+    }
 }
 
 impl<C: Commitment, H: ProverHonestyMarker> ProofPlan<C> for OstensibleFilterExec<C, H>
@@ -141,7 +193,7 @@ where
     }
 
     fn get_table_references(&self) -> IndexSet<TableRef> {
-        IndexSet::from_iter([self.table.table_ref])
+        self.table.table_references(&self.get_column_references())
     }
 }
 
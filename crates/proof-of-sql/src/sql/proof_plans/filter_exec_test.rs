@@ -1,4 +1,4 @@
-use super::{test_utility::*, FilterExec};
+use super::{test_utility::*, DynProofPlan, FilterExec};
 use crate::{
     base::{
         database::{
@@ -10,6 +10,7 @@ use crate::{
         scalar::Curve25519Scalar,
     },
     sql::{
+        parse::ConversionError,
         proof::{
             exercise_verification, FirstRoundBuilder, ProofPlan, ProvableQueryResult,
             ProverEvaluate, VerifiableQueryResult,
@@ -27,10 +28,10 @@ fn we_can_correctly_fetch_the_query_result_schema() {
     let table_ref = TableRef::new(ResourceId::try_new("sxt", "sxt_tab").unwrap());
     let a = Identifier::try_new("a").unwrap();
     let b = Identifier::try_new("b").unwrap();
-    let provable_ast = FilterExec::<RistrettoPoint>::new(
+    let provable_ast = FilterExec::<RistrettoPoint>::try_new(
         vec![
             aliased_plan(
-                DynProofExpr::Column(ColumnExpr::new(ColumnRef::new(
+                DynProofExpr::Column(ColumnExpr::new(&ColumnRef::new(
                     table_ref,
                     a,
                     ColumnType::BigInt,
@@ -38,7 +39,7 @@ fn we_can_correctly_fetch_the_query_result_schema() {
                 "a",
             ),
             aliased_plan(
-                DynProofExpr::Column(ColumnExpr::new(ColumnRef::new(
+                DynProofExpr::Column(ColumnExpr::new(&ColumnRef::new(
                     table_ref,
                     b,
                     ColumnType::BigInt,
@@ -46,9 +47,12 @@ fn we_can_correctly_fetch_the_query_result_schema() {
                 "b",
             ),
         ],
-        TableExpr { table_ref },
+        TableExpr {
+            table_ref,
+            alias: None,
+        },
         DynProofExpr::try_new_equals(
-            DynProofExpr::Column(ColumnExpr::new(ColumnRef::new(
+            DynProofExpr::Column(ColumnExpr::new(&ColumnRef::new(
                 table_ref,
                 Identifier::try_new("c").unwrap(),
                 ColumnType::BigInt,
@@ -56,7 +60,8 @@ fn we_can_correctly_fetch_the_query_result_schema() {
             DynProofExpr::Literal(LiteralExpr::new(LiteralValue::BigInt(123))),
         )
         .unwrap(),
-    );
+    )
+    .unwrap();
 
     let column_fields: Vec<ColumnField> = provable_ast.get_column_result_fields();
     assert_eq!(
@@ -73,10 +78,10 @@ fn we_can_correctly_fetch_all_the_referenced_columns() {
     let table_ref = TableRef::new(ResourceId::try_new("sxt", "sxt_tab").unwrap());
     let a = Identifier::try_new("a").unwrap();
     let f = Identifier::try_new("f").unwrap();
-    let provable_ast = FilterExec::new(
+    let provable_ast = FilterExec::try_new(
         vec![
             aliased_plan(
-                DynProofExpr::Column(ColumnExpr::new(ColumnRef::new(
+                DynProofExpr::Column(ColumnExpr::new(&ColumnRef::new(
                     table_ref,
                     a,
                     ColumnType::BigInt,
@@ -84,7 +89,7 @@ fn we_can_correctly_fetch_all_the_referenced_columns() {
                 "a",
             ),
             aliased_plan(
-                DynProofExpr::Column(ColumnExpr::new(ColumnRef::new(
+                DynProofExpr::Column(ColumnExpr::new(&ColumnRef::new(
                     table_ref,
                     f,
                     ColumnType::BigInt,
@@ -92,11 +97,14 @@ fn we_can_correctly_fetch_all_the_referenced_columns() {
                 "f",
             ),
         ],
-        TableExpr { table_ref },
+        TableExpr {
+            table_ref,
+            alias: None,
+        },
         not::<RistrettoPoint>(and(
             or(
                 DynProofExpr::try_new_equals(
-                    DynProofExpr::Column(ColumnExpr::new(ColumnRef::new(
+                    DynProofExpr::Column(ColumnExpr::new(&ColumnRef::new(
                         table_ref,
                         Identifier::try_new("f").unwrap(),
                         ColumnType::BigInt,
@@ -105,7 +113,7 @@ fn we_can_correctly_fetch_all_the_referenced_columns() {
                 )
                 .unwrap(),
                 DynProofExpr::try_new_equals(
-                    DynProofExpr::Column(ColumnExpr::new(ColumnRef::new(
+                    DynProofExpr::Column(ColumnExpr::new(&ColumnRef::new(
                         table_ref,
                         Identifier::try_new("c").unwrap(),
                         ColumnType::BigInt,
@@ -115,7 +123,7 @@ fn we_can_correctly_fetch_all_the_referenced_columns() {
                 .unwrap(),
             ),
             DynProofExpr::try_new_equals(
-                DynProofExpr::Column(ColumnExpr::new(ColumnRef::new(
+                DynProofExpr::Column(ColumnExpr::new(&ColumnRef::new(
                     table_ref,
                     Identifier::try_new("b").unwrap(),
                     ColumnType::BigInt,
@@ -124,7 +132,8 @@ fn we_can_correctly_fetch_all_the_referenced_columns() {
             )
             .unwrap(),
         )),
-    );
+    )
+    .unwrap();
 
     let ref_columns = provable_ast.get_column_references();
 
@@ -159,6 +168,129 @@ fn we_can_correctly_fetch_all_the_referenced_columns() {
     assert_eq!(ref_tables, IndexSet::from_iter([table_ref]));
 }
 
+#[test]
+fn we_get_each_table_reference_once_for_a_filter_over_a_self_referenced_table() {
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        t,
+        owned_table([bigint("a", [1, 2, 3]), bigint("b", [3, 2, 1])]),
+        0,
+        (),
+    );
+    let l = aliased_tab(t, "l");
+    let r = aliased_tab(t, "r");
+    let ast: DynProofPlan<RistrettoPoint> = filter(
+        vec![aliased_plan(tab_column(&l, "a", &accessor), "l_a")],
+        tab(t),
+        equal(
+            tab_column(&l, "a", &accessor),
+            tab_column(&r, "b", &accessor),
+        ),
+    );
+    assert_eq!(ast.get_column_references().len(), 2);
+    assert_eq!(ast.get_table_references(), IndexSet::from_iter([t]));
+}
+
+/// `select a, b from sxt.t where a in (select k from sxt.other where m = 1)`
+#[test]
+fn we_can_prove_a_semi_join_using_the_verified_result_of_a_sub_query() {
+    let t = "sxt.t".parse().unwrap();
+    let other = "sxt.other".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        t,
+        owned_table([
+            bigint("a", [1, 2, 3, 5, 7, 9, 2]),
+            varchar("b", ["t", "u", "v", "w", "x", "y", "z"]),
+        ]),
+        0,
+        (),
+    );
+    accessor.add_table(
+        other,
+        owned_table([bigint("k", [2, 5, 5, 9, 7]), bigint("m", [1, 1, 1, 1, 0])]),
+        0,
+    );
+
+    // The sub-query is proven and verified on its own first.
+    let right = filter(
+        cols_expr_plan(other, &["k"], &accessor),
+        tab(other),
+        equal(column(other, "m", &accessor), const_bigint(1)),
+    );
+    let right_res = VerifiableQueryResult::new(&right, &accessor, &());
+    exercise_verification(&right_res, &right, &accessor, other);
+    let keys = right_res.verify(&right, &accessor, &()).unwrap().table;
+    let keys = keys.inner_table()[&"k".parse::<Identifier>().unwrap()].to_literal_values();
+
+    // Its verified keys then become the list that the left rows are tested against.
+    let left = filter(
+        cols_expr_plan(t, &["a", "b"], &accessor),
+        tab(t),
+        DynProofExpr::try_new_in_list(&column(t, "a", &accessor), &keys).unwrap(),
+    );
+    let res = VerifiableQueryResult::new(&left, &accessor, &());
+    exercise_verification(&res, &left, &accessor, t);
+    let res = res.verify(&left, &accessor, &()).unwrap().table;
+    let expected = owned_table([
+        bigint("a", [2, 5, 9, 2]),
+        varchar("b", ["u", "w", "y", "z"]),
+    ]);
+    assert_eq!(res, expected);
+}
+
+#[test]
+fn we_can_prove_a_filter_with_an_empty_in_list() {
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        t,
+        owned_table([bigint("a", [1, 2, 3])]),
+        0,
+        (),
+    );
+    let expr = filter(
+        cols_expr_plan(t, &["a"], &accessor),
+        tab(t),
+        DynProofExpr::try_new_in_list(&column(t, "a", &accessor), &[]).unwrap(),
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    assert_eq!(res, owned_table([bigint("a", [0; 0])]));
+}
+
+#[test]
+fn we_can_create_an_in_list_as_a_balanced_or_of_distinct_values() {
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        t,
+        owned_table([bigint("a", [1, 2, 3])]),
+        0,
+        (),
+    );
+    // 64 distinct values, each given twice
+    let values: Vec<_> = (0..128).map(|i| LiteralValue::BigInt(i % 64)).collect();
+    let expr = DynProofExpr::<RistrettoPoint>::try_new_in_list(&column(t, "a", &accessor), &values)
+        .unwrap();
+    // 6 levels of `OR`, then the equality and its operands
+    assert_eq!(expr.depth(), 8);
+}
+
+#[test]
+fn we_cannot_create_an_in_list_with_values_of_an_incompatible_type() {
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        t,
+        owned_table([bigint("a", [1, 2, 3])]),
+        0,
+        (),
+    );
+    assert!(DynProofExpr::<RistrettoPoint>::try_new_in_list(
+        &column(t, "a", &accessor),
+        &[LiteralValue::BigInt(1), LiteralValue::Boolean(true)]
+    )
+    .is_err());
+}
+
 #[test]
 fn we_can_prove_and_get_the_correct_result_from_a_basic_filter() {
     let data = owned_table([
@@ -403,6 +535,63 @@ fn we_can_prove_a_filter_with_empty_results() {
     assert_eq!(res, expected);
 }
 
+#[test]
+fn we_can_prove_a_filter_with_empty_results_and_keep_the_full_result_schema() {
+    let data = owned_table([
+        bigint("a", [101, 104, 105]),
+        varchar("d", ["1", "2", "3"]),
+        decimal75("e", 12, 3, [1, 2, 3]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = filter(
+        cols_expr_plan(t, &["d", "e"], &accessor),
+        tab(t),
+        equal(column(t, "a", &accessor), const_int128(106)),
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    assert_eq!(res.num_rows(), 0);
+    let schema: Vec<_> = res
+        .inner_table()
+        .iter()
+        .map(|(name, column)| ColumnField::new(*name, column.column_type()))
+        .collect();
+    assert_eq!(
+        schema,
+        vec![
+            ColumnField::new("d".parse().unwrap(), ColumnType::VarChar),
+            ColumnField::new(
+                "e".parse().unwrap(),
+                ColumnType::Decimal75(Precision::new(12).unwrap(), 3)
+            ),
+        ]
+    );
+    assert_eq!(schema, expr.get_column_result_fields());
+}
+
+#[test]
+fn we_cannot_verify_a_filter_with_empty_results_and_trailing_result_data() {
+    let data = owned_table([
+        bigint("a", [101, 104, 105]),
+        varchar("d", ["1", "2", "3"]),
+        decimal75("e", 12, 3, [1, 2, 3]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = filter(
+        cols_expr_plan(t, &["d", "e"], &accessor),
+        tab(t),
+        equal(column(t, "a", &accessor), const_int128(106)),
+    );
+    let mut res = VerifiableQueryResult::<InnerProductProof>::new(&expr, &accessor, &());
+    res.provable_result.as_mut().unwrap().data_mut().push(0);
+    assert!(res.verify(&expr, &accessor, &()).is_err());
+}
+
 #[test]
 fn we_can_prove_a_filter() {
     let data = owned_table([
@@ -443,3 +632,98 @@ fn we_can_prove_a_filter() {
     ]);
     assert_eq!(res, expected);
 }
+#[test]
+fn we_can_prove_a_filter_that_returns_its_predicate_and_other_boolean_columns() {
+    let data = owned_table([
+        bigint("a", [5, 1, 7, 3, 9]),
+        bigint("b", [2, 4, 7, 1, 3]),
+        bigint("c", [0, 1, 1, 0, 1]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let where_clause = gte(column(t, "a", &accessor), column(t, "b", &accessor));
+    let expr = DynProofPlan::Filter(
+        FilterExec::try_new(
+            vec![
+                col_expr_plan(t, "a", &accessor),
+                aliased_plan(
+                    equal(column(t, "c", &accessor), const_bigint(1)),
+                    "c_is_one",
+                ),
+            ],
+            tab(t),
+            where_clause,
+        )
+        .unwrap()
+        .try_with_predicate_column("flag".parse().unwrap())
+        .unwrap(),
+    );
+    assert_eq!(
+        expr.get_column_result_fields(),
+        vec![
+            ColumnField::new("a".parse().unwrap(), ColumnType::BigInt),
+            ColumnField::new("c_is_one".parse().unwrap(), ColumnType::Boolean),
+            ColumnField::new("flag".parse().unwrap(), ColumnType::Boolean),
+        ]
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    let expected = owned_table([
+        bigint("a", [5, 7, 3, 9]),
+        boolean("c_is_one", [false, true, false, true]),
+        boolean("flag", [true, true, true, true]),
+    ]);
+    assert_eq!(res, expected);
+}
+
+#[test]
+fn we_cannot_return_the_predicate_of_a_filter_under_an_existing_name() {
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        t,
+        owned_table([bigint("a", [1, 2]), bigint("b", [2, 1])]),
+        0,
+        (),
+    );
+    let filter = FilterExec::<RistrettoPoint>::try_new(
+        cols_expr_plan(t, &["a"], &accessor),
+        tab(t),
+        gte(column(t, "a", &accessor), column(t, "b", &accessor)),
+    )
+    .unwrap();
+    assert!(matches!(
+        filter.try_with_predicate_column("a".parse().unwrap()),
+        Err(ConversionError::DuplicateResultAlias { alias }) if alias == "a"
+    ));
+}
+
+#[test]
+fn we_can_render_a_filter_as_sql() {
+    let data = owned_table([
+        bigint("a", [1, 2]),
+        decimal75("d", 5, 2, [100, 250]),
+        varchar("v", ["x", "y"]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let expr: DynProofPlan<RistrettoPoint> = filter(
+        cols_expr_plan(t, &["a", "v"], &accessor),
+        tab(t),
+        and(
+            equal(column(t, "v", &accessor), const_varchar("y")),
+            gte(column(t, "d", &accessor), const_decimal75(3, 1, 15)),
+        ),
+    );
+    assert_eq!(
+        expr.to_sql_string(),
+        "SELECT a, v FROM sxt.t WHERE (v = 'y') AND (d >= 1.5)"
+    );
+    let expr: DynProofPlan<RistrettoPoint> = filter(
+        vec![aliased_plan(const_bool(false), "f")],
+        tab(t),
+        const_bool(true),
+    );
+    assert_eq!(expr.to_sql_string(), "SELECT FALSE AS f FROM sxt.t");
+}
@@ -148,11 +148,12 @@ fn we_fail_to_verify_a_basic_filter_with_a_dishonest_prover() {
     let t = "sxt.t".parse().unwrap();
     let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
     accessor.add_table(t, data, 0);
-    let expr = DishonestFilterExec::new(
+    let expr = DishonestFilterExec::try_new(
         cols_expr_plan(t, &["b", "c", "d", "e"], &accessor),
         tab(t),
         equal(column(t, "a", &accessor), const_int128(105_i128)),
-    );
+    )
+    .unwrap();
     let res = VerifiableQueryResult::<InnerProductProof>::new(&expr, &accessor, &());
     assert!(matches!(
         res.verify(&expr, &accessor, &()),
@@ -0,0 +1,214 @@
+use super::{test_utility::*, DynProofPlan};
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{
+            owned_table_utility::*, ColumnType, LiteralValue, OwnedColumn, OwnedTableTestAccessor,
+            TableRef,
+        },
+        scalar::Curve25519Scalar,
+    },
+    sql::{
+        proof::{exercise_verification, VerifiableQueryResult},
+        proof_exprs::{test_utility::*, AliasedDynProofExpr, DynProofExpr},
+    },
+};
+use curve25519_dalek::RistrettoPoint;
+use proof_of_sql_parser::Identifier;
+use rand::{seq::SliceRandom, Rng};
+
+/// The decimal type used for [`ColumnType::Decimal75`] columns.
+const DECIMAL_PRECISION: u8 = 10;
+const DECIMAL_SCALE: i8 = 2;
+
+/// Proves and verifies a random plan over a random table with two columns, `a` and `b`, of the given type.
+///
+/// The table also has a `BigInt` column `k`, which is summed in group by plans.
+/// The plan is a projection, filter, group by or distinct over `a` and `b`,
+/// built from the expressions that are implemented for `column_type`.
+/// Verification must succeed. Half of the time the proof is also tampered with,
+/// and each tampered proof must fail to verify.
+///
+/// # Panics
+/// Panics if verification fails, if a tampered proof verifies,
+/// or if `column_type` is not supported by the fuzzer.
+pub fn fuzz_roundtrip(column_type: ColumnType, rng: &mut impl Rng) {
+    let num_rows = rng.gen_range(1..=20);
+    let offset = rng.gen_range(0..100);
+    let data = owned_table([
+        random_column("a", column_type, num_rows, rng),
+        random_column("b", column_type, num_rows, rng),
+        bigint("k", (0..num_rows).map(|_| rng.gen_range(-3..=3))),
+    ]);
+    let t: TableRef = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, offset, ());
+
+    let plan = random_plan(column_type, t, &accessor, rng);
+    let res = VerifiableQueryResult::new(&plan, &accessor, &());
+    if rng.gen() {
+        exercise_verification(&res, &plan, &accessor, t);
+    } else {
+        res.verify(&plan, &accessor, &()).unwrap();
+    }
+}
+
+/// Returns a column of the given type with values in a small range, so that equalities are likely to hold.
+fn random_column(
+    name: &str,
+    column_type: ColumnType,
+    num_rows: usize,
+    rng: &mut impl Rng,
+) -> (Identifier, OwnedColumn<Curve25519Scalar>) {
+    let values: Vec<i8> = (0..num_rows).map(|_| rng.gen_range(-3..=3)).collect();
+    let values = values.into_iter();
+    match column_type {
+        ColumnType::Boolean => boolean(name, values.map(|v| v > 0)),
+        ColumnType::TinyInt => tinyint(name, values),
+        ColumnType::SmallInt => smallint(name, values),
+        ColumnType::Int => int(name, values),
+        ColumnType::BigInt => bigint(name, values),
+        ColumnType::Int128 => int128(name, values),
+        ColumnType::Decimal75(precision, scale)
+            if precision.value() == DECIMAL_PRECISION && scale == DECIMAL_SCALE =>
+        {
+            decimal75(name, DECIMAL_PRECISION, DECIMAL_SCALE, values)
+        }
+        ColumnType::VarChar => varchar(name, values.map(|v| format!("s{v}"))),
+        _ => panic!("fuzzing is not supported for {column_type}"),
+    }
+}
+
+/// Returns a literal of the given type with a value in the same range as [`random_column`].
+fn random_literal(column_type: ColumnType, rng: &mut impl Rng) -> DynProofExpr<RistrettoPoint> {
+    let value: i8 = rng.gen_range(-3..=3);
+    DynProofExpr::new_literal(match column_type {
+        ColumnType::Boolean => LiteralValue::Boolean(value > 0),
+        ColumnType::TinyInt => LiteralValue::TinyInt(value),
+        ColumnType::SmallInt => LiteralValue::SmallInt(value.into()),
+        ColumnType::Int => LiteralValue::Int(value.into()),
+        ColumnType::BigInt => LiteralValue::BigInt(value.into()),
+        ColumnType::Int128 => LiteralValue::Int128(value.into()),
+        ColumnType::Decimal75(precision, scale) => {
+            LiteralValue::Decimal75(precision, scale, value.into())
+        }
+        ColumnType::VarChar => {
+            let value = format!("s{value}");
+            let scalar = value.as_str().into();
+            LiteralValue::VarChar((value, scalar))
+        }
+        _ => panic!("fuzzing is not supported for {column_type}"),
+    })
+}
+
+/// Returns a random predicate built from the expressions implemented for `column_type`.
+fn random_predicate(
+    column_type: ColumnType,
+    t: TableRef,
+    accessor: &OwnedTableTestAccessor<InnerProductProof>,
+    rng: &mut impl Rng,
+) -> DynProofExpr<RistrettoPoint> {
+    let a = || column(t, "a", accessor);
+    let b = || column(t, "b", accessor);
+    let mut predicates = vec![
+        const_bool(true),
+        equal(a(), b()),
+        equal(a(), random_literal(column_type, rng)),
+        not(equal(b(), random_literal(column_type, rng))),
+    ];
+    match column_type {
+        ColumnType::Boolean => predicates.extend([
+            a(),
+            and(a(), b()),
+            or(a(), not(b())),
+            equal(a(), and(b(), random_literal(column_type, rng))),
+        ]),
+        ColumnType::VarChar => {}
+        _ => predicates.extend([
+            lte(a(), random_literal(column_type, rng)),
+            gte(a(), b()),
+            and(
+                gte(a(), random_literal(column_type, rng)),
+                lte(b(), random_literal(column_type, rng)),
+            ),
+            or(lte(a(), b()), equal(b(), random_literal(column_type, rng))),
+            equal(add(a(), b()), random_literal(column_type, rng)),
+        ]),
+    }
+    predicates.swap_remove(rng.gen_range(0..predicates.len()))
+}
+
+/// Returns a random, non-empty list of result expressions built from the expressions implemented for `column_type`.
+fn random_results(
+    column_type: ColumnType,
+    t: TableRef,
+    accessor: &OwnedTableTestAccessor<InnerProductProof>,
+    rng: &mut impl Rng,
+) -> Vec<AliasedDynProofExpr<RistrettoPoint>> {
+    let a = || column(t, "a", accessor);
+    let b = || column(t, "b", accessor);
+    let mut results = vec![
+        aliased_plan(a(), "a"),
+        aliased_plan(b(), "b"),
+        aliased_plan(random_literal(column_type, rng), "a_lit"),
+        aliased_plan(equal(a(), b()), "a_eq_b"),
+    ];
+    match column_type {
+        ColumnType::Boolean => {
+            results.extend([
+                aliased_plan(and(a(), b()), "a_and_b"),
+                aliased_plan(or(a(), b()), "a_or_b"),
+                aliased_plan(not(a()), "not_a"),
+            ]);
+        }
+        ColumnType::VarChar => {}
+        _ => {
+            results.extend([
+                aliased_plan(add(a(), b()), "a_plus_b"),
+                aliased_plan(
+                    subtract(a(), random_literal(column_type, rng)),
+                    "a_minus_lit",
+                ),
+                aliased_plan(multiply(a(), b()), "a_times_b"),
+                aliased_plan(lte(a(), b()), "a_lte_b"),
+            ]);
+        }
+    }
+    results.shuffle(rng);
+    results.truncate(rng.gen_range(1..=results.len()));
+    results
+}
+
+/// Returns a random plan over `a` and `b` built from the expressions implemented for `column_type`.
+fn random_plan(
+    column_type: ColumnType,
+    t: TableRef,
+    accessor: &OwnedTableTestAccessor<InnerProductProof>,
+    rng: &mut impl Rng,
+) -> DynProofPlan<RistrettoPoint> {
+    match rng.gen_range(0..4) {
+        0 => projection(random_results(column_type, t, accessor, rng), tab(t)),
+        1 => filter(
+            random_results(column_type, t, accessor, rng),
+            tab(t),
+            random_predicate(column_type, t, accessor, rng),
+        ),
+        2 => {
+            let mut sums = vec![sum_expr(column(t, "k", accessor), "sum_k")];
+            if !matches!(column_type, ColumnType::Boolean | ColumnType::VarChar) {
+                sums.push(sum_expr(column(t, "b", accessor), "sum_b"));
+            }
+            group_by(
+                cols_expr(t, &["a"], accessor),
+                sums,
+                "__count__",
+                tab(t),
+                random_predicate(column_type, t, accessor, rng),
+            )
+        }
+        _ => distinct(
+            cols_expr(t, &["a", "b"], accessor),
+            tab(t),
+            random_predicate(column_type, t, accessor, rng),
+        ),
+    }
+}
@@ -0,0 +1,35 @@
+use super::fuzz_test_utility::fuzz_roundtrip;
+use crate::base::{database::ColumnType, math::decimal::Precision};
+use rand::{rngs::StdRng, SeedableRng};
+
+fn fuzz_roundtrips(column_type: ColumnType) {
+    let mut rng = StdRng::from_seed([0u8; 32]);
+    for _ in 0..10 {
+        fuzz_roundtrip(column_type, &mut rng);
+    }
+}
+
+#[test]
+fn we_can_fuzz_bigint_columns() {
+    fuzz_roundtrips(ColumnType::BigInt);
+}
+
+#[test]
+fn we_can_fuzz_int128_columns() {
+    fuzz_roundtrips(ColumnType::Int128);
+}
+
+#[test]
+fn we_can_fuzz_decimal75_columns() {
+    fuzz_roundtrips(ColumnType::Decimal75(Precision::new(10).unwrap(), 2));
+}
+
+#[test]
+fn we_can_fuzz_varchar_columns() {
+    fuzz_roundtrips(ColumnType::VarChar);
+}
+
+#[test]
+fn we_can_fuzz_boolean_columns() {
+    fuzz_roundtrips(ColumnType::Boolean);
+}
@@ -1,4 +1,4 @@
-use super::{fold_columns, fold_vals};
+use super::{dyn_proof_plan::check_result_aliases, fold_columns, fold_vals};
 use crate::{
     base::{
         commitment::Commitment,
@@ -15,6 +15,7 @@ use crate::{
         slice_ops,
     },
     sql::{
+        parse::ConversionResult,
         proof::{
             CountBuilder, FinalRoundBuilder, FirstRoundBuilder, ProofPlan, ProverEvaluate,
             SumcheckSubpolynomialType, VerificationBuilder,
@@ -51,6 +52,8 @@ pub struct GroupByExec<C: Commitment> {
 
 impl<C: Commitment> GroupByExec<C> {
     /// Creates a new `group_by` expression.
+    ///
+    /// Unlike [`Self::try_new`], this does not check the column references or the result aliases.
     pub fn new(
         group_by_exprs: Vec<ColumnExpr<C>>,
         sum_expr: Vec<AliasedDynProofExpr<C>>,
@@ -66,6 +69,31 @@ impl<C: Commitment> GroupByExec<C> {
             where_clause,
         }
     }
+
+    /// Creates a new `group_by` expression, checking its column references and result aliases.
+    ///
+    /// # Errors
+    /// Returns [`ConversionError::ColumnFromOtherTable`](crate::sql::parse::ConversionError::ColumnFromOtherTable)
+    /// if an expression references a column of another table, and
+    /// [`ConversionError::DuplicateResultAlias`](crate::sql::parse::ConversionError::DuplicateResultAlias)
+    /// if two result columns have the same name.
+    pub fn try_new(
+        group_by_exprs: Vec<ColumnExpr<C>>,
+        sum_expr: Vec<AliasedDynProofExpr<C>>,
+        count_alias: Identifier,
+        table: TableExpr,
+        where_clause: DynProofExpr<C>,
+    ) -> ConversionResult<Self> {
+        let plan = Self::new(group_by_exprs, sum_expr, count_alias, table, where_clause);
+        plan.table
+            .check_column_references(&plan.get_column_references())?;
+        check_result_aliases(
+            plan.get_column_result_fields()
+                .iter()
+                .map(ColumnField::name),
+        )?;
+        Ok(plan)
+    }
 }
 
 impl<C: Commitment> ProofPlan<C> for GroupByExec<C> {
@@ -155,8 +183,8 @@ impl<C: Commitment> ProofPlan<C> for GroupByExec<C> {
                     .ok_or(ProofError::VerificationError {
                         error: "Result does not all correct group by columns.",
                     })?;
-                if (0..table.num_rows() - 1)
-                    .any(|i| compare_indexes_by_owned_columns(&cols, i, i + 1).is_ge())
+                if (1..table.num_rows())
+                    .any(|i| compare_indexes_by_owned_columns(&cols, i - 1, i).is_ge())
                 {
                     Err(ProofError::VerificationError {
                         error: "Result of group by not ordered as expected.",
@@ -204,7 +232,7 @@ impl<C: Commitment> ProofPlan<C> for GroupByExec<C> {
     }
 
     fn get_table_references(&self) -> IndexSet<TableRef> {
-        IndexSet::from_iter([self.table.table_ref])
+        self.table.table_references(&self.get_column_references())
     }
 }
 
@@ -324,7 +352,7 @@ impl<C: Commitment> ProverEvaluate<C::Scalar> for GroupByExec<C> {
 }
 
 #[allow(clippy::unnecessary_wraps)]
-fn verify_group_by<C: Commitment>(
+pub(super) fn verify_group_by<C: Commitment>(
     builder: &mut VerificationBuilder<C>,
     alpha: C::Scalar,
     beta: C::Scalar,
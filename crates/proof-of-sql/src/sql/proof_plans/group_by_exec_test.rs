@@ -1,4 +1,4 @@
-use super::test_utility::*;
+use super::{test_utility::*, DynProofPlan, GroupByExec};
 use crate::{
     base::{
         commitment::InnerProductProof,
@@ -6,10 +6,14 @@ use crate::{
         scalar::Curve25519Scalar,
     },
     sql::{
+        parse::ConversionError,
+        postprocessing::{apply_postprocessing_steps, test_utility::avg},
         proof::{exercise_verification, VerifiableQueryResult},
         proof_exprs::test_utility::*,
     },
 };
+use curve25519_dalek::RistrettoPoint;
+use proof_of_sql_parser::Identifier;
 
 /// `select a, sum(c) as sum_c, count(*) as __count__ from sxt.t where b = 99 group by a`
 #[test]
@@ -40,6 +44,35 @@ fn we_can_prove_a_simple_group_by_with_bigint_columns() {
     assert_eq!(res, expected);
 }
 
+/// `select a, sum(c) as sum_c, count(*) as __count__ from sxt.t where b = 0 group by a`
+#[test]
+fn we_can_prove_a_group_by_with_an_empty_result() {
+    let data = owned_table([
+        bigint("a", [1, 2, 2, 1, 2]),
+        bigint("b", [99, 99, 99, 99, 98]),
+        bigint("c", [101, 102, 103, 104, 105]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = group_by(
+        cols_expr(t, &["a"], &accessor),
+        vec![sum_expr(column(t, "c", &accessor), "sum_c")],
+        "__count__",
+        tab(t),
+        equal(column(t, "b", &accessor), const_int128(0)),
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    let expected = owned_table([
+        bigint("a", [0; 0]),
+        bigint("sum_c", [0; 0]),
+        bigint("__count__", [0; 0]),
+    ]);
+    assert_eq!(res, expected);
+}
+
 /// `select a, sum(c * 2 + 1) as sum_c, count(*) as __count__ from sxt.t where b = 99 group by a`
 #[test]
 fn we_can_prove_a_group_by_with_bigint_columns() {
@@ -230,3 +263,131 @@ fn we_can_prove_a_complex_group_by_query_with_many_columns() {
     ]);
     assert_eq!(res, expected);
 }
+
+/// `select a, sum(c) as a, count(*) as __count__ from sxt.t group by a`
+#[test]
+fn we_cannot_build_a_group_by_with_duplicate_aliases() {
+    let data = owned_table([bigint("a", [1, 2]), bigint("c", [3, 4])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    assert!(matches!(
+        GroupByExec::<RistrettoPoint>::try_new(
+            cols_expr(t, &["a"], &accessor),
+            vec![sum_expr(column(t, "c", &accessor), "a")],
+            "__count__".parse().unwrap(),
+            tab(t),
+            const_bool(true),
+        ),
+        Err(ConversionError::DuplicateResultAlias { alias }) if alias == "a"
+    ));
+}
+
+#[test]
+fn we_can_render_a_group_by_as_sql() {
+    let data = owned_table([
+        bigint("a", [1, 2]),
+        bigint("b", [99, 0]),
+        bigint("c", [3, 4]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let expr: DynProofPlan<RistrettoPoint> = group_by(
+        cols_expr(t, &["a", "b"], &accessor),
+        vec![sum_expr(column(t, "c", &accessor), "sum_c")],
+        "__count__",
+        tab(t),
+        equal(column(t, "b", &accessor), const_int128(99)),
+    );
+    assert_eq!(
+        expr.to_sql_string(),
+        "SELECT a, b, SUM(c) AS sum_c, COUNT(*) AS __count__ FROM sxt.t WHERE b = 99 GROUP BY a, b"
+    );
+    let expr: DynProofPlan<RistrettoPoint> =
+        group_by(vec![], vec![], "__count__", tab(t), const_bool(true));
+    assert_eq!(
+        expr.to_sql_string(),
+        "SELECT COUNT(*) AS __count__ FROM sxt.t"
+    );
+}
+
+/// `select a, sum(c) as sum_c, count(*) as __count__ from sxt.t where b = 99 group by a`,
+/// followed by `sum_c / __count__ as avg_c`
+#[test]
+fn we_can_average_the_sums_of_a_proven_group_by() {
+    let data = owned_table([
+        bigint("a", [1, 2, 2, 1, 2, 3]),
+        bigint("b", [99, 99, 99, 99, 99, 0]),
+        bigint("c", [101, 102, 103, 104, 106, 105]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = group_by(
+        cols_expr(t, &["a"], &accessor),
+        vec![sum_expr(column(t, "c", &accessor), "sum_c")],
+        "__count__",
+        tab(t),
+        equal(column(t, "b", &accessor), const_int128(99)),
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    let res = apply_postprocessing_steps(res, &[avg("sum_c", "__count__", "avg_c")]).unwrap();
+    let expected = owned_table([
+        bigint("a", [1, 2]),
+        bigint("sum_c", [205, 311]),
+        bigint("__count__", [2, 3]),
+        decimal75("avg_c", 25, 6, [102_500_000, 103_666_666]),
+    ]);
+    assert_eq!(res, expected);
+}
+
+/// `select sum(c) as sum_c, count(*) as __count__ from sxt.t`, followed by `sum_c / __count__ as avg_c`
+#[test]
+fn we_can_average_a_proven_group_by_with_a_single_group() {
+    let data = owned_table([decimal75("c", 5, 2, [150, 250, 350, 450])]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = group_by(
+        vec![],
+        vec![sum_expr(column(t, "c", &accessor), "sum_c")],
+        "__count__",
+        tab(t),
+        const_bool(true),
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    let res = apply_postprocessing_steps(res, &[avg("sum_c", "__count__", "avg_c")]).unwrap();
+    let avg_c = &res.inner_table()[&"avg_c".parse::<Identifier>().unwrap()];
+    assert_eq!(avg_c.len(), 1);
+    assert_eq!(avg_c.to_literal_values()[0].to_string(), "3.000000");
+}
+
+/// An average over a group by whose rows are all filtered out has no rows.
+#[test]
+fn we_can_average_a_proven_group_by_with_an_empty_result() {
+    let data = owned_table([bigint("a", [1, 2, 2]), bigint("c", [101, 102, 103])]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = group_by(
+        cols_expr(t, &["a"], &accessor),
+        vec![sum_expr(column(t, "c", &accessor), "sum_c")],
+        "__count__",
+        tab(t),
+        const_bool(false),
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    let res = apply_postprocessing_steps(res, &[avg("sum_c", "__count__", "avg_c")]).unwrap();
+    let expected = owned_table([
+        bigint("a", [0; 0]),
+        bigint("sum_c", [0; 0]),
+        bigint("__count__", [0; 0]),
+        decimal75("avg_c", 25, 6, [0; 0]),
+    ]);
+    assert_eq!(res, expected);
+}
@@ -0,0 +1,198 @@
+use crate::{
+    base::{
+        commitment::Commitment,
+        database::{
+            Column, ColumnRef, CommitmentAccessor, DataAccessor, MetadataAccessor, OwnedColumn,
+            OwnedTable, TableRef,
+        },
+        polynomial::compute_evaluation_vector,
+        proof::ProofError,
+        scalar::{Scalar, ScalarExt},
+    },
+    sql::proof::VerificationBuilder,
+};
+use alloc::{vec, vec::Vec};
+use bumpalo::Bump;
+use proof_of_sql_parser::Identifier;
+
+/// Copy an [`OwnedColumn`] into `alloc`, so that it lives as long as the allocator
+fn alloc_column<'a, S: Scalar>(column: &OwnedColumn<S>, alloc: &'a Bump) -> Column<'a, S> {
+    match column {
+        OwnedColumn::Boolean(col) => Column::Boolean(alloc.alloc_slice_copy(col)),
+        OwnedColumn::TinyInt(col) => Column::TinyInt(alloc.alloc_slice_copy(col)),
+        OwnedColumn::SmallInt(col) => Column::SmallInt(alloc.alloc_slice_copy(col)),
+        OwnedColumn::Int(col) => Column::Int(alloc.alloc_slice_copy(col)),
+        OwnedColumn::BigInt(col) => Column::BigInt(alloc.alloc_slice_copy(col)),
+        OwnedColumn::Int128(col) => Column::Int128(alloc.alloc_slice_copy(col)),
+        OwnedColumn::Decimal75(precision, scale, col) => {
+            Column::Decimal75(*precision, *scale, alloc.alloc_slice_copy(col))
+        }
+        OwnedColumn::Scalar(col) => Column::Scalar(alloc.alloc_slice_copy(col)),
+        OwnedColumn::VarChar(col) => Column::VarChar((
+            alloc.alloc_slice_fill_iter(col.iter().map(|s| &*alloc.alloc_str(s))),
+            alloc.alloc_slice_fill_iter(col.iter().map(S::from)),
+        )),
+        OwnedColumn::VarBinary(col) => Column::VarBinary((
+            alloc.alloc_slice_fill_iter(col.iter().map(|b| &*alloc.alloc_slice_copy(b))),
+            alloc.alloc_slice_fill_iter(col.iter().map(|b| S::from_byte_slice_via_hash(b))),
+        )),
+        OwnedColumn::TimestampTZ(tu, tz, col) => {
+            Column::TimestampTZ(*tu, *tz, alloc.alloc_slice_copy(col))
+        }
+    }
+}
+
+/// Evaluate the MLE of a column of public data at `point`
+fn evaluate_inline_column<S: Scalar>(column: &OwnedColumn<S>, point: &[S]) -> S {
+    let alloc = Bump::new();
+    let scalars = Column::from_owned_column(column, &alloc).to_scalar_with_scaling(0);
+    let mut evaluation_vector = vec![S::zero(); scalars.len()];
+    compute_evaluation_vector(&mut evaluation_vector, point);
+    scalars
+        .iter()
+        .zip(&evaluation_vector)
+        .map(|(value, weight)| *value * *weight)
+        .sum()
+}
+
+/// Consume the commitments the prover sent for the columns of an inline table, in the order of the table
+///
+/// The evaluation of each commitment is checked against the public data,
+/// which binds the commitments to the data wherever they are used as anchored commitments.
+pub(super) fn consume_inline_commitments<C: Commitment>(
+    builder: &mut VerificationBuilder<C>,
+    values: &OwnedTable<C::Scalar>,
+) -> Result<Vec<(Identifier, C)>, ProofError> {
+    values
+        .inner_table()
+        .iter()
+        .map(|(id, column)| {
+            let (commitment, evaluation) = builder.consume_intermediate_mle_with_commitment();
+            if evaluation
+                == evaluate_inline_column(column, builder.mle_evaluations.evaluation_point)
+            {
+                Ok((*id, commitment))
+            } else {
+                Err(ProofError::VerificationError {
+                    error: "inline table evaluation check failed",
+                })
+            }
+        })
+        .collect()
+}
+
+/// A [`DataAccessor`] that serves the columns of an inline table and defers to another accessor otherwise
+pub(super) struct InlineDataAccessor<'a, S: Scalar> {
+    table_ref: TableRef,
+    length: usize,
+    columns: &'a [(Identifier, Column<'a, S>)],
+    accessor: &'a dyn DataAccessor<S>,
+}
+
+impl<'a, S: Scalar> InlineDataAccessor<'a, S> {
+    /// Copy the inline table into `alloc` and wrap `accessor` with it
+    pub(super) fn new_in(
+        table_ref: TableRef,
+        values: &OwnedTable<S>,
+        accessor: &'a dyn DataAccessor<S>,
+        alloc: &'a Bump,
+    ) -> &'a Self {
+        let columns = alloc.alloc_slice_fill_iter(
+            values
+                .inner_table()
+                .iter()
+                .map(|(id, column)| (*id, alloc_column(column, alloc))),
+        );
+        alloc.alloc(Self {
+            table_ref,
+            length: values.num_rows(),
+            columns,
+            accessor,
+        })
+    }
+
+    /// The columns of the inline table, in the order of the table
+    pub(super) fn columns(&self) -> impl Iterator<Item = Column<'a, S>> + '_ {
+        self.columns.iter().map(|(_, column)| *column)
+    }
+}
+
+impl<S: Scalar> MetadataAccessor for InlineDataAccessor<'_, S> {
+    fn get_length(&self, table_ref: TableRef) -> usize {
+        if table_ref == self.table_ref {
+            self.length
+        } else {
+            self.accessor.get_length(table_ref)
+        }
+    }
+
+    fn get_offset(&self, table_ref: TableRef) -> usize {
+        if table_ref == self.table_ref {
+            0
+        } else {
+            self.accessor.get_offset(table_ref)
+        }
+    }
+}
+
+impl<S: Scalar> DataAccessor<S> for InlineDataAccessor<'_, S> {
+    fn get_column(&self, column: ColumnRef) -> Column<S> {
+        if column.table_ref() != self.table_ref {
+            return self.accessor.get_column(column);
+        }
+        self.columns
+            .iter()
+            .find(|(id, _)| *id == column.column_id())
+            .map(|(_, values)| *values)
+            .expect("the column is in the inline table")
+    }
+
+    fn prefetch(&self, column_refs: &[ColumnRef]) {
+        let column_refs: Vec<_> = column_refs
+            .iter()
+            .filter(|column| column.table_ref() != self.table_ref)
+            .copied()
+            .collect();
+        self.accessor.prefetch(&column_refs);
+    }
+}
+
+/// A [`CommitmentAccessor`] that serves the commitments the prover sent for the columns of an inline table
+/// and defers to another accessor otherwise
+pub(super) struct InlineCommitmentAccessor<'a, C: Commitment> {
+    pub(super) table_ref: TableRef,
+    pub(super) length: usize,
+    pub(super) commitments: &'a [(Identifier, C)],
+    pub(super) accessor: &'a dyn CommitmentAccessor<C>,
+}
+
+impl<C: Commitment> MetadataAccessor for InlineCommitmentAccessor<'_, C> {
+    fn get_length(&self, table_ref: TableRef) -> usize {
+        if table_ref == self.table_ref {
+            self.length
+        } else {
+            self.accessor.get_length(table_ref)
+        }
+    }
+
+    fn get_offset(&self, table_ref: TableRef) -> usize {
+        if table_ref == self.table_ref {
+            0
+        } else {
+            self.accessor.get_offset(table_ref)
+        }
+    }
+}
+
+impl<C: Commitment> CommitmentAccessor<C> for InlineCommitmentAccessor<'_, C> {
+    fn get_commitment(&self, column: ColumnRef) -> C {
+        if column.table_ref() != self.table_ref {
+            return self.accessor.get_commitment(column);
+        }
+        self.commitments
+            .iter()
+            .find(|(id, _)| *id == column.column_id())
+            .map(|(_, commitment)| commitment.clone())
+            .expect("the column is in the inline table")
+    }
+}
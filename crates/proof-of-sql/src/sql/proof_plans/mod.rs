@@ -1,4 +1,6 @@
 //! This module proves provable execution plans.
+mod inline_table;
+
 mod projection_exec;
 pub(crate) use projection_exec::ProjectionExec;
 #[cfg(all(test, feature = "blitzar"))]
@@ -7,6 +9,11 @@ mod projection_exec_test;
 #[cfg(test)]
 pub(crate) mod test_utility;
 
+#[cfg(all(test, feature = "blitzar"))]
+pub(crate) mod fuzz_test_utility;
+#[cfg(all(test, feature = "blitzar"))]
+mod fuzz_test_utility_test;
+
 mod filter_exec;
 pub(crate) use filter_exec::FilterExec;
 #[cfg(test)]
@@ -27,5 +34,17 @@ pub(crate) use group_by_exec::GroupByExec;
 #[cfg(all(test, feature = "blitzar"))]
 mod group_by_exec_test;
 
+mod distinct_exec;
+pub(crate) use distinct_exec::DistinctExec;
+
+#[cfg(all(test, feature = "blitzar"))]
+mod distinct_exec_test;
+
+mod divide_and_modulo_exec;
+pub(crate) use divide_and_modulo_exec::DivideAndModuloExec;
+
+#[cfg(all(test, feature = "blitzar"))]
+mod divide_and_modulo_exec_test;
+
 mod dyn_proof_plan;
 pub use dyn_proof_plan::DynProofPlan;
@@ -1,3 +1,7 @@
+use super::{
+    dyn_proof_plan::check_result_aliases,
+    inline_table::{consume_inline_commitments, InlineCommitmentAccessor, InlineDataAccessor},
+};
 use crate::{
     base::{
         commitment::Commitment,
@@ -9,6 +13,7 @@ use crate::{
         proof::ProofError,
     },
     sql::{
+        parse::{ConversionError, ConversionResult},
         proof::{
             CountBuilder, FinalRoundBuilder, FirstRoundBuilder, ProofPlan, ProverEvaluate,
             VerificationBuilder,
@@ -16,7 +21,7 @@ use crate::{
         proof_exprs::{AliasedDynProofExpr, ProofExpr, TableExpr},
     },
 };
-use alloc::vec::Vec;
+use alloc::{boxed::Box, format, vec::Vec};
 use bumpalo::Bump;
 use core::iter::repeat_with;
 use serde::{Deserialize, Serialize};
@@ -25,19 +30,105 @@ use serde::{Deserialize, Serialize};
 /// ```ignore
 ///     SELECT <result_expr1>, ..., <result_exprN> FROM <table>
 /// ```
+///
+/// The result columns, both in [`ProofPlan::get_column_result_fields`] and in
+/// [`ProverEvaluate::result_evaluate`], are always in the order of `aliased_results`,
+/// regardless of the order of the columns in the table.
+///
+/// The table can be given inline, as in `SELECT ... FROM (VALUES ...) AS <table>`, see [`Self::try_new_with_values`].
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct ProjectionExec<C: Commitment> {
     pub(super) aliased_results: Vec<AliasedDynProofExpr<C>>,
     pub(super) table: TableExpr,
+    /// The data of the table if it is given inline rather than read from the accessor
+    pub(super) values: Option<OwnedTable<C::Scalar>>,
 }
 
 impl<C: Commitment> ProjectionExec<C> {
     /// Creates a new projection expression.
+    ///
+    /// Unlike [`Self::try_new`], this does not check the column references or the result aliases.
     pub fn new(aliased_results: Vec<AliasedDynProofExpr<C>>, table: TableExpr) -> Self {
         Self {
             aliased_results,
             table,
+            values: None,
+        }
+    }
+
+    /// Creates a new projection expression, checking its column references and result aliases.
+    ///
+    /// # Errors
+    /// Returns [`ConversionError::ColumnFromOtherTable`] if an expression references a column of another table,
+    /// and [`ConversionError::DuplicateResultAlias`] if two result columns have the same name.
+    pub fn try_new(
+        aliased_results: Vec<AliasedDynProofExpr<C>>,
+        table: TableExpr,
+    ) -> ConversionResult<Self> {
+        let plan = Self::new(aliased_results, table);
+        plan.table
+            .check_column_references(&plan.get_column_references())?;
+        check_result_aliases(
+            plan.get_column_result_fields()
+                .iter()
+                .map(ColumnField::name),
+        )?;
+        Ok(plan)
+    }
+
+    /// Creates a new projection expression over an inline table with the given `values`.
+    ///
+    /// The inline table is part of the plan, so it does not need to be in any accessor.
+    /// The prover commits to its columns while proving and the verifier checks these
+    /// commitments against the data in the plan.
+    ///
+    /// # Errors
+    /// Returns [`ConversionError::ColumnFromOtherTable`] if a result expression references a column
+    /// of another table, [`ConversionError::MissingColumn`] if it references a column of `table` that
+    /// is not in `values`, and [`ConversionError::InvalidExpression`] if the type of such a column
+    /// differs from the type of the column in `values`, and [`ConversionError::DuplicateResultAlias`]
+    /// if two result columns have the same name.
+    pub fn try_new_with_values(
+        aliased_results: Vec<AliasedDynProofExpr<C>>,
+        table: TableExpr,
+        values: OwnedTable<C::Scalar>,
+    ) -> ConversionResult<Self> {
+        let mut columns = IndexSet::default();
+        for aliased_expr in &aliased_results {
+            aliased_expr.expr.get_column_references(&mut columns);
         }
+        table.check_column_references(&columns)?;
+        for column in &columns {
+            let id = column.column_id();
+            let values_column =
+                values
+                    .inner_table()
+                    .get(&id)
+                    .ok_or_else(|| ConversionError::MissingColumn {
+                        identifier: Box::new(id),
+                        resource_id: Box::new(table.table_ref.resource_id()),
+                    })?;
+            if values_column.column_type() != *column.column_type() {
+                return Err(ConversionError::InvalidExpression {
+                    expression: format!(
+                        "column {id} has type {} but its inline values have type {}",
+                        column.column_type(),
+                        values_column.column_type()
+                    ),
+                });
+            }
+        }
+        let plan = Self {
+            aliased_results,
+            table,
+            values: Some(values),
+        };
+        check_result_aliases(
+            plan.get_column_result_fields()
+                .iter()
+                .map(ColumnField::name),
+        )?;
+        Ok(plan)
     }
 }
 
@@ -47,6 +138,9 @@ impl<C: Commitment> ProofPlan<C> for ProjectionExec<C> {
         builder: &mut CountBuilder,
         _accessor: &dyn MetadataAccessor,
     ) -> Result<(), ProofError> {
+        if let Some(values) = &self.values {
+            builder.count_intermediate_mles(values.num_columns());
+        }
         for aliased_expr in &self.aliased_results {
             aliased_expr.expr.count(builder)?;
             builder.count_intermediate_mles(1);
@@ -55,11 +149,17 @@ impl<C: Commitment> ProofPlan<C> for ProjectionExec<C> {
     }
 
     fn get_length(&self, accessor: &dyn MetadataAccessor) -> usize {
-        accessor.get_length(self.table.table_ref)
+        match &self.values {
+            Some(values) => values.num_rows(),
+            None => accessor.get_length(self.table.table_ref),
+        }
     }
 
     fn get_offset(&self, accessor: &dyn MetadataAccessor) -> usize {
-        accessor.get_offset(self.table.table_ref)
+        match &self.values {
+            Some(_) => 0,
+            None => accessor.get_offset(self.table.table_ref),
+        }
     }
 
     #[allow(unused_variables)]
@@ -69,6 +169,25 @@ impl<C: Commitment> ProofPlan<C> for ProjectionExec<C> {
         accessor: &dyn CommitmentAccessor<C>,
         _result: Option<&OwnedTable<C::Scalar>>,
     ) -> Result<Vec<C::Scalar>, ProofError> {
+        let inline_commitments = self
+            .values
+            .as_ref()
+            .map(|values| consume_inline_commitments(builder, values))
+            .transpose()?;
+        let inline_accessor =
+            self.values
+                .as_ref()
+                .zip(inline_commitments.as_deref())
+                .map(|(values, commitments)| InlineCommitmentAccessor {
+                    table_ref: self.table.table_ref,
+                    length: values.num_rows(),
+                    commitments,
+                    accessor,
+                });
+        let accessor: &dyn CommitmentAccessor<C> = match &inline_accessor {
+            Some(inline_accessor) => inline_accessor,
+            None => accessor,
+        };
         self.aliased_results
             .iter()
             .map(|aliased_expr| aliased_expr.expr.verifier_evaluate(builder, accessor))
@@ -85,16 +204,25 @@ impl<C: Commitment> ProofPlan<C> for ProjectionExec<C> {
             .collect()
     }
 
+    /// The columns of an inline table are not in any accessor, so they are not included
     fn get_column_references(&self) -> IndexSet<ColumnRef> {
         let mut columns = IndexSet::default();
         self.aliased_results.iter().for_each(|aliased_expr| {
             aliased_expr.expr.get_column_references(&mut columns);
         });
+        if self.values.is_some() {
+            columns.retain(|column| column.table_ref() != self.table.table_ref);
+        }
         columns
     }
 
+    /// An inline table is not in any accessor, so it is not included
     fn get_table_references(&self) -> IndexSet<TableRef> {
-        IndexSet::from_iter([self.table.table_ref])
+        if self.values.is_some() {
+            IndexSet::default()
+        } else {
+            self.table.table_references(&self.get_column_references())
+        }
     }
 }
 
@@ -106,6 +234,12 @@ impl<C: Commitment> ProverEvaluate<C::Scalar> for ProjectionExec<C> {
         alloc: &'a Bump,
         accessor: &'a dyn DataAccessor<C::Scalar>,
     ) -> Vec<Column<'a, C::Scalar>> {
+        let accessor = match &self.values {
+            Some(values) => {
+                InlineDataAccessor::new_in(self.table.table_ref, values, accessor, alloc)
+            }
+            None => accessor,
+        };
         let columns: Vec<_> = self
             .aliased_results
             .iter()
@@ -132,6 +266,18 @@ impl<C: Commitment> ProverEvaluate<C::Scalar> for ProjectionExec<C> {
         alloc: &'a Bump,
         accessor: &'a dyn DataAccessor<C::Scalar>,
     ) -> Vec<Column<'a, C::Scalar>> {
+        // 0. Commit to the columns of an inline table
+        let accessor = match &self.values {
+            Some(values) => {
+                let inline_accessor =
+                    InlineDataAccessor::new_in(self.table.table_ref, values, accessor, alloc);
+                for column in inline_accessor.columns() {
+                    builder.produce_intermediate_mle(column.as_scalar(alloc));
+                }
+                inline_accessor
+            }
+            None => accessor,
+        };
         // 1. Evaluate result expressions
         let res: Vec<_> = self
             .aliased_results
@@ -7,14 +7,18 @@ use crate::{
         },
         map::{IndexMap, IndexSet},
         math::decimal::Precision,
+        proof::ProofError,
         scalar::Curve25519Scalar,
     },
     sql::{
+        parse::ConversionError,
         proof::{
             exercise_verification, FirstRoundBuilder, ProofPlan, ProvableQueryResult,
-            ProverEvaluate, VerifiableQueryResult,
+            ProverEvaluate, QueryError, VerifiableQueryResult,
+        },
+        proof_exprs::{
+            test_utility::*, ColumnExpr, DynProofExpr, TableExpr, DEFAULT_MAX_EXPR_DEPTH,
         },
-        proof_exprs::{test_utility::*, ColumnExpr, DynProofExpr, TableExpr},
     },
 };
 use blitzar::proof::InnerProductProof;
@@ -27,10 +31,10 @@ fn we_can_correctly_fetch_the_query_result_schema() {
     let table_ref = TableRef::new(ResourceId::try_new("sxt", "sxt_tab").unwrap());
     let a = Identifier::try_new("a").unwrap();
     let b = Identifier::try_new("b").unwrap();
-    let provable_ast = ProjectionExec::<RistrettoPoint>::new(
+    let provable_ast = ProjectionExec::<RistrettoPoint>::try_new(
         vec![
             aliased_plan(
-                DynProofExpr::Column(ColumnExpr::new(ColumnRef::new(
+                DynProofExpr::Column(ColumnExpr::new(&ColumnRef::new(
                     table_ref,
                     a,
                     ColumnType::BigInt,
@@ -38,7 +42,7 @@ fn we_can_correctly_fetch_the_query_result_schema() {
                 "a",
             ),
             aliased_plan(
-                DynProofExpr::Column(ColumnExpr::new(ColumnRef::new(
+                DynProofExpr::Column(ColumnExpr::new(&ColumnRef::new(
                     table_ref,
                     b,
                     ColumnType::BigInt,
@@ -46,8 +50,12 @@ fn we_can_correctly_fetch_the_query_result_schema() {
                 "b",
             ),
         ],
-        TableExpr { table_ref },
-    );
+        TableExpr {
+            table_ref,
+            alias: None,
+        },
+    )
+    .unwrap();
     let column_fields: Vec<ColumnField> = provable_ast.get_column_result_fields();
     assert_eq!(
         column_fields,
@@ -63,10 +71,10 @@ fn we_can_correctly_fetch_all_the_referenced_columns() {
     let table_ref = TableRef::new(ResourceId::try_new("sxt", "sxt_tab").unwrap());
     let a = Identifier::try_new("a").unwrap();
     let f = Identifier::try_new("f").unwrap();
-    let provable_ast = ProjectionExec::<RistrettoPoint>::new(
+    let provable_ast = ProjectionExec::<RistrettoPoint>::try_new(
         vec![
             aliased_plan(
-                DynProofExpr::Column(ColumnExpr::new(ColumnRef::new(
+                DynProofExpr::Column(ColumnExpr::new(&ColumnRef::new(
                     table_ref,
                     a,
                     ColumnType::BigInt,
@@ -74,7 +82,7 @@ fn we_can_correctly_fetch_all_the_referenced_columns() {
                 "a",
             ),
             aliased_plan(
-                DynProofExpr::Column(ColumnExpr::new(ColumnRef::new(
+                DynProofExpr::Column(ColumnExpr::new(&ColumnRef::new(
                     table_ref,
                     f,
                     ColumnType::BigInt,
@@ -82,8 +90,12 @@ fn we_can_correctly_fetch_all_the_referenced_columns() {
                 "f",
             ),
         ],
-        TableExpr { table_ref },
-    );
+        TableExpr {
+            table_ref,
+            alias: None,
+        },
+    )
+    .unwrap();
 
     let ref_columns = provable_ast.get_column_references();
 
@@ -108,6 +120,118 @@ fn we_can_correctly_fetch_all_the_referenced_columns() {
     assert_eq!(ref_tables, IndexSet::from_iter([table_ref]));
 }
 
+#[test]
+fn we_get_distinct_column_references_for_different_aliases_of_the_same_table() {
+    let data = owned_table([bigint("a", [1, 2, 3])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let a: Identifier = "a".parse().unwrap();
+    let l = aliased_tab(t, "l");
+    let r = aliased_tab(t, "r");
+    let l_a = l.column_ref(a, ColumnType::BigInt);
+    let r_a = r.column_ref(a, ColumnType::BigInt);
+    assert_ne!(l_a, r_a);
+    assert_eq!(l_a.table_ref(), r_a.table_ref());
+    assert_eq!(l_a.table_alias(), Some("l".parse().unwrap()));
+    assert_eq!(
+        tab(t).column_ref(a, ColumnType::BigInt),
+        ColumnRef::new(t, a, ColumnType::BigInt)
+    );
+
+    let ast: DynProofPlan<RistrettoPoint> = projection(
+        vec![
+            aliased_plan(tab_column(&l, "a", &accessor), "l_a"),
+            aliased_plan(tab_column(&r, "a", &accessor), "r_a"),
+        ],
+        tab(t),
+    );
+    assert_eq!(ast.get_column_references(), IndexSet::from_iter([l_a, r_a]));
+    assert_eq!(ast.get_table_references(), IndexSet::from_iter([t]));
+}
+
+#[test]
+fn we_cannot_build_a_projection_over_a_column_of_another_table() {
+    let t: TableRef = "sxt.t".parse().unwrap();
+    let u: TableRef = "sxt.u".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        t,
+        owned_table([bigint("a", [1, 2, 3])]),
+        0,
+        (),
+    );
+    accessor.add_table(u, owned_table([bigint("b", [4, 5, 6])]), 0);
+    let results = vec![
+        aliased_plan(
+            add(column(t, "a", &accessor), column(u, "b", &accessor)),
+            "c",
+        ),
+        col_expr_plan(t, "a", &accessor),
+    ];
+    assert!(matches!(
+        ProjectionExec::<RistrettoPoint>::try_new(results.clone(), tab(t)),
+        Err(ConversionError::ColumnFromOtherTable { identifier, .. }) if *identifier == "b".parse::<Identifier>().unwrap()
+    ));
+
+    // A deserialized plan is checked in the same way
+    let plan = DynProofPlan::Projection(ProjectionExec::<RistrettoPoint>::new(results, tab(t)));
+    let bytes = postcard::to_allocvec(&plan).unwrap();
+    assert!(postcard::from_bytes::<DynProofPlan<RistrettoPoint>>(&bytes).is_err());
+}
+
+#[test]
+fn we_get_the_table_references_of_all_the_columns_in_a_projection() {
+    let t: TableRef = "sxt.t".parse().unwrap();
+    let u: TableRef = "sxt.u".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        t,
+        owned_table([bigint("a", [1, 2, 3])]),
+        0,
+        (),
+    );
+    accessor.add_table(u, owned_table([bigint("b", [4, 5, 6])]), 0);
+    let ast = ProjectionExec::<RistrettoPoint>::new(
+        vec![
+            aliased_plan(
+                add(column(t, "a", &accessor), column(u, "b", &accessor)),
+                "c",
+            ),
+            col_expr_plan(u, "b", &accessor),
+            col_expr_plan(t, "a", &accessor),
+        ],
+        tab(t),
+    );
+    assert_eq!(ast.get_table_references(), IndexSet::from_iter([t, u]));
+}
+
+#[test]
+fn we_can_prove_a_projection_of_the_same_column_through_different_table_aliases() {
+    let data = owned_table([bigint("a", [1, 2, 3]), varchar("b", ["x", "y", "z"])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let l = aliased_tab(t, "l");
+    let r = aliased_tab(t, "r");
+    let ast: DynProofPlan<RistrettoPoint> = projection(
+        vec![
+            aliased_plan(tab_column(&l, "a", &accessor), "l_a"),
+            aliased_plan(
+                add(tab_column(&r, "a", &accessor), const_bigint(1)),
+                "r_a_plus_one",
+            ),
+            aliased_plan(tab_column(&r, "b", &accessor), "r_b"),
+        ],
+        tab(t),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected = owned_table([
+        bigint("l_a", [1, 2, 3]),
+        bigint("r_a_plus_one", [2, 3, 4]),
+        varchar("r_b", ["x", "y", "z"]),
+    ]);
+    assert_eq!(res, expected);
+}
+
 #[test]
 fn we_can_prove_and_get_the_correct_result_from_a_basic_projection() {
     let data = owned_table([
@@ -125,6 +249,49 @@ fn we_can_prove_and_get_the_correct_result_from_a_basic_projection() {
     assert_eq!(res, expected);
 }
 
+#[test]
+fn we_cannot_verify_a_projection_if_the_accessor_reports_a_different_column_type() {
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, owned_table([bigint("a", [1_i64, 2, 3])]), 0);
+    let ast = projection(cols_expr_plan(t, &["a"], &accessor), tab(t));
+    let verifiable_res = VerifiableQueryResult::<InnerProductProof>::new(&ast, &accessor, &());
+
+    let mut drifted_accessor =
+        OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    drifted_accessor.add_table(t, owned_table([int128("a", [1_i128, 2, 3])]), 0);
+    assert!(matches!(
+        verifiable_res.verify(&ast, &drifted_accessor, &()),
+        Err(QueryError::ProofError {
+            source: ProofError::ColumnTypeMismatch {
+                column,
+                expected: ColumnType::BigInt,
+                actual: ColumnType::Int128,
+            }
+        }) if *column == ColumnRef::new(t, "a".parse().unwrap(), ColumnType::BigInt)
+    ));
+    assert!(verifiable_res.verify(&ast, &accessor, &()).is_ok());
+}
+
+#[test]
+fn we_cannot_verify_a_projection_if_the_accessor_does_not_know_a_column() {
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, owned_table([bigint("a", [1_i64, 2, 3])]), 0);
+    let ast = projection(cols_expr_plan(t, &["a"], &accessor), tab(t));
+    let verifiable_res = VerifiableQueryResult::<InnerProductProof>::new(&ast, &accessor, &());
+
+    let mut drifted_accessor =
+        OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    drifted_accessor.add_table(t, owned_table([bigint("b", [1_i64, 2, 3])]), 0);
+    assert!(matches!(
+        verifiable_res.verify(&ast, &drifted_accessor, &()),
+        Err(QueryError::ProofError {
+            source: ProofError::UnknownColumn { column }
+        }) if *column == ColumnRef::new(t, "a".parse().unwrap(), ColumnType::BigInt)
+    ));
+}
+
 #[test]
 fn we_can_prove_and_get_the_correct_result_from_a_nontrivial_projection() {
     let data = owned_table([
@@ -274,6 +441,71 @@ fn we_can_get_the_correct_result_from_a_basic_projection_using_result_evaluate()
     assert_eq!(res, expected);
 }
 
+#[test]
+fn we_get_the_result_columns_in_the_order_of_the_projection_expressions() {
+    let data = owned_table([
+        bigint("a", [1, 2, 3]),
+        bigint("b", [10, 20, 30]),
+        varchar("c", ["x", "y", "z"]),
+        boolean("d", [true, false, true]),
+        int128("e", [-1, 0, 1]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let ast: DynProofPlan<RistrettoPoint> = projection(
+        vec![
+            col_expr_plan(t, "e", &accessor),
+            col_expr_plan(t, "c", &accessor),
+            aliased_plan(
+                add(column(t, "a", &accessor), column(t, "b", &accessor)),
+                "a_plus_b",
+            ),
+            aliased_plan(const_bigint(7), "seven"),
+            col_expr_plan(t, "d", &accessor),
+            aliased_plan(
+                multiply(column(t, "e", &accessor), const_int128(2)),
+                "double_e",
+            ),
+            col_expr_plan(t, "a", &accessor),
+            aliased_plan(const_varchar("w"), "w"),
+            aliased_plan(
+                subtract(column(t, "b", &accessor), column(t, "a", &accessor)),
+                "b_minus_a",
+            ),
+            aliased_col_expr_plan(t, "b", "renamed_b", &accessor),
+        ],
+        tab(t),
+    );
+    let expected = owned_table([
+        int128("e", [-1, 0, 1]),
+        varchar("c", ["x", "y", "z"]),
+        bigint("a_plus_b", [11, 22, 33]),
+        bigint("seven", [7; 3]),
+        boolean("d", [true, false, true]),
+        int128("double_e", [-2, 0, 2]),
+        bigint("a", [1, 2, 3]),
+        varchar("w", ["w"; 3]),
+        bigint("b_minus_a", [9, 18, 27]),
+        bigint("renamed_b", [10, 20, 30]),
+    ]);
+
+    let fields = ast.get_column_result_fields();
+    assert_eq!(fields, expected.schema());
+
+    let alloc = Bump::new();
+    let result_cols = ast.result_evaluate(3, &alloc, &accessor);
+    let res: OwnedTable<Curve25519Scalar> = ProvableQueryResult::new(3, &result_cols)
+        .to_owned_table(&fields)
+        .unwrap();
+    assert_eq!(res, expected);
+
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    assert_eq!(res, expected);
+}
+
 #[test]
 fn we_can_prove_a_projection_on_an_empty_table() {
     let data = owned_table([
@@ -310,6 +542,106 @@ fn we_can_prove_a_projection_on_an_empty_table() {
     assert_eq!(res, expected);
 }
 
+#[test]
+fn we_can_prove_a_projection_on_an_empty_table_and_keep_the_full_result_schema() {
+    let data = owned_table([varchar("d", ["3"; 0]), decimal75("e", 12, 3, [3; 0])]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = projection(cols_expr_plan(t, &["d", "e"], &accessor), tab(t));
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    assert_eq!(res.num_rows(), 0);
+    let schema: Vec<_> = res
+        .inner_table()
+        .iter()
+        .map(|(name, column)| ColumnField::new(*name, column.column_type()))
+        .collect();
+    assert_eq!(
+        schema,
+        vec![
+            ColumnField::new("d".parse().unwrap(), ColumnType::VarChar),
+            ColumnField::new(
+                "e".parse().unwrap(),
+                ColumnType::Decimal75(Precision::new(12).unwrap(), 3)
+            ),
+        ]
+    );
+    assert_eq!(schema, expr.get_column_result_fields());
+}
+
+#[test]
+fn we_can_get_the_result_of_an_arithmetic_projection_without_passing_fields() {
+    let data = owned_table([bigint("a", [1, 2, 3]), decimal75("e", 12, 3, [100, -5, 0])]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let ast: DynProofPlan<RistrettoPoint> = projection(
+        vec![
+            col_expr_plan(t, "e", &accessor),
+            aliased_plan(
+                add(column(t, "e", &accessor), column(t, "a", &accessor)),
+                "e_plus_a",
+            ),
+            aliased_plan(
+                multiply(column(t, "a", &accessor), column(t, "a", &accessor)),
+                "a_squared",
+            ),
+        ],
+        tab(t),
+    );
+    let alloc = Bump::new();
+    let result_cols = ast.result_evaluate(3, &alloc, &accessor);
+    // Decimal results carry their type, integer results get theirs when the result is decoded
+    assert_eq!(
+        result_cols[..2]
+            .iter()
+            .map(Column::column_type)
+            .collect::<Vec<_>>(),
+        vec![
+            ColumnType::Decimal75(Precision::new(12).unwrap(), 3),
+            ColumnType::Decimal75(Precision::new(23).unwrap(), 3),
+        ]
+    );
+
+    let res: OwnedTable<Curve25519Scalar> = ProvableQueryResult::new(3, &result_cols)
+        .to_owned_table_for_plan(&ast)
+        .unwrap();
+    let schema: Vec<_> = res
+        .inner_table()
+        .iter()
+        .map(|(name, column)| ColumnField::new(*name, column.column_type()))
+        .collect();
+    assert_eq!(schema, ast.get_column_result_fields());
+    let expected = owned_table([
+        decimal75("e", 12, 3, [100, -5, 0]),
+        decimal75("e_plus_a", 23, 3, [1100, 1995, 3000]),
+        bigint("a_squared", [1, 4, 9]),
+    ]);
+    assert_eq!(res, expected);
+}
+
+#[test]
+fn we_cannot_get_the_result_of_a_projection_that_overflows_its_type() {
+    let data = owned_table([smallint("a", [2_i16, i16::MAX])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast: DynProofPlan<RistrettoPoint> = projection(
+        vec![aliased_plan(
+            multiply(column(t, "a", &accessor), column(t, "a", &accessor)),
+            "a_squared",
+        )],
+        tab(t),
+    );
+    let alloc = Bump::new();
+    let result_cols = ast.result_evaluate(2, &alloc, &accessor);
+    assert!(matches!(
+        ProvableQueryResult::new(2, &result_cols).to_owned_table_for_plan(&ast),
+        Err(QueryError::Overflow)
+    ));
+}
+
 #[test]
 fn we_can_prove_a_projection() {
     let data = owned_table([
@@ -349,3 +681,300 @@ fn we_can_prove_a_projection() {
     ]);
     assert_eq!(res, expected);
 }
+
+#[test]
+fn we_can_prove_a_projection_of_only_constants() {
+    let data = owned_table([bigint("a", [101, 104, 105, 102, 105])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let expr: DynProofPlan<RistrettoPoint> = projection(
+        vec![
+            aliased_plan(const_bigint(1), "one"),
+            aliased_plan(const_varchar("x"), "x"),
+        ],
+        tab(t),
+    );
+    assert!(expr.get_column_references().is_empty());
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    let expected = owned_table([bigint("one", [1; 5]), varchar("x", ["x"; 5])]);
+    assert_eq!(res, expected);
+}
+
+#[test]
+fn we_can_prove_a_filter_of_only_constants() {
+    let data = owned_table([bigint("a", [101, 104, 105, 102, 105])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let expr: DynProofPlan<RistrettoPoint> = filter(
+        vec![
+            aliased_plan(const_bigint(1), "one"),
+            aliased_plan(const_varchar("x"), "x"),
+        ],
+        tab(t),
+        equal(column(t, "a", &accessor), const_bigint(105)),
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    let expected = owned_table([bigint("one", [1; 2]), varchar("x", ["x"; 2])]);
+    assert_eq!(res, expected);
+}
+
+#[test]
+fn we_cannot_build_a_projection_with_duplicate_aliases() {
+    let data = owned_table([bigint("a", [1, 2]), bigint("b", [3, 4])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let results = vec![
+        aliased_plan(add(column(t, "a", &accessor), const_bigint(1)), "x"),
+        aliased_plan(
+            multiply(column(t, "a", &accessor), column(t, "b", &accessor)),
+            "x",
+        ),
+    ];
+    assert!(matches!(
+        ProjectionExec::<RistrettoPoint>::try_new(results.clone(), tab(t)),
+        Err(ConversionError::DuplicateResultAlias { alias }) if alias == "x"
+    ));
+
+    // A deserialized plan is checked in the same way
+    let plan = DynProofPlan::Projection(ProjectionExec::<RistrettoPoint>::new(results, tab(t)));
+    let bytes = postcard::to_allocvec(&plan).unwrap();
+    assert!(postcard::from_bytes::<DynProofPlan<RistrettoPoint>>(&bytes).is_err());
+}
+
+#[test]
+fn we_can_build_a_projection_with_distinct_aliases() {
+    let data = owned_table([bigint("a", [1, 2]), bigint("b", [3, 4])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let expr: DynProofPlan<RistrettoPoint> = projection(
+        vec![
+            col_expr_plan(t, "a", &accessor),
+            aliased_plan(column(t, "a", &accessor), "b"),
+            aliased_plan(column(t, "b", &accessor), "a_2"),
+        ],
+        tab(t),
+    );
+    assert_eq!(expr.get_column_result_fields().len(), 3);
+}
+
+#[test]
+fn we_can_render_a_nontrivial_projection_as_sql() {
+    let data = owned_table([bigint("a", [1, 4]), bigint("b", [1, 2])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast: DynProofPlan<RistrettoPoint> = projection(
+        vec![
+            aliased_plan(add(column(t, "b", &accessor), const_bigint(1)), "b"),
+            aliased_plan(
+                multiply(column(t, "a", &accessor), column(t, "b", &accessor)),
+                "prod",
+            ),
+            col_expr_plan(t, "a", &accessor),
+        ],
+        tab(t),
+    );
+    assert_eq!(
+        ast.to_sql_string(),
+        "SELECT b + 1 AS b, a * b AS prod, a FROM sxt.t"
+    );
+}
+
+#[test]
+fn we_can_render_a_projection_with_table_aliases_and_nested_expressions_as_sql() {
+    let data = owned_table([bigint("a", [1, 4]), boolean("c", [true, false])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let r = aliased_tab(t, "r");
+    let ast: DynProofPlan<RistrettoPoint> = projection(
+        vec![
+            aliased_plan(tab_column(&r, "a", &accessor), "a"),
+            aliased_plan(
+                multiply(
+                    subtract(tab_column(&r, "a", &accessor), const_bigint(2)),
+                    pow(tab_column(&r, "a", &accessor), 2),
+                ),
+                "m",
+            ),
+            aliased_plan(
+                or(
+                    not(tab_column(&r, "c", &accessor)),
+                    lte(tab_column(&r, "a", &accessor), const_bigint(-3)),
+                ),
+                "d",
+            ),
+            aliased_plan(const_varchar("it's"), "s"),
+        ],
+        r,
+    );
+    assert_eq!(
+        ast.to_sql_string(),
+        "SELECT r.a AS a, (r.a - 2) * POWER(r.a, 2) AS m, (NOT r.c) OR (r.a <= -3) AS d, \
+         'it''s' AS s FROM sxt.t AS r"
+    );
+}
+
+fn inline_column(
+    table: &TableExpr,
+    name: &str,
+    column_type: ColumnType,
+) -> DynProofExpr<RistrettoPoint> {
+    DynProofExpr::Column(ColumnExpr::new(
+        &table.column_ref(name.parse().unwrap(), column_type),
+    ))
+}
+
+#[test]
+fn we_can_prove_a_projection_over_an_inline_table() {
+    let t: TableRef = "sxt.values".parse().unwrap();
+    let table = tab(t);
+    let values = owned_table([bigint("a", [1_i64, 2]), varchar("b", ["a", "b"])]);
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    let ast = projection_with_values(
+        vec![
+            aliased_plan(
+                add(
+                    inline_column(&table, "a", ColumnType::BigInt),
+                    const_bigint(10),
+                ),
+                "c",
+            ),
+            aliased_plan(inline_column(&table, "b", ColumnType::VarChar), "b"),
+        ],
+        tab(t),
+        values.clone(),
+    );
+    assert!(ast.get_column_references().is_empty());
+    assert!(ast.get_table_references().is_empty());
+
+    let verifiable_res = VerifiableQueryResult::<InnerProductProof>::new(&ast, &accessor, &());
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    assert_eq!(
+        res,
+        owned_table([bigint("c", [11_i64, 12]), varchar("b", ["a", "b"])])
+    );
+
+    // Every part of the proof is checked
+    let proof = verifiable_res.proof.as_ref().unwrap();
+    for i in 0..proof.pcs_proof_evaluations.len() {
+        let mut res_p = verifiable_res.clone();
+        res_p.proof.as_mut().unwrap().pcs_proof_evaluations[i] += Curve25519Scalar::from(1);
+        assert!(res_p.verify(&ast, &accessor, &()).is_err());
+    }
+    for i in 0..proof.commitments.len() {
+        let mut res_p = verifiable_res.clone();
+        res_p
+            .proof
+            .as_mut()
+            .unwrap()
+            .commitments
+            .swap(i, (i + 1) % proof.commitments.len());
+        assert!(res_p.verify(&ast, &accessor, &()).is_err());
+    }
+
+    // The proof is only valid for the inline data it was made with
+    let other_values = owned_table([bigint("a", [1_i64, 3]), varchar("b", ["a", "b"])]);
+    let other_ast = projection_with_values(
+        vec![
+            aliased_plan(
+                add(
+                    inline_column(&table, "a", ColumnType::BigInt),
+                    const_bigint(10),
+                ),
+                "c",
+            ),
+            aliased_plan(inline_column(&table, "b", ColumnType::VarChar), "b"),
+        ],
+        tab(t),
+        other_values,
+    );
+    assert!(verifiable_res.verify(&other_ast, &accessor, &()).is_err());
+}
+
+#[test]
+fn we_can_prove_a_projection_over_an_empty_inline_table() {
+    let t: TableRef = "sxt.values".parse().unwrap();
+    let table = tab(t);
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    let ast = projection_with_values(
+        vec![aliased_plan(
+            inline_column(&table, "a", ColumnType::BigInt),
+            "a",
+        )],
+        tab(t),
+        owned_table([bigint("a", [0_i64; 0])]),
+    );
+    let verifiable_res = VerifiableQueryResult::<InnerProductProof>::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    assert_eq!(res, owned_table([bigint("a", [0_i64; 0])]));
+}
+
+#[test]
+fn we_cannot_project_an_inline_table_with_missing_or_mistyped_columns() {
+    let t: TableRef = "sxt.values".parse().unwrap();
+    let table = tab(t);
+    let values = || owned_table::<Curve25519Scalar>([bigint("a", [1_i64, 2])]);
+    assert!(matches!(
+        ProjectionExec::try_new_with_values(
+            vec![aliased_plan(
+                inline_column(&table, "b", ColumnType::BigInt),
+                "b"
+            )],
+            tab(t),
+            values(),
+        ),
+        Err(ConversionError::MissingColumn { .. })
+    ));
+    assert!(matches!(
+        ProjectionExec::try_new_with_values(
+            vec![aliased_plan(
+                inline_column(&table, "a", ColumnType::Int),
+                "a"
+            )],
+            tab(t),
+            values(),
+        ),
+        Err(ConversionError::InvalidExpression { .. })
+    ));
+}
+
+#[test]
+fn we_cannot_deserialize_a_projection_with_an_expression_nested_deeper_than_the_maximum_depth() {
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        t,
+        owned_table([bigint("a", [1_i64, 2, 3])]),
+        0,
+        (),
+    );
+    let sum_of_depth = |depth: usize| {
+        (1..depth).fold(column(t, "a", &accessor), |sum, _| {
+            add(sum, const_bigint(1))
+        })
+    };
+
+    let plan: DynProofPlan<RistrettoPoint> = projection(
+        vec![aliased_plan(sum_of_depth(DEFAULT_MAX_EXPR_DEPTH), "s")],
+        tab(t),
+    );
+    let bytes = postcard::to_allocvec(&plan).unwrap();
+    assert_eq!(
+        postcard::from_bytes::<DynProofPlan<RistrettoPoint>>(&bytes).unwrap(),
+        plan
+    );
+
+    let plan: DynProofPlan<RistrettoPoint> = projection(
+        vec![aliased_plan(sum_of_depth(DEFAULT_MAX_EXPR_DEPTH + 1), "s")],
+        tab(t),
+    );
+    assert!(matches!(
+        plan.check_depth(DEFAULT_MAX_EXPR_DEPTH),
+        Err(ConversionError::PlanTooDeep { depth, .. }) if depth == DEFAULT_MAX_EXPR_DEPTH + 1
+    ));
+    let bytes = postcard::to_allocvec(&plan).unwrap();
+    assert!(postcard::from_bytes::<DynProofPlan<RistrettoPoint>>(&bytes).is_err());
+}
@@ -1,14 +1,27 @@
-use super::{DynProofPlan, FilterExec, GroupByExec, ProjectionExec};
+use super::{
+    DistinctExec, DivideAndModuloExec, DynProofPlan, FilterExec, GroupByExec, ProjectionExec,
+};
 use crate::{
-    base::commitment::Commitment,
-    sql::proof_exprs::{AliasedDynProofExpr, ColumnExpr, DynProofExpr, TableExpr},
+    base::{commitment::Commitment, database::OwnedTable},
+    sql::proof_exprs::{
+        AliasedDynProofExpr, ColumnExpr, DivideAndModuloExpr, DynProofExpr, TableExpr,
+    },
 };
+use alloc::{boxed::Box, vec::Vec};
 
 pub fn projection<C: Commitment>(
     results: Vec<AliasedDynProofExpr<C>>,
     table: TableExpr,
 ) -> DynProofPlan<C> {
-    DynProofPlan::Projection(ProjectionExec::new(results, table))
+    DynProofPlan::Projection(ProjectionExec::try_new(results, table).unwrap())
+}
+
+pub fn projection_with_values<C: Commitment>(
+    results: Vec<AliasedDynProofExpr<C>>,
+    table: TableExpr,
+    values: OwnedTable<C::Scalar>,
+) -> DynProofPlan<C> {
+    DynProofPlan::Projection(ProjectionExec::try_new_with_values(results, table, values).unwrap())
 }
 
 pub fn filter<C: Commitment>(
@@ -16,7 +29,7 @@ pub fn filter<C: Commitment>(
     table: TableExpr,
     where_clause: DynProofExpr<C>,
 ) -> DynProofPlan<C> {
-    DynProofPlan::Filter(FilterExec::new(results, table, where_clause))
+    DynProofPlan::Filter(FilterExec::try_new(results, table, where_clause).unwrap())
 }
 
 /// # Panics
@@ -29,11 +42,40 @@ pub fn group_by<C: Commitment>(
     table: TableExpr,
     where_clause: DynProofExpr<C>,
 ) -> DynProofPlan<C> {
-    DynProofPlan::GroupBy(GroupByExec::new(
-        group_by_exprs,
-        sum_expr,
-        count_alias.parse().unwrap(),
-        table,
-        where_clause,
-    ))
+    DynProofPlan::GroupBy(
+        GroupByExec::try_new(
+            group_by_exprs,
+            sum_expr,
+            count_alias.parse().unwrap(),
+            table,
+            where_clause,
+        )
+        .unwrap(),
+    )
+}
+
+pub fn distinct<C: Commitment>(
+    distinct_exprs: Vec<ColumnExpr<C>>,
+    table: TableExpr,
+    where_clause: DynProofExpr<C>,
+) -> DynProofPlan<C> {
+    DynProofPlan::Distinct(DistinctExec::try_new(distinct_exprs, table, where_clause).unwrap())
+}
+
+pub fn divide_and_modulo<C: Commitment>(
+    lhs: DynProofExpr<C>,
+    rhs: DynProofExpr<C>,
+    quotient_alias: &str,
+    remainder_alias: &str,
+    table: TableExpr,
+) -> DynProofPlan<C> {
+    DynProofPlan::DivideAndModulo(
+        DivideAndModuloExec::try_new(
+            DivideAndModuloExpr::try_new(Box::new(lhs), Box::new(rhs)).unwrap(),
+            quotient_alias.parse().unwrap(),
+            remainder_alias.parse().unwrap(),
+            table,
+        )
+        .unwrap(),
+    )
 }
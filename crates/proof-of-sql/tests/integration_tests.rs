@@ -118,6 +118,41 @@ fn we_can_prove_a_minimal_filter_query_with_dynamic_dory() {
     assert_eq!(owned_table_result, expected_result);
 }
 
+#[test]
+#[cfg(feature = "blitzar")]
+fn we_can_prove_a_query_with_quoted_identifiers_with_curve25519() {
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(
+        r#"sxt."Order Table""#.parse().unwrap(),
+        owned_table([
+            bigint(r#""Order Id""#, [1, 2, 3, 2]),
+            bigint(r#""Amount""#, [10, 20, 30, 40]),
+            bigint("amount", [5, 6, 7, 8]),
+        ]),
+        0,
+    );
+    let query = QueryExpr::try_new(
+        r#"SELECT "Order Id", "Amount", AMOUNT FROM "Order Table" WHERE "Order Id" = 2;"#
+            .parse()
+            .unwrap(),
+        "sxt".parse().unwrap(),
+        &accessor,
+    )
+    .unwrap();
+    let (proof, serialized_result) =
+        QueryProof::<InnerProductProof>::new(query.proof_expr(), &accessor, &());
+    let owned_table_result = proof
+        .verify(query.proof_expr(), &accessor, &serialized_result, &())
+        .unwrap()
+        .table;
+    let expected_result = owned_table([
+        bigint(r#""Order Id""#, [2, 2]),
+        bigint(r#""Amount""#, [20, 40]),
+        bigint("amount", [6, 8]),
+    ]);
+    assert_eq!(owned_table_result, expected_result);
+}
+
 #[test]
 #[cfg(feature = "blitzar")]
 fn we_can_prove_a_basic_equality_query_with_curve25519() {